@@ -0,0 +1,362 @@
+//! Plays many seeded games between registered bots and reports win rates, average points,
+//! and average game lengths with 95% confidence intervals.
+//!
+//! ```text
+//! tournament [--games N] [--seed S] [--players 2,3] [--copies 1,3] [--hand-lens 6]
+//!     [--bots greedy,mcts:64]
+//! ```
+//!
+//! Every combination of `--players`, `--copies`, and `--hand-lens` is played `--games` times.
+//! Seats are filled by cycling through `--bots`, which is rotated by one seat every game so that
+//! each bot sits in every seat equally often. The first player is still chosen by the rules,
+//! which is a seeded random choice among the players holding the most matching tiles, so going
+//! first is not rotated. Game `g` is seeded from `--seed` plus `g`, so the same arguments always
+//! play the same games.
+//!
+//! Registered bots:
+//!
+//! * `greedy`: [GreedyPlayer] breaking ties deterministically.
+//! * `random-greedy`: [GreedyPlayer] breaking ties with a seeded rng.
+//! * `mcts`, `mcts:<iterations>`: [MctsPlayer] searching `64` or `<iterations>` iterations.
+//! * `mcts-ms:<milliseconds>`: [MctsPlayer] searching for `<milliseconds>` on each turn.
+
+use futures::executor::block_on;
+use matching_game_state::{
+    drive_game, Event, FirstState, GreedyPlayer, MctsPlayer, Player, SearchBudget,
+    DEFAULT_HAND_LEN, DEFAULT_UNIQUE_TILE_COPIED_COUNT,
+};
+use std::fmt::Write;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// The z score of a two-sided 95% confidence interval.
+const Z_95: f64 = 1.96;
+
+/// The number of iterations searched by `mcts` without a suffix.
+const MCTS_ITERATIONS: usize = 64;
+
+/// Printed when the arguments cannot be parsed.
+const USAGE: &str = "usage: tournament [--games N] [--seed S] [--players 2,3] [--copies 1,3] \
+                     [--hand-lens 6] [--bots greedy,mcts:64]";
+
+/// Parsed command line arguments.
+#[derive(Debug, Clone, PartialEq)]
+struct Args {
+    /// The number of games played for each combination of parameters.
+    games: usize,
+    /// The seed of the first game.
+    seed: u64,
+    /// Every number of players.
+    players: Vec<usize>,
+    /// Every number of copies of each tile in the bag.
+    copies: Vec<usize>,
+    /// Every number of tiles in each hand.
+    hand_lens: Vec<usize>,
+    /// Every bot which fills the seats in order.
+    bots: Vec<String>,
+}
+
+/// Results of one bot over every game it played with the same parameters.
+#[derive(Debug, Clone, Default)]
+struct Standing {
+    /// The number of games played.
+    games: usize,
+    /// `1` for a win, `1 / n` for an `n` way tie, or `0` for a loss in each game.
+    wins: Vec<f64>,
+    /// The final points in each game.
+    points: Vec<f64>,
+}
+
+impl Default for Args {
+    fn default() -> Self {
+        Args {
+            games: 100,
+            seed: 0,
+            players: vec![2],
+            copies: vec![DEFAULT_UNIQUE_TILE_COPIED_COUNT],
+            hand_lens: vec![DEFAULT_HAND_LEN],
+            bots: vec!["greedy".to_owned(), "mcts".to_owned()],
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("{error}\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    for &players in &args.players {
+        for &copies in &args.copies {
+            for &hand_len in &args.hand_lens {
+                match play_games(&args, players, copies, hand_len) {
+                    Ok(report) => print!("{report}"),
+                    Err(error) => {
+                        eprintln!("{error}");
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// Plays every game with the same parameters and formats the results.
+fn play_games(
+    args: &Args,
+    players: usize,
+    copies: usize,
+    hand_len: usize,
+) -> Result<String, String> {
+    let mut standings = vec![Standing::default(); args.bots.len()];
+    let mut lengths = Vec::with_capacity(args.games);
+
+    for game in 0..args.games {
+        let seed = args.seed.wrapping_add(game as u64);
+        let lineup: Vec<usize> = (0..players)
+            .map(|seat| (seat + game) % args.bots.len())
+            .collect();
+        let mut seats = lineup
+            .iter()
+            .enumerate()
+            .map(|(seat, &bot)| new_bot(&args.bots[bot], seed ^ ((seat as u64) << 32)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let first_state = FirstState::new_random_first_player_with_seed(
+            players,
+            Some(copies),
+            Some(hand_len),
+            None,
+            seed,
+        )
+        .map_err(|errors| format!("could not create game {seed}: {errors:?}"))?;
+        let mut last_state = block_on(drive_game(first_state, &mut seats, 0, 0))
+            .map_err(|error| format!("could not finish game {seed}: {error:?}"))?;
+
+        // every action except the last advances the turn
+        let turns_advanced = last_state
+            .drain_events()
+            .iter()
+            .filter(|event| matches!(event, Event::TurnAdvanced { .. }))
+            .count();
+        lengths.push((turns_advanced + 1) as f64);
+
+        let points = last_state.last_view().points.clone();
+        let max_points = points.iter().max().copied().unwrap_or_default();
+        let winners = points
+            .iter()
+            .filter(|&&points| points == max_points)
+            .count();
+        for (&bot, &points) in lineup.iter().zip(&points) {
+            let standing = &mut standings[bot];
+            standing.games += 1;
+            standing.points.push(points as f64);
+            standing.wins.push(if points == max_points {
+                1.0 / winners as f64
+            } else {
+                0.0
+            });
+        }
+    }
+
+    let (length, length_margin) = mean_and_margin(&lengths);
+    let mut report = format!(
+        "players {players}, copies {copies}, hand len {hand_len}, {} games, \
+         average length {length:.1} ± {length_margin:.1} turns\n",
+        args.games
+    );
+    let name_width = args.bots.iter().map(String::len).max().unwrap_or(0).max(3);
+    writeln!(
+        report,
+        "{:name_width$}  {:>5}  {:>15}  {:>15}",
+        "bot", "seats", "win rate", "average points"
+    )
+    .expect("write to String should succeed");
+    for (bot, standing) in args.bots.iter().zip(&standings) {
+        let (win_rate, win_rate_margin) = mean_and_margin(&standing.wins);
+        let (points, points_margin) = mean_and_margin(&standing.points);
+        writeln!(
+            report,
+            "{bot:name_width$}  {:>5}  {:>15}  {:>15}",
+            standing.games,
+            format!("{win_rate:.3} ± {win_rate_margin:.3}"),
+            format!("{points:.1} ± {points_margin:.1}"),
+        )
+        .expect("write to String should succeed");
+    }
+    report.push('\n');
+    Ok(report)
+}
+
+/// Creates the registered bot named by `spec`.
+fn new_bot(spec: &str, seed: u64) -> Result<Box<dyn Player>, String> {
+    let (name, parameter) = match spec.split_once(':') {
+        Some((name, parameter)) => (name, Some(parameter)),
+        None => (spec, None),
+    };
+    let parse = |parameter: &str| {
+        parameter
+            .parse::<u64>()
+            .map_err(|_| format!("bot {spec} has an invalid number {parameter}"))
+    };
+
+    match (name, parameter) {
+        ("greedy", None) => Ok(Box::new(GreedyPlayer::new())),
+        ("random-greedy", None) => Ok(Box::new(GreedyPlayer::new_with_seed(seed))),
+        ("mcts", parameter) => {
+            let iterations = match parameter {
+                Some(parameter) => parse(parameter)? as usize,
+                None => MCTS_ITERATIONS,
+            };
+            let budget = SearchBudget::Iterations(iterations);
+            Ok(Box::new(MctsPlayer::new_with_seed(
+                budget, None, None, seed,
+            )))
+        }
+        ("mcts-ms", Some(parameter)) => {
+            let budget = SearchBudget::Duration(Duration::from_millis(parse(parameter)?));
+            Ok(Box::new(MctsPlayer::new_with_seed(
+                budget, None, None, seed,
+            )))
+        }
+        _ => Err(format!("unknown bot {spec}")),
+    }
+}
+
+/// Parses the command line arguments after the program name.
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args::default();
+    let mut args = args.into_iter();
+    while let Some(flag) = args.next() {
+        if flag == "--help" || flag == "-h" {
+            return Err("plays seeded games between bots".to_owned());
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| format!("{flag} is missing a value"))?;
+        let number = |value: &str| {
+            value
+                .parse::<usize>()
+                .map_err(|_| format!("{flag} has an invalid number {value}"))
+        };
+        let numbers = |value: &str| value.split(',').map(number).collect::<Result<Vec<_>, _>>();
+
+        match flag.as_str() {
+            "--games" => parsed.games = number(&value)?,
+            "--seed" => {
+                parsed.seed = value
+                    .parse()
+                    .map_err(|_| format!("{flag} has an invalid number {value}"))?
+            }
+            "--players" => parsed.players = numbers(&value)?,
+            "--copies" => parsed.copies = numbers(&value)?,
+            "--hand-lens" => parsed.hand_lens = numbers(&value)?,
+            "--bots" => {
+                parsed.bots = value.split(',').map(str::to_owned).collect();
+                for bot in &parsed.bots {
+                    new_bot(bot, 0)?;
+                }
+            }
+            _ => return Err(format!("unknown flag {flag}")),
+        }
+    }
+
+    if parsed.bots.is_empty() || parsed.bots.iter().any(String::is_empty) {
+        return Err("--bots must name some bot".to_owned());
+    }
+    Ok(parsed)
+}
+
+/// The mean of `values` and half the width of its 95% confidence interval from
+/// the normal approximation, which is `0` for fewer than two values.
+fn mean_and_margin(values: &[f64]) -> (f64, f64) {
+    let len = values.len() as f64;
+    if values.is_empty() {
+        return (0.0, 0.0);
+    }
+    let mean = values.iter().sum::<f64>() / len;
+    if values.len() < 2 {
+        return (mean, 0.0);
+    }
+    let variance = values
+        .iter()
+        .map(|value| (value - mean).powi(2))
+        .sum::<f64>()
+        / (len - 1.0);
+    (mean, Z_95 * (variance / len).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Args, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parse_args_lists() {
+        let parsed = args(&[
+            "--games",
+            "4",
+            "--players",
+            "2,3",
+            "--bots",
+            "greedy,mcts:8,mcts-ms:5",
+        ])
+        .expect("parse_args should return Ok");
+
+        assert_eq!(4, parsed.games);
+        assert_eq!(vec![2, 3], parsed.players);
+        assert_eq!(vec!["greedy", "mcts:8", "mcts-ms:5"], parsed.bots);
+        assert_eq!(Args::default().copies, parsed.copies);
+    }
+
+    #[test]
+    fn parse_args_errors() {
+        assert!(args(&["--games"]).is_err());
+        assert!(args(&["--games", "many"]).is_err());
+        assert!(args(&["--rounds", "4"]).is_err());
+        assert!(args(&["--bots", "random"]).is_err());
+        assert!(args(&["--bots", "mcts-ms"]).is_err());
+    }
+
+    #[test]
+    fn mean_and_margin_values() {
+        assert_eq!((0.0, 0.0), mean_and_margin(&[]));
+        assert_eq!((2.0, 0.0), mean_and_margin(&[2.0]));
+
+        let (mean, margin) = mean_and_margin(&[1.0, 0.0, 1.0, 0.0]);
+
+        assert_eq!(0.5, mean);
+        assert!((margin - Z_95 * (1.0f64 / 12.0).sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn play_games_report() {
+        let parsed = args(&[
+            "--games",
+            "2",
+            "--copies",
+            "1",
+            "--bots",
+            "greedy,random-greedy",
+        ])
+        .expect("parse_args should return Ok");
+
+        let report =
+            play_games(&parsed, 2, 1, DEFAULT_HAND_LEN).expect("play_games should return Ok");
+
+        let rows = report
+            .lines()
+            .map(|line| line.split_whitespace().take(2).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert!(report.starts_with("players 2, copies 1, hand len 6, 2 games"));
+        assert!(rows.contains(&vec!["greedy", "2"]));
+        assert!(rows.contains(&vec!["random-greedy", "2"]));
+    }
+}
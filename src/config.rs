@@ -0,0 +1,295 @@
+use crate::{
+    COORDINATE_LIMIT, FULL_MATCH_BONUS, HOLES_LIMIT, LAST_PLAY_BONUS, TILES_LEN, TILE_LIMIT,
+};
+use std::collections::HashSet;
+
+/// Limits and bonuses of a single game which are chosen at runtime. Created from
+/// [GameConfig::new] or [GameConfig::default] and passed to
+/// [FirstState::new](crate::FirstState::new).
+///
+/// [PLAYER_CAPACITY](crate::PLAYER_CAPACITY) and [HAND_CAPACITY](crate::HAND_CAPACITY) are not
+/// included since they only size the stack allocations of every game and do not limit the game.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "GameConfigFields")
+)]
+pub struct GameConfig {
+    /// The maximum number of [tiles](crate::Tile) allowed in the bag.
+    tile_limit: usize,
+    /// The exclusive maximum absolute value of a component in a [coordinate](crate::Coordinate).
+    coordinate_limit: isize,
+    /// The maximum number of holes that can be returned in an error.
+    holes_limit: usize,
+    /// The amount of extra points given for each line completed with either
+    /// [every shape](crate::Shape::shapes) or [every color](crate::Color::colors).
+    full_match_bonus: usize,
+    /// The amount of extra points given when a player [plays](crate::Plays) the last
+    /// [tile](crate::Tile) of the game.
+    last_play_bonus: usize,
+}
+
+/// Describes the reason why [GameConfig] could not be created.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConfigError {
+    /// Attempting to allow less [tiles](crate::Tile) in the bag than
+    /// the [number of tile variants](TILES_LEN).
+    NotEnoughTileLimit {
+        /// The maximum number of [tiles](crate::Tile) allowed in the bag.
+        tile_limit: usize,
+    },
+    /// Attempting to set the coordinate limit to `0`.
+    ZeroCoordinateLimit,
+    /// Attempting to set the coordinate limit to [isize::MAX], [isize::MIN] `+ 1`,
+    /// or [isize::MIN].
+    MaxCoordinateLimit,
+    /// Attempting to set the tile limit and the coordinate limit where
+    /// ([isize::MAX] / `coordinate_limit` >= `tile_limit`) is false.
+    OverflowingLimits {
+        /// The maximum number of [tiles](crate::Tile) allowed in the bag.
+        tile_limit: usize,
+        /// The exclusive maximum absolute value of a component in
+        /// a [coordinate](crate::Coordinate).
+        coordinate_limit: isize,
+    },
+}
+
+impl GameConfig {
+    /// Checks that the tile limit is greater than or equal to
+    /// the [number of tile variants](TILES_LEN), that the saturating absolute value of
+    /// the coordinate limit is neither `0` nor [isize::MAX], and that
+    /// ([isize::MAX] / `coordinate_limit` >= `tile_limit`) is true so that overflow is prevented.
+    ///
+    /// When any argument is [None], the default value from [TILE_LIMIT], [COORDINATE_LIMIT],
+    /// [HOLES_LIMIT], [FULL_MATCH_BONUS], or [LAST_PLAY_BONUS] is used respectively.
+    ///
+    /// # Arguments
+    ///
+    /// * `tile_limit`: The maximum number of [tiles](crate::Tile) allowed in the bag.
+    /// * `coordinate_limit`: The exclusive maximum absolute value of a component in
+    /// a [coordinate](crate::Coordinate).
+    /// * `holes_limit`: The maximum number of holes that can be returned in an error.
+    /// * `full_match_bonus`: The amount of extra points given for each line completed with either
+    /// [every shape](crate::Shape::shapes) or [every color](crate::Color::colors).
+    /// * `last_play_bonus`: The amount of extra points given when a player
+    /// [plays](crate::Plays) the last [tile](crate::Tile) of the game.
+    ///
+    /// # Errors
+    ///
+    /// * [ConfigError::NotEnoughTileLimit] Attempting to allow less [tiles](crate::Tile)
+    /// in the bag than the [number of tile variants](TILES_LEN).
+    /// * [ConfigError::ZeroCoordinateLimit] Attempting to set the coordinate limit to `0`.
+    /// * [ConfigError::MaxCoordinateLimit] Attempting to set the coordinate limit to
+    /// [isize::MAX], [isize::MIN] `+ 1`, or [isize::MIN].
+    /// * [ConfigError::OverflowingLimits] Attempting to set the tile limit and
+    /// the coordinate limit where ([isize::MAX] / `coordinate_limit` >= `tile_limit`) is false.
+    pub fn new(
+        tile_limit: Option<usize>,
+        coordinate_limit: Option<isize>,
+        holes_limit: Option<usize>,
+        full_match_bonus: Option<usize>,
+        last_play_bonus: Option<usize>,
+    ) -> Result<GameConfig, HashSet<ConfigError>> {
+        let tile_limit = tile_limit.unwrap_or(TILE_LIMIT);
+        let coordinate_limit = coordinate_limit
+            .unwrap_or(COORDINATE_LIMIT)
+            .saturating_abs();
+
+        let mut errors = HashSet::with_capacity(3);
+        if tile_limit < TILES_LEN {
+            errors.insert(ConfigError::NotEnoughTileLimit { tile_limit });
+        }
+        if coordinate_limit == 0 {
+            errors.insert(ConfigError::ZeroCoordinateLimit);
+        } else if coordinate_limit == isize::MAX {
+            errors.insert(ConfigError::MaxCoordinateLimit);
+        } else if ((isize::MAX / coordinate_limit) as usize) < tile_limit {
+            errors.insert(ConfigError::OverflowingLimits {
+                tile_limit,
+                coordinate_limit,
+            });
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(GameConfig {
+            tile_limit,
+            coordinate_limit,
+            holes_limit: holes_limit.unwrap_or(HOLES_LIMIT),
+            full_match_bonus: full_match_bonus.unwrap_or(FULL_MATCH_BONUS),
+            last_play_bonus: last_play_bonus.unwrap_or(LAST_PLAY_BONUS),
+        })
+    }
+
+    /// The maximum number of [tiles](crate::Tile) allowed in the bag.
+    ///
+    /// # See Also
+    ///
+    /// * [TILE_LIMIT]
+    pub fn tile_limit(&self) -> usize {
+        self.tile_limit
+    }
+
+    /// The exclusive maximum absolute value of a component in a [coordinate](crate::Coordinate).
+    ///
+    /// # See Also
+    ///
+    /// * [COORDINATE_LIMIT]
+    pub fn coordinate_limit(&self) -> isize {
+        self.coordinate_limit
+    }
+
+    /// The maximum number of holes that can be returned in an error.
+    ///
+    /// # See Also
+    ///
+    /// * [HOLES_LIMIT]
+    pub fn holes_limit(&self) -> usize {
+        self.holes_limit
+    }
+
+    /// The amount of extra points given for each line completed with either
+    /// [every shape](crate::Shape::shapes) or [every color](crate::Color::colors).
+    ///
+    /// # See Also
+    ///
+    /// * [FULL_MATCH_BONUS]
+    pub fn full_match_bonus(&self) -> usize {
+        self.full_match_bonus
+    }
+
+    /// The amount of extra points given when a player [plays](crate::Plays) the last
+    /// [tile](crate::Tile) of the game.
+    ///
+    /// # See Also
+    ///
+    /// * [LAST_PLAY_BONUS]
+    pub fn last_play_bonus(&self) -> usize {
+        self.last_play_bonus
+    }
+}
+
+impl Default for GameConfig {
+    /// A [GameConfig] with [TILE_LIMIT], [COORDINATE_LIMIT], [HOLES_LIMIT], [FULL_MATCH_BONUS],
+    /// and [LAST_PLAY_BONUS], which are already checked at compile time.
+    fn default() -> Self {
+        GameConfig {
+            tile_limit: TILE_LIMIT,
+            coordinate_limit: COORDINATE_LIMIT,
+            holes_limit: HOLES_LIMIT,
+            full_match_bonus: FULL_MATCH_BONUS,
+            last_play_bonus: LAST_PLAY_BONUS,
+        }
+    }
+}
+
+/// Every [config error](ConfigError) which rejected a deserialized [GameConfig]. Implements
+/// `Display` so that serde can report it.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ConfigErrors(pub HashSet<ConfigError>);
+
+#[cfg(feature = "serde")]
+impl std::fmt::Display for ConfigErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid config: {:?}", self.0)
+    }
+}
+
+/// Every field of [GameConfig], which is deserialized before being checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct GameConfigFields {
+    tile_limit: usize,
+    coordinate_limit: isize,
+    holes_limit: usize,
+    full_match_bonus: usize,
+    last_play_bonus: usize,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<GameConfigFields> for GameConfig {
+    type Error = ConfigErrors;
+
+    /// Checks every field with [GameConfig::new], so that an invalid config cannot be
+    /// deserialized.
+    fn try_from(fields: GameConfigFields) -> Result<Self, Self::Error> {
+        GameConfig::new(
+            Some(fields.tile_limit),
+            Some(fields.coordinate_limit),
+            Some(fields.holes_limit),
+            Some(fields.full_match_bonus),
+            Some(fields.last_play_bonus),
+        )
+        .map_err(ConfigErrors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use map_macro::hash_set;
+
+    #[test]
+    fn new_none() {
+        let config = GameConfig::new(None, None, None, None, None).expect("new should return Ok");
+
+        assert_eq!(GameConfig::default(), config);
+    }
+
+    #[test]
+    fn new_some() {
+        let config = GameConfig::new(Some(TILES_LEN), Some(-100), Some(0), Some(12), Some(0))
+            .expect("new should return Ok");
+
+        assert_eq!(TILES_LEN, config.tile_limit());
+        assert_eq!(100, config.coordinate_limit());
+        assert_eq!(0, config.holes_limit());
+        assert_eq!(12, config.full_match_bonus());
+        assert_eq!(0, config.last_play_bonus());
+    }
+
+    #[test]
+    fn not_enough_tile_limit_zero_coordinate_limit() {
+        let errors = GameConfig::new(Some(TILES_LEN - 1), Some(0), None, None, None)
+            .expect_err("new should return Err");
+
+        assert_eq!(
+            hash_set! {
+                ConfigError::NotEnoughTileLimit { tile_limit: TILES_LEN - 1 },
+                ConfigError::ZeroCoordinateLimit,
+            },
+            errors
+        );
+    }
+
+    #[test]
+    fn max_coordinate_limit() {
+        for coordinate_limit in [isize::MAX, isize::MIN + 1, isize::MIN] {
+            let errors = GameConfig::new(None, Some(coordinate_limit), None, None, None)
+                .expect_err("new should return Err");
+
+            assert_eq!(hash_set! { ConfigError::MaxCoordinateLimit }, errors);
+        }
+    }
+
+    #[test]
+    fn overflowing_limits() {
+        let coordinate_limit = isize::MAX / 2;
+
+        let errors = GameConfig::new(Some(TILES_LEN), Some(coordinate_limit), None, None, None)
+            .expect_err("new should return Err");
+
+        assert_eq!(
+            hash_set! { ConfigError::OverflowingLimits {
+                tile_limit: TILES_LEN,
+                coordinate_limit,
+            }},
+            errors
+        );
+    }
+}
@@ -2,20 +2,22 @@ use crate::{Color, Shape, TILES_LEN};
 use konst::primitive::{parse_isize, parse_usize};
 use konst::{option, result};
 
-/// The amount of extra points given for each line completed with either
+/// The default amount of extra points given for each line completed with either
 /// [every shape](Shape::shapes) or [every color](Color::colors). `6` additional points.
 ///
 /// # See Also
 ///
+/// * [GameConfig::full_match_bonus](crate::GameConfig::full_match_bonus)
 /// * [check_line](crate::check_line)
 /// * [FirstState::first_play](crate::FirstState::first_play)
 /// * [NextState::next_play](crate::NextState::next_play)
 pub const FULL_MATCH_BONUS: usize = 6;
-/// The amount of extra points given when there are no available [tiles](crate::Tile) and a player
-/// [plays](crate::Plays) their last [tile](crate::Tile). `6` additional points.
+/// The default amount of extra points given when there are no available [tiles](crate::Tile) and
+/// a player [plays](crate::Plays) their last [tile](crate::Tile). `6` additional points.
 ///
 /// # See Also
 ///
+/// * [GameConfig::last_play_bonus](crate::GameConfig::last_play_bonus)
 /// * [FirstState::first_play](crate::FirstState::first_play)
 /// * [NextState::next_play](crate::NextState::next_play)
 pub const LAST_PLAY_BONUS: usize = 6;
@@ -58,7 +60,7 @@ pub const HAND_CAPACITY: usize = option::unwrap_or!(
         Shape::SHAPES_LEN
     }
 );
-/// The maximum number of [tiles](crate::Tile) allowed in the bag. If
+/// The default maximum number of [tiles](crate::Tile) allowed in the bag. If
 /// the environment variable named `TILE_LIMIT` is present at compile time, is able to be parsed
 /// into a `usize`, and is greater than or equal to the [number of tile variants](TILES_LEN),
 /// set to the value of the environment variable. Otherwise, it is set to `10_000`.
@@ -75,6 +77,7 @@ pub const HAND_CAPACITY: usize = option::unwrap_or!(
 ///
 /// # See Also
 ///
+/// * [GameConfig::tile_limit](crate::GameConfig::tile_limit)
 /// * [PLAYER_CAPACITY]
 /// * [HAND_CAPACITY]
 /// * [FirstState::new](crate::FirstState::new)
@@ -86,10 +89,10 @@ pub const TILE_LIMIT: usize = option::unwrap_or!(
     10_000
 );
 const _: () = assert!(TILE_LIMIT >= TILES_LEN);
-/// The exclusive maximum absolute value of a component in a [coordinate](crate::Coordinate).
-/// If the environment variable named `COORDINATE_LIMIT` is present at compile time,
-/// is able to be parsed into a `isize`, is not `0` and would not cause an overflow,
-/// set to the saturating absolute value of the environment variable.
+/// The default exclusive maximum absolute value of a component in
+/// a [coordinate](crate::Coordinate). If the environment variable named `COORDINATE_LIMIT`
+/// is present at compile time, is able to be parsed into a `isize`, is not `0` and would not
+/// cause an overflow, set to the saturating absolute value of the environment variable.
 /// Otherwise, it is set to `10_000`.
 ///
 /// It is recommended to set the value of `COORDINATE_LIMIT` greater than [TILES_LEN] *
@@ -104,6 +107,7 @@ const _: () = assert!(TILE_LIMIT >= TILES_LEN);
 ///
 /// # See Also
 ///
+/// * [GameConfig::coordinate_limit](crate::GameConfig::coordinate_limit)
 /// * [Coordinate](crate::Coordinate)
 /// * [FirstState::first_play](crate::FirstState::first_play)
 /// * [NextState::next_play](crate::NextState::next_play)
@@ -119,8 +123,8 @@ const _: () = assert!(COORDINATE_LIMIT > 0);
 //noinspection RsAssertEqual
 const _: () = assert!(COORDINATE_LIMIT != isize::MAX);
 const _: () = assert!(isize::MAX / COORDINATE_LIMIT >= TILE_LIMIT as isize);
-/// The maximum number of holes that can be returned in an error. If the environment variable
-/// named `HOLES_LIMIT` is present at compile time and is able to be parsed into a `usize`,
+/// The default maximum number of holes that can be returned in an error. If the environment
+/// variable named `HOLES_LIMIT` is present at compile time and is able to be parsed into a `usize`,
 /// set to the value of the environment variable. Otherwise, it is set to `100`.
 ///
 /// Setting `HOLES_LIMIT` to `0` stops any searching for holes.
@@ -133,6 +137,7 @@ const _: () = assert!(isize::MAX / COORDINATE_LIMIT >= TILE_LIMIT as isize);
 ///
 /// # See Also
 ///
+/// * [GameConfig::holes_limit](crate::GameConfig::holes_limit)
 /// * [FirstPlayError::Holes](crate::FirstPlayError::Holes)
 /// * [NextPlayError::Holes](crate::NextPlayError::Holes)
 /// * [COORDINATE_LIMIT]
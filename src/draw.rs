@@ -0,0 +1,206 @@
+use crate::{Color, Shape, Tile, UnseenTiles};
+
+/// Which [tiles](Tile) count as a success when [drawing](UnseenTiles::draw_probability).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DrawTarget {
+    /// Any copy of the [tile](Tile).
+    Tile(Tile),
+    /// Any [tile](Tile) with the [color](Color).
+    Color(Color),
+    /// Any [tile](Tile) with the [shape](Shape).
+    Shape(Shape),
+}
+
+impl DrawTarget {
+    /// Whether `tile` counts as a success.
+    pub fn matches(&self, tile: &Tile) -> bool {
+        match self {
+            DrawTarget::Tile(target) => target == tile,
+            DrawTarget::Color(color) => *color == tile.0,
+            DrawTarget::Shape(shape) => *shape == tile.1,
+        }
+    }
+}
+
+impl UnseenTiles {
+    /// The probability of drawing exactly `0`, `1`, and so on up to `draws` [tiles](Tile)
+    /// matching `target` from the bag. The bag holds a uniformly random part of the
+    /// [unseen tiles](UnseenTiles), so the number of matching [tiles](Tile) drawn is
+    /// hypergeometric over every unseen [tile](Tile). No more [tiles](Tile) are drawn than are
+    /// in the bag.
+    ///
+    /// # Arguments
+    ///
+    /// * `target`: Which [tiles](Tile) count as a success.
+    /// * `draws`: The number of [tiles](Tile) drawn, such as the number of
+    /// [tiles](Tile) played or exchanged.
+    ///
+    /// # Returns
+    ///
+    /// A vector where the probability of drawing exactly `i` matching [tiles](Tile) is at
+    /// index `i`, with one more element than the number of [tiles](Tile) drawn.
+    pub fn draw_distribution(&self, target: DrawTarget, draws: usize) -> Vec<f64> {
+        let population = self.len();
+        let successes: usize = self
+            .iter()
+            .filter(|(tile, _)| target.matches(tile))
+            .map(|(_, count)| count)
+            .sum();
+        let draws = draws.min(self.bag_len()).min(population);
+
+        let total = ln_choose(population, draws);
+        (0..=draws)
+            .map(|drawn| {
+                if drawn > successes || draws - drawn > population - successes {
+                    return 0.0;
+                }
+                (ln_choose(successes, drawn) + ln_choose(population - successes, draws - drawn)
+                    - total)
+                    .exp()
+            })
+            .collect()
+    }
+
+    /// The probability of drawing at least `at_least` [tiles](Tile) matching `target` from the
+    /// bag.
+    ///
+    /// # Arguments
+    ///
+    /// * `target`: Which [tiles](Tile) count as a success.
+    /// * `draws`: The number of [tiles](Tile) drawn.
+    /// * `at_least`: The minimum number of matching [tiles](Tile).
+    ///
+    /// # See Also
+    ///
+    /// * [UnseenTiles::draw_distribution]
+    pub fn draw_probability(&self, target: DrawTarget, draws: usize, at_least: usize) -> f64 {
+        self.draw_distribution(target, draws)
+            .into_iter()
+            .skip(at_least)
+            .sum::<f64>()
+            .min(1.0)
+    }
+}
+
+/// The natural logarithm of the number of ways to choose `k` from `n`.
+fn ln_choose(n: usize, k: usize) -> f64 {
+    let k = k.min(n - k);
+    (1..=k).map(|i| ((n - k + i) as f64 / i as f64).ln()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NextState, TILES_LEN};
+
+    /// A single player who holds nothing and sees nothing, with every copy in the bag.
+    fn set_up_unseen_tiles(unique_tile_copied_count: usize) -> UnseenTiles {
+        let mut next_state = NextState::empty_next_state();
+        *next_state.mut_unique_tile_copied_count() = unique_tile_copied_count;
+        next_state.mut_points().push(0);
+        next_state.mut_hands().push(Default::default());
+        let unseen_tiles = next_state.next_view().unseen_tiles(0, &[]);
+        next_state.mut_bag().extend(unseen_tiles.to_bag());
+        next_state.next_view().unseen_tiles(0, &[])
+    }
+
+    fn assert_close(expected: f64, actual: f64) {
+        assert!(
+            (expected - actual).abs() < 1e-9,
+            "expected {expected} but was {actual}"
+        );
+    }
+
+    #[test]
+    fn matches() {
+        let tile = (Color::Red, Shape::Circle);
+
+        assert!(DrawTarget::Tile(tile).matches(&tile));
+        assert!(!DrawTarget::Tile(tile).matches(&(Color::Red, Shape::X)));
+        assert!(DrawTarget::Color(Color::Red).matches(&tile));
+        assert!(!DrawTarget::Color(Color::Blue).matches(&tile));
+        assert!(DrawTarget::Shape(Shape::Circle).matches(&tile));
+        assert!(!DrawTarget::Shape(Shape::X).matches(&tile));
+    }
+
+    #[test]
+    fn draw_distribution_tile() {
+        let unseen_tiles = set_up_unseen_tiles(1);
+        let target = DrawTarget::Tile((Color::Red, Shape::Circle));
+
+        let distribution = unseen_tiles.draw_distribution(target, 2);
+
+        let population = TILES_LEN as f64;
+        assert_eq!(3, distribution.len());
+        assert_close((population - 2.0) / population, distribution[0]);
+        assert_close(2.0 / population, distribution[1]);
+        assert_close(0.0, distribution[2]);
+    }
+
+    #[test]
+    fn draw_distribution_sums_to_one() {
+        let unseen_tiles = set_up_unseen_tiles(3);
+
+        for target in [
+            DrawTarget::Tile((Color::Blue, Shape::X)),
+            DrawTarget::Color(Color::Green),
+            DrawTarget::Shape(Shape::Square),
+        ] {
+            let distribution = unseen_tiles.draw_distribution(target, 6);
+
+            assert_eq!(7, distribution.len());
+            assert_close(1.0, distribution.iter().sum());
+        }
+    }
+
+    #[test]
+    fn draw_probability_color() {
+        let unseen_tiles = set_up_unseen_tiles(1);
+        let target = DrawTarget::Color(Color::Purple);
+
+        // 6 of 36 tiles are purple, so 30 choose 2 of 36 choose 2 draw no purple tiles
+        let none = (30.0 * 29.0) / (36.0 * 35.0);
+        assert_close(1.0 - none, unseen_tiles.draw_probability(target, 2, 1));
+        assert_close(1.0, unseen_tiles.draw_probability(target, 2, 0));
+        assert_close(
+            (6.0 * 5.0) / (36.0 * 35.0),
+            unseen_tiles.draw_probability(target, 2, 2),
+        );
+        assert_close(0.0, unseen_tiles.draw_probability(target, 2, 3));
+    }
+
+    #[test]
+    fn draw_probability_seen_tiles() {
+        let mut next_state = NextState::empty_next_state();
+        *next_state.mut_unique_tile_copied_count() = 1;
+        next_state.mut_points().extend([0, 0]);
+        next_state.mut_hands().extend([
+            [(Color::Red, Shape::Circle)].into_iter().collect(),
+            Default::default(),
+        ]);
+        next_state
+            .mut_board()
+            .insert((0, 0), (Color::Red, Shape::X));
+        next_state.mut_bag().push((Color::Blue, Shape::X));
+        let hand = next_state.mut_hands()[0].clone();
+
+        let unseen_tiles = next_state.next_view().unseen_tiles(0, &hand);
+
+        // only 1 tile is left in the bag, so only 1 tile is drawn
+        assert_eq!(
+            2,
+            unseen_tiles
+                .draw_distribution(DrawTarget::Color(Color::Red), 3)
+                .len()
+        );
+        assert_close(
+            4.0 / 34.0,
+            unseen_tiles.draw_probability(DrawTarget::Color(Color::Red), 3, 1),
+        );
+        assert_close(
+            0.0,
+            unseen_tiles.draw_probability(DrawTarget::Tile((Color::Red, Shape::X)), 1, 1),
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{Coordinate, Points, ScoreBreakdown, Tile, TileId};
+
+/// Describes a single change made by [FirstState::first_play](crate::FirstState::first_play),
+/// [NextState::next_play](crate::NextState::next_play),
+/// [NextState::next_exchange](crate::NextState::next_exchange),
+/// [NextState::next_pass](crate::NextState::next_pass), or
+/// [NextState::reorder_hand](crate::NextState::reorder_hand).
+///
+/// Events are kept by the resulting state in the order they happened until they are taken by
+/// [NextState::drain_events](crate::NextState::drain_events),
+/// [LastState::drain_events](crate::LastState::drain_events), or
+/// [Game::drain_events](crate::Game::drain_events).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Event {
+    /// [Tiles](Tile) left a hand and landed on the board.
+    TilesPlayed {
+        /// The index of the player who played.
+        player: usize,
+        /// The index in the hand before the [play](crate::Plays), the [coordinate](Coordinate)
+        /// on the board, and the [tile](Tile) of each played [tile](Tile) ordered by index.
+        tiles: Vec<(usize, Coordinate, Tile)>,
+        /// The [identity](TileId) of each played [tile](Tile) in the same order as `tiles`
+        /// or [None] if [tile ids](crate::TileIds) are not tracked.
+        ids: Option<Vec<TileId>>,
+    },
+    /// [Tiles](Tile) were drawn from the bag and added to the end of a hand. Only the player
+    /// holding the hand should see the [tiles](Tile).
+    TilesDrawn {
+        /// The index of the player who drew.
+        player: usize,
+        /// The drawn [tiles](Tile) in the order they were added.
+        tiles: Vec<Tile>,
+        /// The [identity](TileId) of each drawn [tile](Tile) in the same order as `tiles`
+        /// or [None] if [tile ids](crate::TileIds) are not tracked.
+        ids: Option<Vec<TileId>>,
+    },
+    /// [Tiles](Tile) left a hand and were shuffled into the bag.
+    TilesExchanged {
+        /// The index of the player who exchanged.
+        player: usize,
+        /// The index in the hand before the [exchange](crate::Exchanges) and the [tile](Tile)
+        /// of each exchanged [tile](Tile) ordered by index.
+        tiles: Vec<(usize, Tile)>,
+        /// The [identity](TileId) of each exchanged [tile](Tile) in the same order as `tiles`
+        /// or [None] if [tile ids](crate::TileIds) are not tracked.
+        ids: Option<Vec<TileId>>,
+    },
+    /// A hand was reordered without taking a turn. Only the player holding the hand should see
+    /// the new order.
+    HandReordered {
+        /// The index of the player who reordered.
+        player: usize,
+        /// The index in the hand before reordering of each [tile](Tile) in the new order.
+        permutation: Vec<usize>,
+    },
+    /// Points were added to a player.
+    PointsAwarded {
+        /// The index of the player who earned points.
+        player: usize,
+        /// Itemises the earned points.
+        score_breakdown: ScoreBreakdown,
+        /// The player's points after adding the earned points.
+        total: usize,
+    },
+    /// The turn moved on to the next player.
+    TurnAdvanced {
+        /// The index of the player whose turn it is.
+        player: usize,
+    },
+    /// The game reached the `Last` phase.
+    GameEnded {
+        /// A vector of final points for each player.
+        points: Points,
+    },
+}
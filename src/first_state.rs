@@ -1,8 +1,11 @@
-use crate::{tiles, Bag, Color, Hands, MaxMatches, Shape, PLAYER_CAPACITY, TILES_LEN, TILE_LIMIT};
+use crate::{
+    tiles, Bag, Color, GameConfig, GameRng, Hands, MaxMatches, Shape, TileIds, PLAYER_CAPACITY,
+    TILES_LEN,
+};
 use itertools::{Chunk, Itertools};
 use map_macro::hash_set;
 use rand::seq::SliceRandom;
-use rand::Rng;
+use rand::{Rng, SeedableRng};
 use std::cmp;
 use std::collections::{BTreeSet, HashSet};
 
@@ -15,7 +18,12 @@ mod first_view;
 mod test_setup;
 
 /// Owns game state on the first turn and implements methods. Created from [FirstState::new].
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "FirstStateFields")
+)]
 pub struct FirstState {
     /// This is a bag of all the [tiles](crate::Tile) that haven't been removed yet.
     bag: Bag,
@@ -27,10 +35,23 @@ pub struct FirstState {
     max_matches: MaxMatches,
     /// The index of the player whose turn it is.
     current_player: usize,
+    /// Shuffles [tiles](crate::Tile) and is handed over to the [next state](crate::NextState).
+    rng: GameRng,
+    /// Seeded the [game rng](GameRng) when the game started.
+    seed: u64,
+    /// The number of copies of each [tile](crate::Tile) the game started with.
+    unique_tile_copied_count: usize,
+    /// Limits and bonuses of the game.
+    config: GameConfig,
+    /// The [identities](crate::TileId) of every [tile](crate::Tile) or [None] if
+    /// [tile ids](crate::TileIds) are not tracked.
+    #[cfg_attr(feature = "serde", serde(default))]
+    tile_ids: Option<TileIds>,
 }
 
 /// Describes the reason why [FirstState] could not be created.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NewError {
     /// Attempting [to start](FirstState::new) with empty players.
     EmptyPlayers,
@@ -46,7 +67,7 @@ pub enum NewError {
         tiles_in_bag: usize,
     },
     /// Attempting to create more than [tiles](crate::Tile) in the bag
-    /// than the [tile limit](TILE_LIMIT).
+    /// than the [tile limit](GameConfig::tile_limit).
     TooManyTiles {
         /// The number of [tiles](crate::Tile) being created in the bag.
         tiles_in_bag: usize,
@@ -81,7 +102,7 @@ impl FirstState {
     /// non-zero, that the number of [tiles](crate::Tile) requested (`player_len * hand_len`) is
     /// less than the number of [tiles](crate::Tile) in the bag
     /// ([TILES_LEN] ` * unique_tile_copied_count`), and that the number of [tiles](crate::Tile)
-    /// in the bag is less than or equal to the [tile limit](TILE_LIMIT).
+    /// in the bag is less than or equal to the [tile limit](GameConfig::tile_limit).
     ///
     /// Creates a bag of [tiles](crate::Tile), and then draws [tiles](crate::Tile)
     /// from the bag to create a hand for each player.
@@ -90,8 +111,9 @@ impl FirstState {
     /// and then finds the maximum of those maximums. Then, finds all players with that
     /// maximum number of matches, and then selects one of those players at random.
     ///
-    /// When `unique_tile_copied_count` and/or `hand_len` are [None], default values
-    /// [DEFAULT_UNIQUE_TILE_COPIED_COUNT] and [DEFAULT_HAND_LEN] are used respectively.
+    /// When `unique_tile_copied_count`, `hand_len`, and/or `config` are [None], default values
+    /// [DEFAULT_UNIQUE_TILE_COPIED_COUNT], [DEFAULT_HAND_LEN], and [GameConfig::default]
+    /// are used respectively.
     ///
     /// # Arguments
     ///
@@ -100,6 +122,7 @@ impl FirstState {
     /// in the bag.
     /// * `hand_len`: The number of [tiles](crate::Tile) each player will have
     /// in their hand.
+    /// * `config`: Limits and bonuses of the game.
     ///
     /// # Errors
     ///
@@ -112,32 +135,98 @@ impl FirstState {
     /// * [NewError::NotEnoughTiles] Attempting to request more [tiles](crate::Tile)
     /// than [tiles](crate::Tile) in the bag.
     /// * [NewError::TooManyTiles] Attempting to create more than [tiles](crate::Tile)
-    /// in the bag than the [tile limit](TILE_LIMIT).
+    /// in the bag than the [tile limit](GameConfig::tile_limit).
     ///
     /// # See Also
     ///
     /// * [FirstState::new]
+    /// * [FirstState::new_random_first_player_with_seed]
     pub fn new_random_first_player(
         players_len: usize,
         unique_tile_copied_count: Option<usize>,
         hand_len: Option<usize>,
+        config: Option<GameConfig>,
     ) -> Result<FirstState, HashSet<NewError>> {
-        fn first_player_selector(max_matching_players: &BTreeSet<usize>) -> usize {
-            let len = max_matching_players.len();
-            if len == 0 {
-                unreachable!("max_matching_players should not be empty.");
-            }
-            max_matching_players
-                .into_iter()
-                .nth(rand::thread_rng().gen_range(0..len))
-                .copied()
-                .unwrap_or_else(|| unreachable!("max_matching_players should not be empty."))
-        }
-        FirstState::new(
+        FirstState::new_random_first_player_with_rng(
             players_len,
             unique_tile_copied_count,
             hand_len,
-            first_player_selector,
+            config,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Same as [FirstState::new_random_first_player], but the [game rng](GameRng) is seeded
+    /// from `rng` instead of the thread local generator.
+    ///
+    /// # Arguments
+    ///
+    /// * `players_len`: The number of players in the game.
+    /// * `unique_tile_copied_count`: The number of copies of each [tile](crate::Tile)
+    /// in the bag.
+    /// * `hand_len`: The number of [tiles](crate::Tile) each player will have
+    /// in their hand.
+    /// * `config`: Limits and bonuses of the game.
+    /// * `rng`: Seeds the [game rng](GameRng) carried by the game state.
+    ///
+    /// # Errors
+    ///
+    /// Same as [FirstState::new_random_first_player].
+    ///
+    /// # See Also
+    ///
+    /// * [FirstState::new_random_first_player_with_seed]
+    pub fn new_random_first_player_with_rng<R: Rng + ?Sized>(
+        players_len: usize,
+        unique_tile_copied_count: Option<usize>,
+        hand_len: Option<usize>,
+        config: Option<GameConfig>,
+        rng: &mut R,
+    ) -> Result<FirstState, HashSet<NewError>> {
+        FirstState::new_random_first_player_with_seed(
+            players_len,
+            unique_tile_copied_count,
+            hand_len,
+            config,
+            rng.gen(),
+        )
+    }
+
+    /// Same as [FirstState::new_random_first_player], but the [game rng](GameRng) is seeded
+    /// from `seed`. The bag, the hands, the first player, and every later
+    /// [exchange](crate::NextState::next_exchange) are reproduced by the same `seed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `players_len`: The number of players in the game.
+    /// * `unique_tile_copied_count`: The number of copies of each [tile](crate::Tile)
+    /// in the bag.
+    /// * `hand_len`: The number of [tiles](crate::Tile) each player will have
+    /// in their hand.
+    /// * `config`: Limits and bonuses of the game.
+    /// * `seed`: Seeds the [game rng](GameRng) carried by the game state.
+    ///
+    /// # Errors
+    ///
+    /// Same as [FirstState::new_random_first_player].
+    ///
+    /// # See Also
+    ///
+    /// * [FirstState::new_with_seed]
+    pub fn new_random_first_player_with_seed(
+        players_len: usize,
+        unique_tile_copied_count: Option<usize>,
+        hand_len: Option<usize>,
+        config: Option<GameConfig>,
+        seed: u64,
+    ) -> Result<FirstState, HashSet<NewError>> {
+        FirstState::new_with_game_rng(
+            players_len,
+            unique_tile_copied_count,
+            hand_len,
+            config,
+            |_, random_player| random_player,
+            seed,
         )
     }
 
@@ -155,8 +244,9 @@ impl FirstState {
     /// maximum number of matches, and then selects one of those players
     /// with `first_player_selector`.
     ///
-    /// When `unique_tile_copied_count` and/or `hand_len` are [None], default values
-    /// [DEFAULT_UNIQUE_TILE_COPIED_COUNT] and [DEFAULT_HAND_LEN] are used respectively.
+    /// When `unique_tile_copied_count`, `hand_len`, and/or `config` are [None], default values
+    /// [DEFAULT_UNIQUE_TILE_COPIED_COUNT], [DEFAULT_HAND_LEN], and [GameConfig::default]
+    /// are used respectively.
     ///
     /// # Arguments
     ///
@@ -165,6 +255,7 @@ impl FirstState {
     /// in the bag.
     /// * `hand_len`: The number of [tiles](crate::Tile) each player will have
     /// in their hand.
+    /// * `config`: Limits and bonuses of the game.
     /// * `first_player_selector`: Selects the first player from a set of possible first players.
     ///
     /// # Errors
@@ -175,30 +266,162 @@ impl FirstState {
     /// * [NewError::NotEnoughTiles] Attempting to request more [tiles](crate::Tile)
     /// than [tiles](crate::Tile) in the bag.
     /// * [NewError::TooManyTiles] Attempting to create more than [tiles](crate::Tile)
-    /// in the bag than the [tile limit](TILE_LIMIT).
+    /// in the bag than the [tile limit](GameConfig::tile_limit).
     /// * [NewError::CurrentPlayerNotMaxMatchingPlayers] Attempt to select some current player
     /// not in `max_matching_players`.
     ///
     /// # See Also
     ///
     /// * [FirstState::new_random_first_player]
+    /// * [FirstState::new_with_seed]
     pub fn new(
         players_len: usize,
         unique_tile_copied_count: Option<usize>,
         hand_len: Option<usize>,
+        config: Option<GameConfig>,
         first_player_selector: impl FnOnce(&BTreeSet<usize>) -> usize,
     ) -> Result<FirstState, HashSet<NewError>> {
+        FirstState::new_with_rng(
+            players_len,
+            unique_tile_copied_count,
+            hand_len,
+            config,
+            first_player_selector,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Same as [FirstState::new], but the [game rng](GameRng) is seeded from `rng` instead of
+    /// the thread local generator.
+    ///
+    /// # Arguments
+    ///
+    /// * `players_len`: The number of players in the game.
+    /// * `unique_tile_copied_count`: The number of copies of each [tile](crate::Tile)
+    /// in the bag.
+    /// * `hand_len`: The number of [tiles](crate::Tile) each player will have
+    /// in their hand.
+    /// * `config`: Limits and bonuses of the game.
+    /// * `first_player_selector`: Selects the first player from a set of possible first players.
+    /// * `rng`: Seeds the [game rng](GameRng) carried by the game state.
+    ///
+    /// # Errors
+    ///
+    /// Same as [FirstState::new].
+    ///
+    /// # See Also
+    ///
+    /// * [FirstState::new_with_seed]
+    pub fn new_with_rng<R: Rng + ?Sized>(
+        players_len: usize,
+        unique_tile_copied_count: Option<usize>,
+        hand_len: Option<usize>,
+        config: Option<GameConfig>,
+        first_player_selector: impl FnOnce(&BTreeSet<usize>) -> usize,
+        rng: &mut R,
+    ) -> Result<FirstState, HashSet<NewError>> {
+        FirstState::new_with_seed(
+            players_len,
+            unique_tile_copied_count,
+            hand_len,
+            config,
+            first_player_selector,
+            rng.gen(),
+        )
+    }
+
+    /// Same as [FirstState::new], but the [game rng](GameRng) is seeded from `seed`.
+    /// The bag, the hands, and every later [exchange](crate::NextState::next_exchange)
+    /// are reproduced by the same `seed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `players_len`: The number of players in the game.
+    /// * `unique_tile_copied_count`: The number of copies of each [tile](crate::Tile)
+    /// in the bag.
+    /// * `hand_len`: The number of [tiles](crate::Tile) each player will have
+    /// in their hand.
+    /// * `config`: Limits and bonuses of the game.
+    /// * `first_player_selector`: Selects the first player from a set of possible first players.
+    /// * `seed`: Seeds the [game rng](GameRng) carried by the game state.
+    ///
+    /// # Errors
+    ///
+    /// Same as [FirstState::new].
+    ///
+    /// # See Also
+    ///
+    /// * [FirstState::new_random_first_player_with_seed]
+    pub fn new_with_seed(
+        players_len: usize,
+        unique_tile_copied_count: Option<usize>,
+        hand_len: Option<usize>,
+        config: Option<GameConfig>,
+        first_player_selector: impl FnOnce(&BTreeSet<usize>) -> usize,
+        seed: u64,
+    ) -> Result<FirstState, HashSet<NewError>> {
+        FirstState::new_with_game_rng(
+            players_len,
+            unique_tile_copied_count,
+            hand_len,
+            config,
+            |max_matching_players, _| first_player_selector(max_matching_players),
+            seed,
+        )
+    }
+
+    /// Creates the game with a [game rng](GameRng) seeded from `seed` carried by the game state.
+    /// A random first player is always drawn from the [game rng](GameRng) and given to
+    /// `first_player_selector`, so that every constructor leaves the [game rng](GameRng) in
+    /// the same state and the same `seed` reproduces every later
+    /// [exchange](crate::NextState::next_exchange) however the first player was selected.
+    ///
+    /// # Arguments
+    ///
+    /// * `players_len`: The number of players in the game.
+    /// * `unique_tile_copied_count`: The number of copies of each [tile](crate::Tile)
+    /// in the bag.
+    /// * `hand_len`: The number of [tiles](crate::Tile) each player will have
+    /// in their hand.
+    /// * `config`: Limits and bonuses of the game.
+    /// * `first_player_selector`: Selects the first player from a set of possible first players
+    /// and a random player from that set.
+    /// * `seed`: Seeds the [game rng](GameRng) which shuffles the bag and is then owned by
+    /// the game state.
+    ///
+    /// # Errors
+    ///
+    /// Same as [FirstState::new].
+    fn new_with_game_rng(
+        players_len: usize,
+        unique_tile_copied_count: Option<usize>,
+        hand_len: Option<usize>,
+        config: Option<GameConfig>,
+        first_player_selector: impl FnOnce(&BTreeSet<usize>, usize) -> usize,
+        seed: u64,
+    ) -> Result<FirstState, HashSet<NewError>> {
+        let mut rng = GameRng::seed_from_u64(seed);
         let unique_tile_copied_count =
             unique_tile_copied_count.unwrap_or(DEFAULT_UNIQUE_TILE_COPIED_COUNT);
         let hand_len = hand_len.unwrap_or(DEFAULT_HAND_LEN);
-        FirstState::check(players_len, unique_tile_copied_count, hand_len)?;
+        let config = config.unwrap_or_default();
+        FirstState::check(players_len, unique_tile_copied_count, hand_len, &config)?;
 
-        let (bag, hands) =
-            FirstState::new_bag_and_hands(players_len, unique_tile_copied_count, hand_len);
+        let (bag, hands) = FirstState::new_bag_and_hands(
+            players_len,
+            unique_tile_copied_count,
+            hand_len,
+            &mut rng,
+        );
         let (max_matches, max_matching_players) =
             FirstState::new_max_matches_and_max_matching_players(&hands);
 
-        let current_player = first_player_selector(&max_matching_players);
+        let random_player = max_matching_players
+            .iter()
+            .nth(rng.gen_range(0..max_matching_players.len()))
+            .copied()
+            .unwrap_or_else(|| unreachable!("max_matching_players should not be empty."));
+        let current_player = first_player_selector(&max_matching_players, random_player);
 
         // check whether selected current_player is in max_matching_players
         if !max_matching_players.contains(&current_player) {
@@ -213,19 +436,52 @@ impl FirstState {
             hands,
             max_matches,
             current_player,
+            rng,
+            seed,
+            unique_tile_copied_count,
+            config,
+            tile_ids: None,
         })
     }
 
+    /// Starts tracking the [identity](crate::TileId) of every [tile](crate::Tile), which each
+    /// [tile](crate::Tile) keeps from the bag, through a hand, to the board for the rest of
+    /// the game.
+    ///
+    /// # See Also
+    ///
+    /// * [TileIds]
+    pub fn track_tile_ids(mut self) -> FirstState {
+        self.tile_ids = Some(TileIds::new(&self.bag, &self.hands));
+        self
+    }
+
     /// The index of the player whose turn it is.
     pub fn current_player(&self) -> usize {
         self.current_player
     }
 
+    /// Seeded the [game rng](GameRng) when the game started, which reproduces the game with
+    /// [FirstState::new_with_seed] however the game was started.
+    ///
+    /// # See Also
+    ///
+    /// * [GameRecord](crate::GameRecord)
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// The [identities](crate::TileId) of every [tile](crate::Tile) or [None] if
+    /// [tile ids](TileIds) are not tracked.
+    pub fn tile_ids(&self) -> Option<&TileIds> {
+        self.tile_ids.as_ref()
+    }
+
     /// Checks that `players_len`, `unique_tile_copied_count`, and `hand_len` are all
     /// non-zero, that the number of [tiles](crate::Tile) requested (`player_len * hand_len`) is
     /// less than the number of [tiles](crate::Tile) in the bag
     /// ([TILES_LEN] ` * unique_tile_copied_count`), and that the number of [tiles](crate::Tile)
-    /// in the bag is less than or equal to the [tile limit](TILE_LIMIT).
+    /// in the bag is less than or equal to the [tile limit](GameConfig::tile_limit).
     ///
     /// # Arguments
     ///
@@ -234,6 +490,7 @@ impl FirstState {
     /// in the bag.
     /// * `hand_len`: The number of [tiles](crate::Tile) each player will have
     /// in their hand.
+    /// * `config`: Limits and bonuses of the game.
     ///
     /// # Errors
     ///
@@ -243,11 +500,12 @@ impl FirstState {
     /// * [NewError::NotEnoughTiles] Attempting to request more [tiles](crate::Tile)
     /// than [tiles](crate::Tile) in the bag.
     /// * [NewError::TooManyTiles] Attempting to create more than [tiles](crate::Tile)
-    /// in the bag than the [tile limit](TILE_LIMIT).
+    /// in the bag than the [tile limit](GameConfig::tile_limit).
     fn check(
         players_len: usize,
         unique_tile_copied_count: usize,
         hand_len: usize,
+        config: &GameConfig,
     ) -> Result<(), HashSet<NewError>> {
         let mut errors = HashSet::with_capacity(5);
         if players_len == 0 {
@@ -270,7 +528,7 @@ impl FirstState {
             });
         }
 
-        if tiles_in_bag > TILE_LIMIT {
+        if tiles_in_bag > config.tile_limit() {
             errors.insert(NewError::TooManyTiles { tiles_in_bag });
         }
 
@@ -291,6 +549,7 @@ impl FirstState {
     /// in the bag.
     /// * `hand_len`: The number of [tiles](crate::Tile) each player will have
     /// in their hand.
+    /// * `rng`: Shuffles the bag.
     ///
     /// # Returns
     ///
@@ -301,12 +560,13 @@ impl FirstState {
         players_len: usize,
         unique_tile_copied_count: usize,
         hand_len: usize,
+        rng: &mut GameRng,
     ) -> (Bag, Hands) {
         let mut bag = tiles()
             .into_iter()
             .flat_map(|tile| vec![tile; unique_tile_copied_count])
             .collect_vec();
-        bag.shuffle(&mut rand::thread_rng());
+        bag.shuffle(rng);
         let hands = bag
             .drain(bag.len() - (players_len * hand_len)..)
             .chunks(hand_len)
@@ -369,6 +629,71 @@ impl FirstState {
     }
 }
 
+/// Every field of [FirstState], which is deserialized before being checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+pub(crate) struct FirstStateFields {
+    bag: Bag,
+    hands: Hands,
+    max_matches: MaxMatches,
+    current_player: usize,
+    rng: GameRng,
+    seed: u64,
+    unique_tile_copied_count: usize,
+    config: GameConfig,
+    #[serde(default)]
+    tile_ids: Option<TileIds>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<FirstStateFields> for FirstState {
+    type Error = crate::StateErrors;
+
+    /// Checks that the current player and the maximum matches line up with the players,
+    /// that the [tiles](crate::Tile) are within the [tile limit](GameConfig::tile_limit), and
+    /// that any [tile ids](TileIds) line up with the [tiles](crate::Tile), so that
+    /// an unreachable state cannot be deserialized.
+    fn try_from(fields: FirstStateFields) -> Result<Self, Self::Error> {
+        let mut errors = HashSet::new();
+        crate::serialization::check_players(
+            &fields.hands,
+            Some(fields.current_player),
+            None,
+            Some(fields.max_matches.len()),
+            &mut errors,
+        );
+        crate::serialization::check_tiles(
+            fields.bag.len(),
+            &crate::Board::new(),
+            &fields.hands,
+            &fields.config,
+            &mut errors,
+        );
+        crate::serialization::check_tile_ids(
+            fields.tile_ids.as_ref(),
+            Some(fields.bag.len()),
+            &crate::Board::new(),
+            &fields.hands,
+            &mut errors,
+        );
+        if !errors.is_empty() {
+            return Err(crate::StateErrors(errors));
+        }
+
+        Ok(FirstState {
+            bag: fields.bag,
+            hands: fields.hands,
+            max_matches: fields.max_matches,
+            current_player: fields.current_player,
+            rng: fields.rng,
+            seed: fields.seed,
+            unique_tile_copied_count: fields.unique_tile_copied_count,
+            config: fields.config,
+            tile_ids: fields.tile_ids,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -377,7 +702,7 @@ mod tests {
 
     #[test]
     fn empty_players() {
-        test_new_one_error(0, None, None, NewError::EmptyPlayers);
+        test_new_one_error(0, None, None, None, NewError::EmptyPlayers);
     }
 
     #[test]
@@ -386,6 +711,7 @@ mod tests {
             1,
             Some(0),
             Some(1),
+            None,
             hash_set! {
               NewError::EmptyBag,
               NewError::NotEnoughTiles {
@@ -398,7 +724,7 @@ mod tests {
 
     #[test]
     fn empty_hands() {
-        test_new_one_error(1, None, Some(0), NewError::EmptyHands);
+        test_new_one_error(1, None, Some(0), None, NewError::EmptyHands);
     }
 
     #[test]
@@ -407,6 +733,7 @@ mod tests {
             0,
             Some(0),
             Some(0),
+            None,
             hash_set! { NewError::EmptyPlayers, NewError::EmptyBag, NewError::EmptyHands },
         );
     }
@@ -421,6 +748,7 @@ mod tests {
             players_len,
             Some(unique_tile_copied_count),
             Some(hand_len),
+            None,
             NewError::NotEnoughTiles {
                 requested_tiles: players_len * hand_len,
                 tiles_in_bag: unique_tile_copied_count * TILES_LEN,
@@ -431,13 +759,32 @@ mod tests {
     #[test]
     fn too_many_tiles() {
         let players_len = 10;
-        let unique_tile_copied_count = TILE_LIMIT;
+        let unique_tile_copied_count = crate::TILE_LIMIT;
         let hand_len = 20;
 
         test_new_one_error(
             players_len,
             Some(unique_tile_copied_count),
             Some(hand_len),
+            None,
+            NewError::TooManyTiles {
+                tiles_in_bag: unique_tile_copied_count * TILES_LEN,
+            },
+        );
+    }
+
+    #[test]
+    fn too_many_tiles_config() {
+        let players_len = 2;
+        let unique_tile_copied_count = 2;
+        let config =
+            GameConfig::new(Some(TILES_LEN), None, None, None, None).expect("new should return Ok");
+
+        test_new_one_error(
+            players_len,
+            Some(unique_tile_copied_count),
+            None,
+            Some(config),
             NewError::TooManyTiles {
                 tiles_in_bag: unique_tile_copied_count * TILES_LEN,
             },
@@ -463,6 +810,7 @@ mod tests {
             players_len,
             Some(unique_tile_copied_count),
             Some(hand_len),
+            None,
             first_player_selector,
         )
         .expect_err("new should return Err");
@@ -482,12 +830,13 @@ mod tests {
     fn new_none() {
         let players_len = rand::thread_rng().gen_range(2..=PLAYER_CAPACITY);
 
-        let none = FirstState::new_random_first_player(players_len, None, None)
+        let none = FirstState::new_random_first_player(players_len, None, None, None)
             .expect("new should return Ok");
         let some = FirstState::new_random_first_player(
             players_len,
             Some(DEFAULT_UNIQUE_TILE_COPIED_COUNT),
             Some(DEFAULT_HAND_LEN),
+            None,
         )
         .expect("new should return Ok");
 
@@ -509,6 +858,7 @@ mod tests {
             players_len,
             Some(unique_tile_copied_count),
             Some(hand_len),
+            None,
         )
         .expect("new should return Ok");
 
@@ -528,6 +878,10 @@ mod tests {
             TILES_LEN * unique_tile_copied_count - (hand_len * players_len),
             first_state.bag.len()
         );
+        assert_eq!(
+            unique_tile_copied_count,
+            first_state.unique_tile_copied_count
+        );
         assert_eq!(players_len, first_state.hands.len());
         for player in 0..players_len {
             assert_eq!(hand_len, first_state.hands[player].len());
@@ -535,16 +889,107 @@ mod tests {
         assert!(max_matching_players.contains(&first_state.current_player));
     }
 
+    #[test]
+    fn new_with_seed_reproducible() {
+        let seed = rand::thread_rng().gen();
+        fn first_player_selector(max_matching_players: &BTreeSet<usize>) -> usize {
+            max_matching_players
+                .iter()
+                .next()
+                .copied()
+                .expect("new should always provide non-empty max_matching_players")
+        }
+
+        let first = FirstState::new_with_seed(4, None, None, None, first_player_selector, seed)
+            .expect("new_with_seed should return Ok");
+        let second = FirstState::new_with_seed(4, None, None, None, first_player_selector, seed)
+            .expect("new_with_seed should return Ok");
+
+        assert_eq!(first.bag, second.bag);
+        assert_eq!(first.hands, second.hands);
+        assert_eq!(first.max_matches, second.max_matches);
+        assert_eq!(first.current_player, second.current_player);
+    }
+
+    #[test]
+    fn seed_reproduces_any_first_player() {
+        let random = FirstState::new_random_first_player(PLAYER_CAPACITY, None, None, None)
+            .expect("new_random_first_player should return Ok");
+
+        let selected = FirstState::new_with_seed(
+            PLAYER_CAPACITY,
+            None,
+            None,
+            None,
+            |_| random.current_player,
+            random.seed(),
+        )
+        .expect("new_with_seed should return Ok");
+
+        assert_eq!(random.seed, selected.seed);
+        assert_eq!(random.bag, selected.bag);
+        assert_eq!(random.hands, selected.hands);
+        assert_eq!(random.current_player, selected.current_player);
+        assert_eq!(random.rng, selected.rng);
+    }
+
+    #[test]
+    fn new_random_first_player_with_rng_reproducible() {
+        let seed = rand::thread_rng().gen();
+
+        let first = FirstState::new_random_first_player_with_rng(
+            PLAYER_CAPACITY,
+            None,
+            None,
+            None,
+            &mut GameRng::seed_from_u64(seed),
+        )
+        .expect("new_random_first_player_with_rng should return Ok");
+        let second = FirstState::new_random_first_player_with_rng(
+            PLAYER_CAPACITY,
+            None,
+            None,
+            None,
+            &mut GameRng::seed_from_u64(seed),
+        )
+        .expect("new_random_first_player_with_rng should return Ok");
+
+        assert_eq!(first.bag, second.bag);
+        assert_eq!(first.hands, second.hands);
+        assert_eq!(first.current_player, second.current_player);
+    }
+
+    #[test]
+    fn new_random_first_player_with_seed_different_seeds() {
+        let seed: u64 = rand::thread_rng().gen();
+
+        let first =
+            FirstState::new_random_first_player_with_seed(PLAYER_CAPACITY, None, None, None, seed)
+                .expect("new_random_first_player_with_seed should return Ok");
+        let second = FirstState::new_random_first_player_with_seed(
+            PLAYER_CAPACITY,
+            None,
+            None,
+            None,
+            seed.wrapping_add(1),
+        )
+        .expect("new_random_first_player_with_seed should return Ok");
+
+        assert_ne!(first.bag, second.bag);
+    }
+
     fn test_new_one_error(
         players_len: usize,
         unique_tile_copied_count: Option<usize>,
         hand_len: Option<usize>,
+        config: Option<GameConfig>,
         expected_error: NewError,
     ) {
         test_new_errors(
             players_len,
             unique_tile_copied_count,
             hand_len,
+            config,
             hash_set! { expected_error },
         );
     }
@@ -553,11 +998,16 @@ mod tests {
         players_len: usize,
         unique_tile_copied_count: Option<usize>,
         hand_len: Option<usize>,
+        config: Option<GameConfig>,
         expected_error: HashSet<NewError>,
     ) {
-        let actual_error =
-            FirstState::new_random_first_player(players_len, unique_tile_copied_count, hand_len)
-                .expect_err("new_random_first_player_selector should only return Err");
+        let actual_error = FirstState::new_random_first_player(
+            players_len,
+            unique_tile_copied_count,
+            hand_len,
+            config,
+        )
+        .expect_err("new_random_first_player_selector should only return Err");
 
         assert_eq!(expected_error, actual_error);
     }
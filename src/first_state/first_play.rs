@@ -1,14 +1,16 @@
 use crate::{
     batch_continuous_decreasing_range, batch_continuous_increasing_range, check_line,
     find_component_minimums_and_maximums, find_coordinate_by_minimum_distance,
-    partition_by_coordinates, possible_plays, Coordinate, FirstState, NextState, Plays, Points,
-    HOLES_LIMIT,
+    partition_by_coordinates, possible_plays, resolve_plays, Carried, Coordinate, Event,
+    FirstState, NextState, Plays, Points, ScoreBreakdown, TilePlays,
 };
 use itertools::Itertools;
+use map_macro::hash_set;
 use std::collections::{BTreeSet, HashSet};
 
 /// Describes the reason why the [first play](FirstState::first_play) could not be executed.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FirstPlayError {
     /// Attempting [to play](FirstState::first_play) no [tiles](crate::Tile).
     EmptyPlays,
@@ -16,13 +18,23 @@ pub enum FirstPlayError {
     /// in the current player's hand.
     IndexesOutOfBounds {
         /// [Plays](Plays) where the index is greater than or equal to `hand_len`.
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::plays"))]
         indexes_out_of_bounds: Plays,
     },
+    /// Attempting [to play](FirstState::first_play_tiles) [tiles](crate::Tile) by value
+    /// which are not held in the current player's hand.
+    TilesNotHeld {
+        /// [Tile plays](TilePlays) where the current player does not hold another copy
+        /// of the [tile](crate::Tile).
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::tile_plays"))]
+        tiles_not_held: TilePlays,
+    },
     /// Attempting [to play](FirstState::first_play) [tiles](crate::Tile) too far away from
     /// the center of the board.
     CoordinatesOutOfBounds {
         /// [Plays](Plays) where the absolute value of a component in a [coordinate](Coordinate) is
-        /// greater than or equal to the [coordinate limit](crate::COORDINATE_LIMIT).
+        /// greater than or equal to the [coordinate limit](crate::GameConfig::coordinate_limit).
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::plays"))]
         coordinates_out_of_bounds: Plays,
     },
     /// Not attempting [to play](FirstState::first_play) some [tile](crate::Tile) at the origin.
@@ -72,7 +84,7 @@ impl FirstState {
     /// The number of points from a line is the number of [tiles](crate::Tile) in that line. If the
     /// line creates a full match on the board where a line contains either
     /// [every color](crate::Color::colors) or [every shape](crate::Shape::shapes), an extra
-    /// [full match bonus](crate::FULL_MATCH_BONUS) is earned.
+    /// [full match bonus](crate::GameConfig::full_match_bonus) is earned.
     ///
     /// # Arguments
     ///
@@ -105,40 +117,165 @@ impl FirstState {
     ///
     /// # Returns
     ///
-    /// The [next state](NextState) of the game after the [play](Plays).
+    /// The [next state](NextState) of the game after the [play](Plays) and
+    /// the [score breakdown](ScoreBreakdown) of the [play](Plays).
     pub fn first_play(
         mut self,
         plays: &Plays,
-    ) -> Result<NextState, (Self, HashSet<FirstPlayError>)> {
-        let first_play_points = match self.check_plays(plays) {
-            Ok(points) => points,
+    ) -> Result<(NextState, ScoreBreakdown), (Self, HashSet<FirstPlayError>)> {
+        let score_breakdown = match self.check_plays(plays) {
+            Ok(score_breakdown) => score_breakdown,
             Err(errors) => return Err((self, errors)),
         };
 
-        let hand = &mut self.hands[self.current_player];
-        let board = plays
+        let player = self.current_player;
+        let hand = &mut self.hands[player];
+        let mut tiles = plays
             .iter()
             .rev()
-            .map(|(&index, &coordinate)| (coordinate, hand.remove(index)))
+            .map(|(&index, &coordinate)| (index, coordinate, hand.remove(index)))
+            .collect_vec();
+        tiles.reverse();
+        let board = tiles
+            .iter()
+            .map(|&(_, coordinate, tile)| (coordinate, tile))
             .collect();
         // when the bag is empty, no more tiles will be drained
-        hand.extend(self.bag.drain(self.bag.len().saturating_sub(plays.len())..));
+        let drawn = self
+            .bag
+            .drain(self.bag.len().saturating_sub(plays.len())..)
+            .collect_vec();
+        hand.extend(drawn.iter().copied());
+        let (played_ids, drawn_ids) = match &mut self.tile_ids {
+            Some(tile_ids) => {
+                let (played_ids, drawn_ids) = tile_ids.play(player, plays, drawn.len());
+                (Some(played_ids), Some(drawn_ids))
+            }
+            None => (None, None),
+        };
 
         let mut points: Points = self.hands.iter().map(|_| 0).collect();
-        points[self.current_player] = first_play_points;
-        self.current_player = (self.current_player + 1) % self.hands.len();
-
-        Ok(NextState::new(
+        points[player] = score_breakdown.points();
+        self.current_player = (player + 1) % self.hands.len();
+
+        let mut events = vec![Event::TilesPlayed {
+            player,
+            tiles,
+            ids: played_ids,
+        }];
+        if !drawn.is_empty() {
+            events.push(Event::TilesDrawn {
+                player,
+                tiles: drawn,
+                ids: drawn_ids,
+            });
+        }
+        events.push(Event::PointsAwarded {
+            player,
+            score_breakdown: score_breakdown.clone(),
+            total: points[player],
+        });
+        events.push(Event::TurnAdvanced {
+            player: self.current_player,
+        });
+
+        let next_state = NextState::new(
             self.bag,
             board,
             points,
             self.hands,
             self.current_player,
-        ))
+            Carried {
+                rng: self.rng,
+                unique_tile_copied_count: self.unique_tile_copied_count,
+                config: self.config,
+                events,
+                tile_ids: self.tile_ids,
+            },
+        );
+        Ok((next_state, score_breakdown))
+    }
+
+    /// [Resolves](crate::resolve_plays) [tile plays](TilePlays) against the current order of
+    /// the current player's hand and then [plays](FirstState::first_play) them, so
+    /// [tiles](crate::Tile) are played by value instead of by their index in the hand.
+    ///
+    /// # Arguments
+    ///
+    /// * `tile_plays`: A map of [coordinates](Coordinate) on the board to [tiles](crate::Tile)
+    /// to be played.
+    ///
+    /// # Errors
+    ///
+    /// * [FirstPlayError::TilesNotHeld] Attempting [to play](FirstState::first_play_tiles)
+    /// [tiles](crate::Tile) by value which are not held in the current player's hand.
+    /// * Otherwise, any error returned by [first_play](FirstState::first_play).
+    ///
+    /// # Returns
+    ///
+    /// The [next state](NextState) of the game after the [play](Plays) and
+    /// the [score breakdown](ScoreBreakdown) of the [play](Plays).
+    pub fn first_play_tiles(
+        self,
+        tile_plays: &TilePlays,
+    ) -> Result<(NextState, ScoreBreakdown), (Self, HashSet<FirstPlayError>)> {
+        match resolve_plays(&self.hands[self.current_player], tile_plays) {
+            Ok(plays) => self.first_play(&plays),
+            Err(tiles_not_held) => Err((
+                self,
+                hash_set! { FirstPlayError::TilesNotHeld { tiles_not_held } },
+            )),
+        }
+    }
+
+    /// Checks if the [plays](Plays) are valid and returns the [score breakdown](ScoreBreakdown)
+    /// which would be earned by [playing](FirstState::first_play) them without changing the game.
+    ///
+    /// # Points Calculation
+    ///
+    /// The number of points from a line is the number of [tiles](crate::Tile) in that line. If the
+    /// line creates a full match on the board where a line contains either
+    /// [every color](crate::Color::colors) or [every shape](crate::Shape::shapes), an extra
+    /// [full match bonus](crate::GameConfig::full_match_bonus) is earned.
+    ///
+    /// # Arguments
+    ///
+    /// * `plays`: A bimap of indexes of [tiles](crate::Tile) to be played
+    /// to [coordinates](Coordinate) on the board.
+    ///
+    /// # Errors
+    ///
+    /// * [FirstPlayError::EmptyPlays] Attempting [to play](FirstState::first_play) no
+    /// [tiles](crate::Tile).
+    /// * [FirstPlayError::IndexesOutOfBounds] Attempting [to play](FirstState::first_play)
+    /// [tiles](crate::Tile) not in the current player's hand.
+    /// * [FirstPlayError::CoordinatesOutOfBounds] Attempting [to play](FirstState::first_play)
+    /// [tiles](crate::Tile) too far away from the center of the board.
+    /// * [FirstPlayError::OriginNotIncluded] Not attempting [to play](FirstState::first_play)
+    /// some [tile](crate::Tile) at the origin.
+    /// * [FirstPlayError::NotMaxMatching] Not attempting [to play](FirstState::first_play)
+    /// max match of legal [plays](Plays).
+    /// * [FirstPlayError::NoLegalPlays] Attempting to only [play](FirstState::first_play)
+    /// illegal [plays](Plays).
+    /// * [FirstPlayError::NoLegalLines] Not attempting [to play](FirstState::first_play)
+    /// [tiles](crate::Tile) in a point or a line.
+    /// * [FirstPlayError::Holes] Attempting [to play](FirstState::first_play) [tiles](crate::Tile)
+    /// in a line but not the same connected line.
+    /// * [FirstPlayError::Duplicates] Attempting [to play](FirstState::first_play)
+    /// duplicate [tiles](crate::Tile) in a line.
+    /// * [FirstPlayError::MultipleMatching] Attempting [to play](FirstState::first_play) a line
+    /// where [tiles](crate::Tile) are not either the same [shape](crate::Shape)
+    /// or the same [color](crate::Color).
+    ///
+    /// # Returns
+    ///
+    /// The [score breakdown](ScoreBreakdown) of [plays](Plays).
+    pub fn preview_play(&self, plays: &Plays) -> Result<ScoreBreakdown, HashSet<FirstPlayError>> {
+        self.check_plays(plays)
     }
 
     /// Takes a bimap of indexes of [tiles](crate::Tile) to be played to [coordinates](Coordinate)
-    /// on the board and returns earned points
+    /// on the board and returns the [score breakdown](ScoreBreakdown)
     /// if the bimap is a legal line. Otherwise, it returns the errors.
     ///
     /// # Points Calculation
@@ -146,7 +283,7 @@ impl FirstState {
     /// The number of points from a line is the number of [tiles](crate::Tile) in that line.
     /// If the line creates a full match on the board where a line contains
     /// either [every color](crate::Color::colors) or [every shape](crate::Shape::shapes),
-    /// an extra [FULL_MATCH_BONUS](FULL_MATCH_BONUS) points are earned.
+    /// an extra [full match bonus](crate::GameConfig::full_match_bonus) is earned.
     ///
     /// # Arguments
     ///
@@ -179,8 +316,8 @@ impl FirstState {
     ///
     /// # Returns
     ///
-    /// The earned points from [plays](Plays).
-    fn check_plays(&self, plays: &Plays) -> Result<usize, HashSet<FirstPlayError>> {
+    /// The [score breakdown](ScoreBreakdown) of [plays](Plays).
+    fn check_plays(&self, plays: &Plays) -> Result<ScoreBreakdown, HashSet<FirstPlayError>> {
         let mut errors = HashSet::with_capacity(9);
 
         if plays.is_empty() {
@@ -200,7 +337,8 @@ impl FirstState {
             });
         }
 
-        let (coordinates_in_bounds, coordinates_out_of_bounds) = partition_by_coordinates(plays);
+        let (coordinates_in_bounds, coordinates_out_of_bounds) =
+            partition_by_coordinates(plays, self.config.coordinate_limit());
         if !coordinates_out_of_bounds.is_empty() {
             errors.insert(FirstPlayError::CoordinatesOutOfBounds {
                 coordinates_out_of_bounds,
@@ -236,7 +374,7 @@ impl FirstState {
 
         let Some(holes) =
             FirstState::find_holes(&legal_plays, component_minimums_and_maximums,
-                                   mid_coordinate) else {
+                                   mid_coordinate, self.config.holes_limit()) else {
             errors.insert(FirstPlayError::NoLegalLines);
             return Err(errors);
         };
@@ -251,9 +389,9 @@ impl FirstState {
             .map(|(index, coordinate)| (coordinate, hand[index]))
             .collect();
 
-        let mut total_points = 0;
+        let mut score_breakdown = ScoreBreakdown::default();
 
-        match check_line(&line) {
+        match check_line(&line, self.config.full_match_bonus()) {
             Err((duplicates, multiple_matching)) => {
                 if !duplicates.is_empty() {
                     errors.insert(FirstPlayError::Duplicates { duplicates });
@@ -262,8 +400,8 @@ impl FirstState {
                     errors.insert(FirstPlayError::MultipleMatching { multiple_matching });
                 }
             }
-            Ok(points) => {
-                total_points += points;
+            Ok(line_score) => {
+                score_breakdown.lines.push(line_score);
             }
         }
 
@@ -271,7 +409,7 @@ impl FirstState {
             return Err(errors);
         }
 
-        Ok(total_points)
+        Ok(score_breakdown)
     }
 
     /// Finds holes in the line being played. If there are no holes, [None] is returned.
@@ -288,6 +426,7 @@ impl FirstState {
     /// with the minimum distance from the origin
     /// * `mid_y`: The y component in a potential line of the [coordinate](Coordinate)
     /// with the minimum distance from the origin
+    /// * `holes_limit`: The maximum number of holes that can be returned.
     ///
     /// # Returns
     ///
@@ -296,22 +435,23 @@ impl FirstState {
         legal_plays: &Plays,
         (min_x, min_y, max_x, max_y): (isize, isize, isize, isize),
         (mid_x, mid_y): Coordinate,
+        holes_limit: usize,
     ) -> Option<BTreeSet<(Coordinate, Coordinate)>> {
         // If the range of coordinates is large, hole will be large
         // and slow performance down. Limiting the range of coordinates
         // is necessary to limiting time and memory cost.
-        // Also, COORDINATE_LIMIT and HOLES_LIMIT should prevent overflow.
+        // Also, the coordinate limit and the holes limit should prevent overflow.
         if min_x == max_x {
             let increasing = (mid_y + 1..=max_y)
                 .filter(|&y| !legal_plays.contains_right(&(min_x, y)))
-                .take((HOLES_LIMIT + 1) / 2)
+                .take((holes_limit + 1) / 2)
                 .peekable()
                 .batching(batch_continuous_increasing_range)
                 .map(|(first, last)| ((min_x, first), (min_x, last)));
             let decreasing = (min_y..=mid_y - 1)
                 .rev()
                 .filter(|&y| !legal_plays.contains_right(&(min_x, y)))
-                .take((HOLES_LIMIT + HOLES_LIMIT % 2) / 2)
+                .take((holes_limit + holes_limit % 2) / 2)
                 .peekable()
                 .batching(batch_continuous_decreasing_range)
                 .map(|(first, last)| ((min_x, first), (min_x, last)));
@@ -319,14 +459,14 @@ impl FirstState {
         } else if min_y == max_y {
             let increasing = (mid_x + 1..=max_x)
                 .filter(|&x| !legal_plays.contains_right(&(x, min_y)))
-                .take((HOLES_LIMIT + 1) / 2)
+                .take((holes_limit + 1) / 2)
                 .peekable()
                 .batching(batch_continuous_increasing_range)
                 .map(|(first, last)| ((first, min_y), (last, min_y)));
             let decreasing = (min_x..=mid_x - 1)
                 .rev()
                 .filter(|&x| !legal_plays.contains_right(&(x, min_y)))
-                .take((HOLES_LIMIT + HOLES_LIMIT % 2) / 2)
+                .take((holes_limit + holes_limit % 2) / 2)
                 .peekable()
                 .batching(batch_continuous_decreasing_range)
                 .map(|(first, last)| ((first, min_y), (last, min_y)));
@@ -341,10 +481,11 @@ impl FirstState {
 mod tests {
     use super::*;
     use crate::{
-        random_illegal_coordinates, Color, Shape, Tile, COORDINATE_LIMIT, FULL_MATCH_BONUS,
+        random_illegal_coordinates, Board, Color, GameConfig, Shape, Tile, COORDINATE_LIMIT,
+        FULL_MATCH_BONUS, HOLES_LIMIT,
     };
     use bimap::BiBTreeMap;
-    use map_macro::{btree_set, hash_set};
+    use map_macro::{btree_map, btree_set, hash_set};
     use rand::Rng;
     use tap::Tap;
 
@@ -423,6 +564,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn coordinates_out_of_bounds_config() {
+        let mut rng = rand::thread_rng();
+        let mut first_state = FirstState::empty_first_state();
+        first_state.random_players(&mut rng);
+        first_state.hands[0].extend((0..=1).map(|_| rng.gen::<Tile>()));
+        first_state.max_matches[0] = 1;
+        first_state.config =
+            GameConfig::new(None, Some(1), None, None, None).expect("new should return Ok");
+
+        let illegal_plays: Plays = [(1, (1, 0))].into_iter().collect();
+
+        first_state.test_first_play_one_error(
+            illegal_plays.clone().tap_mut(|plays| {
+                plays.insert(0, (0, 0));
+            }),
+            FirstPlayError::CoordinatesOutOfBounds {
+                coordinates_out_of_bounds: illegal_plays.clone(),
+            },
+        );
+    }
+
     #[test]
     fn origin_not_included() {
         let (first_state, plays) = set_up_first_play();
@@ -659,11 +822,13 @@ mod tests {
         plays.extend([(0, (0, -1)), (1, (0, 0)), (3, (0, 1))]);
         let plays_len = plays.len();
         first_state.max_matches[0] = plays_len;
+        first_state.unique_tile_copied_count = 2;
 
-        let mut next_state = first_state
+        let (mut next_state, _) = first_state
             .first_play(&plays)
             .expect("first_play should return Ok");
 
+        assert_eq!(2, *next_state.mut_unique_tile_copied_count());
         let hand = &next_state.mut_hands()[0];
         assert_eq!(third, hand[0]);
         assert_eq!(bag_tile, hand[1]);
@@ -676,11 +841,51 @@ mod tests {
         assert_eq!(fourth, next_state.mut_board()[&(0, 1)]);
     }
 
+    #[test]
+    fn first_play_tiles_by_value() {
+        let (first_state, plays) = set_up_first_play();
+        let hand = first_state.hands[0].clone();
+        let tile_plays = plays
+            .iter()
+            .map(|(&index, &coordinate)| (coordinate, hand[index]))
+            .collect();
+
+        let (mut next_state, _) = first_state
+            .first_play_tiles(&tile_plays)
+            .expect("first_play_tiles should return Ok");
+
+        let board: Board = tile_plays.into_iter().collect();
+        assert_eq!(&board, next_state.mut_board());
+    }
+
+    #[test]
+    fn first_play_tiles_not_held() {
+        let (first_state, _) = set_up_first_play();
+        let tile = first_state.hands[0][0];
+        let tile_plays = btree_map! {
+            (0, 0) => tile,
+            (0, 1) => tile,
+        };
+
+        let (_, errors) = first_state
+            .first_play_tiles(&tile_plays)
+            .expect_err("first_play_tiles should return Err");
+
+        assert_eq!(
+            hash_set! {
+                FirstPlayError::TilesNotHeld {
+                    tiles_not_held: btree_map! { (0, 1) => tile },
+                }
+            },
+            errors
+        );
+    }
+
     #[test]
     fn first_play_some_points() {
         let (first_state, plays) = set_up_first_play();
 
-        let mut next_state = first_state
+        let (mut next_state, _) = first_state
             .first_play(&plays)
             .expect("first_play should return Ok");
 
@@ -691,18 +896,36 @@ mod tests {
     fn first_play_full_match() {
         let (first_state, plays) = set_up_first_play_full_match();
 
-        let mut next_state = first_state
+        let (mut next_state, score_breakdown) = first_state
             .first_play(&plays)
             .expect("first_play should return Ok");
 
         assert_eq!(plays.len() + FULL_MATCH_BONUS, next_state.mut_points()[0]);
+        assert_eq!(1, score_breakdown.lines.len());
+        assert_eq!(plays.len(), score_breakdown.lines[0].len);
+        assert!(score_breakdown.lines[0].full_match);
+        assert_eq!(FULL_MATCH_BONUS, score_breakdown.lines[0].full_match_bonus);
+        assert!(!score_breakdown.last_play);
+    }
+
+    #[test]
+    fn first_play_full_match_config() {
+        let (mut first_state, plays) = set_up_first_play_full_match();
+        first_state.config =
+            GameConfig::new(None, None, None, Some(1), None).expect("new should return Ok");
+
+        let (mut next_state, _) = first_state
+            .first_play(&plays)
+            .expect("first_play should return Ok");
+
+        assert_eq!(plays.len() + 1, next_state.mut_points()[0]);
     }
 
     #[test]
     fn first_play_increment_current_player() {
         let (first_state, plays) = set_up_first_play();
 
-        let mut next_state = first_state
+        let (mut next_state, _) = first_state
             .first_play(&plays)
             .expect("first_play should return Ok");
 
@@ -720,13 +943,80 @@ mod tests {
         last_hand.extend(first_hand);
         first_state.max_matches_to_hand_len();
 
-        let mut next_state = first_state
+        let (mut next_state, _) = first_state
             .first_play(&plays)
             .expect("first_play should return Ok");
 
         assert_eq!(0, *next_state.mut_current_player());
     }
 
+    #[test]
+    fn first_play_events() {
+        let (first_state, plays) = set_up_first_play();
+        let hand = first_state.hands[0].clone();
+
+        let (mut next_state, score_breakdown) = first_state
+            .first_play(&plays)
+            .expect("first_play should return Ok");
+
+        let tiles = plays
+            .iter()
+            .map(|(&index, &coordinate)| (index, coordinate, hand[index]))
+            .collect();
+        let drawn = next_state.mut_hands()[0].to_vec();
+        let total = next_state.mut_points()[0];
+        assert_eq!(
+            vec![
+                Event::TilesPlayed {
+                    player: 0,
+                    tiles,
+                    ids: None,
+                },
+                Event::TilesDrawn {
+                    player: 0,
+                    tiles: drawn,
+                    ids: None,
+                },
+                Event::PointsAwarded {
+                    player: 0,
+                    score_breakdown,
+                    total,
+                },
+                Event::TurnAdvanced { player: 1 },
+            ],
+            next_state.drain_events()
+        );
+        assert!(next_state.drain_events().is_empty());
+    }
+
+    #[test]
+    fn preview_play_some_points() {
+        let (first_state, plays) = set_up_first_play();
+        let hand = first_state.hands[0].clone();
+
+        let score_breakdown = first_state
+            .preview_play(&plays)
+            .expect("preview_play should return Ok");
+
+        assert_eq!(plays.len(), score_breakdown.points());
+        assert_eq!(hand, first_state.hands[0]);
+        assert_eq!(0, first_state.current_player);
+    }
+
+    #[test]
+    fn preview_play_errors() {
+        let mut rng = rand::thread_rng();
+        let mut first_state = FirstState::empty_first_state();
+        first_state.random_players(&mut rng);
+        first_state.random_hands(&mut rng);
+
+        let errors = first_state
+            .preview_play(&Plays::new())
+            .expect_err("preview_play should return Err");
+
+        assert_eq!(hash_set! { FirstPlayError::EmptyPlays }, errors);
+    }
+
     fn set_up_first_play() -> (FirstState, Plays) {
         let mut rng = rand::thread_rng();
         // avoid playing full match
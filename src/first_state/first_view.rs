@@ -1,8 +1,9 @@
-use crate::{FirstState, Hand, HandLens, MaxMatches};
+use crate::{FirstState, GameConfig, Hand, HandLens, MaxMatches, TileId};
 use smallvec::SmallVec;
 
 /// Immutably borrows properties from [FirstState].
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FirstView<'a> {
     /// This is the number of [tiles](crate::Tile) that haven't been removed yet.
     pub bag_len: usize,
@@ -13,6 +14,14 @@ pub struct FirstView<'a> {
     pub max_matches: &'a MaxMatches,
     /// The index of the player whose turn it is.
     pub current_player: usize,
+    /// The number of copies of each [tile](crate::Tile) the game started with.
+    pub unique_tile_copied_count: usize,
+    /// Limits and bonuses of the game.
+    pub config: &'a GameConfig,
+    /// The [identities](TileId) of the viewer's own hand in the same order as the hand, or
+    /// [None] if the view is [for every player](FirstState::first_view) or
+    /// [tile ids](crate::TileIds) are not tracked.
+    pub hand_ids: Option<&'a [TileId]>,
 }
 
 impl<'a> FirstState {
@@ -25,6 +34,25 @@ impl<'a> FirstState {
             hand_lens: self.hands.iter().map(SmallVec::len).collect(),
             max_matches: &self.max_matches,
             current_player: self.current_player,
+            unique_tile_copied_count: self.unique_tile_copied_count,
+            config: &self.config,
+            hand_ids: None,
+        }
+    }
+
+    /// Same as [first_view](FirstState::first_view), but with the [identities](TileId) of
+    /// the requesting player's own hand, which only that player should see.
+    ///
+    /// # Arguments
+    ///
+    /// * `player`: The index of the player who views the game.
+    pub fn first_view_for(&'a self, player: usize) -> FirstView<'a> {
+        FirstView {
+            hand_ids: self
+                .tile_ids
+                .as_ref()
+                .and_then(|tile_ids| tile_ids.hand(player)),
+            ..self.first_view()
         }
     }
 
@@ -55,6 +83,11 @@ mod tests {
         assert_eq!(hand_lens, first_view.hand_lens);
         assert_eq!(first_state.max_matches, *first_view.max_matches);
         assert_eq!(first_state.current_player, first_view.current_player);
+        assert_eq!(
+            first_state.unique_tile_copied_count,
+            first_view.unique_tile_copied_count
+        );
+        assert_eq!(first_state.config, *first_view.config);
     }
 
     #[test]
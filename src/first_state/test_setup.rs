@@ -1,8 +1,9 @@
 use crate::{
-    random_bag, random_current_player, random_hands, Bag, FirstState, Hand, Hands, MaxMatches,
-    Points, HAND_CAPACITY, PLAYER_CAPACITY, TILES_LEN,
+    random_bag, random_current_player, random_hands, Bag, FirstState, GameConfig, GameRng, Hand,
+    Hands, MaxMatches, Points, DEFAULT_UNIQUE_TILE_COPIED_COUNT, HAND_CAPACITY, PLAYER_CAPACITY,
+    TILES_LEN,
 };
-use rand::Rng;
+use rand::{Rng, SeedableRng};
 
 impl FirstState {
     /// Generates an empty [FirstState] with no players.
@@ -14,13 +15,24 @@ impl FirstState {
     /// * `hands`: An empty hands vector.
     /// * `max_matches`: An empty max matches vector.
     /// * `current_player`: `0`.
+    /// * `rng`: A [game rng](GameRng) seeded from `seed`.
+    /// * `seed`: A random seed.
+    /// * `unique_tile_copied_count`: [DEFAULT_UNIQUE_TILE_COPIED_COUNT].
+    /// * `config`: The [default config](GameConfig::default).
+    /// * `tile_ids`: [None].
     pub fn empty_first_state() -> FirstState {
         // capacity hardcoded to highest expected demand during test cases
+        let seed = rand::random();
         FirstState {
             bag: Bag::with_capacity(TILES_LEN),
             hands: Hands::with_capacity(PLAYER_CAPACITY),
             max_matches: MaxMatches::with_capacity(PLAYER_CAPACITY),
             current_player: 0,
+            rng: GameRng::seed_from_u64(seed),
+            seed,
+            unique_tile_copied_count: DEFAULT_UNIQUE_TILE_COPIED_COUNT,
+            config: GameConfig::default(),
+            tile_ids: None,
         }
     }
 
@@ -44,6 +56,21 @@ impl FirstState {
         &mut self.current_player
     }
 
+    /// A mutable reference to `self.rng`.
+    pub fn mut_rng(&mut self) -> &mut GameRng {
+        &mut self.rng
+    }
+
+    /// A mutable reference to `self.unique_tile_copied_count`.
+    pub fn mut_unique_tile_copied_count(&mut self) -> &mut usize {
+        &mut self.unique_tile_copied_count
+    }
+
+    /// A mutable reference to `self.config`.
+    pub fn mut_config(&mut self) -> &mut GameConfig {
+        &mut self.config
+    }
+
     /// It inserts a random, small, non-zero number of empty hands into hands
     /// and `0`s into max matches.
     ///
@@ -0,0 +1,670 @@
+use crate::{
+    Event, Exchanges, FirstPlayError, FirstState, Hand, LastState, NextExchangeError,
+    NextPassError, NextPlayError, NextState, Plays, ReorderHandError, ScoreBreakdown,
+    TileExchanges, TileIds, TilePlays,
+};
+use either::Either;
+use std::collections::HashSet;
+
+/// Owns game state in any phase of the game and advances the game by
+/// [applying](Game::apply) [actions](Action).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Game {
+    /// The `First` phase of the game. Represented by [FirstState].
+    First(FirstState),
+    /// The `Next` phase of the game. Represented by [NextState].
+    Next(NextState),
+    /// The `Last` phase of the game. Represented by [LastState].
+    Last(LastState),
+}
+
+/// Describes how the current player advances the game, or how any player reorders their hand
+/// without taking a turn.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Action {
+    /// [Play](Plays) [tiles](crate::Tile) with [FirstState::first_play] during the `First` phase
+    /// or [NextState::next_play] during the `Next` phase.
+    Play(#[cfg_attr(feature = "serde", serde(with = "crate::serialization::plays"))] Plays),
+    /// [Exchange](Exchanges) [tiles](crate::Tile) with [NextState::next_exchange] during
+    /// the `Next` phase.
+    Exchange(Exchanges),
+    /// [Play](TilePlays) [tiles](crate::Tile) by value with [FirstState::first_play_tiles]
+    /// during the `First` phase or [NextState::next_play_tiles] during the `Next` phase.
+    PlayTiles(
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::tile_plays"))] TilePlays,
+    ),
+    /// [Exchange](TileExchanges) [tiles](crate::Tile) by value with
+    /// [NextState::next_exchange_tiles] during the `Next` phase.
+    ExchangeTiles(TileExchanges),
+    /// [Pass](NextState::next_pass) during the `Next` phase.
+    Pass,
+    /// [Reorder](NextState::reorder_hand) the player's hand during the `Next` phase, which is
+    /// not a turn, so the current player does not change.
+    ReorderHand {
+        /// The index of the player whose hand is reordered.
+        player: usize,
+        /// The index in the hand before reordering of each [tile](crate::Tile) in
+        /// the new order.
+        permutation: Vec<usize>,
+    },
+}
+
+/// Describes the reason why an [action](Action) could not be [applied](Game::apply).
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ActionError {
+    /// Attempting [to exchange](Action::Exchange) or
+    /// [to exchange by value](Action::ExchangeTiles) during the `First` phase.
+    FirstExchange,
+    /// Attempting [to pass](Action::Pass) during the `First` phase.
+    FirstPass,
+    /// Attempting [to reorder](Action::ReorderHand) a hand during the `First` phase.
+    FirstReorderHand,
+    /// Attempting to apply any [action](Action) after the game has ended.
+    HasEnded,
+    /// Attempting [to play](FirstState::first_play) illegal [plays](Plays) or
+    /// [tile plays](TilePlays) during the `First` phase.
+    FirstPlay(HashSet<FirstPlayError>),
+    /// Attempting [to play](NextState::next_play) illegal [plays](Plays) or
+    /// [tile plays](TilePlays) during the `Next` phase.
+    NextPlay(HashSet<NextPlayError>),
+    /// Attempting [to exchange](NextState::next_exchange) illegal [exchanges](Exchanges) or
+    /// [tile exchanges](TileExchanges) during the `Next` phase.
+    NextExchange(HashSet<NextExchangeError>),
+    /// Attempting [to pass](NextState::next_pass) illegally during the `Next` phase.
+    NextPass(HashSet<NextPassError>),
+    /// Attempting [to reorder](NextState::reorder_hand) a hand illegally during
+    /// the `Next` phase.
+    ReorderHand(HashSet<ReorderHandError>),
+    /// Attempting [to reorder](Action::ReorderHand) the hand of another player while
+    /// the game is [driven](crate::drive_game). [Game::apply] lets any player reorder
+    /// their hand, so it never returns this error.
+    ReorderOtherHand {
+        /// The index of the player whose hand was to be reordered.
+        player: usize,
+        /// The index of the player whose turn it is.
+        current_player: usize,
+    },
+}
+
+impl Game {
+    /// Applies the [action](Action) to the phase of the game, and then wraps the resulting
+    /// state in the phase of the game it advanced to.
+    ///
+    /// # Arguments
+    ///
+    /// * `action`: Describes how the current player advances the game, or how any player
+    /// reorders their hand.
+    ///
+    /// # Errors
+    ///
+    /// * [ActionError::FirstExchange] Attempting [to exchange](Action::Exchange) or
+    /// [to exchange by value](Action::ExchangeTiles) during the `First` phase.
+    /// * [ActionError::FirstPass] Attempting [to pass](Action::Pass) during the `First` phase.
+    /// * [ActionError::FirstReorderHand] Attempting [to reorder](Action::ReorderHand) a hand
+    /// during the `First` phase.
+    /// * [ActionError::HasEnded] Attempting to apply any [action](Action) after the game
+    /// has ended.
+    /// * [ActionError::FirstPlay] Attempting [to play](FirstState::first_play) illegal
+    /// [plays](Plays) or [tile plays](TilePlays) during the `First` phase.
+    /// * [ActionError::NextPlay] Attempting [to play](NextState::next_play) illegal
+    /// [plays](Plays) or [tile plays](TilePlays) during the `Next` phase.
+    /// * [ActionError::NextExchange] Attempting [to exchange](NextState::next_exchange)
+    /// illegal [exchanges](Exchanges) or [tile exchanges](TileExchanges) during
+    /// the `Next` phase.
+    /// * [ActionError::NextPass] Attempting [to pass](NextState::next_pass) illegally during
+    /// the `Next` phase.
+    /// * [ActionError::ReorderHand] Attempting [to reorder](NextState::reorder_hand) a hand
+    /// illegally during the `Next` phase.
+    ///
+    /// # Returns
+    ///
+    /// The [game](Game) after the [action](Action) and the [score breakdown](ScoreBreakdown)
+    /// when the [action](Action) is a [play](Action::Play) or
+    /// a [play by value](Action::PlayTiles).
+    pub fn apply(
+        self,
+        action: &Action,
+    ) -> Result<(Game, Option<ScoreBreakdown>), (Game, ActionError)> {
+        match (self, action) {
+            (Game::First(first_state), Action::Play(plays)) => {
+                match first_state.first_play(plays) {
+                    Ok((next_state, score_breakdown)) => {
+                        Ok((Game::Next(next_state), Some(score_breakdown)))
+                    }
+                    Err((first_state, errors)) => {
+                        Err((Game::First(first_state), ActionError::FirstPlay(errors)))
+                    }
+                }
+            }
+            (Game::First(first_state), Action::PlayTiles(tile_plays)) => {
+                match first_state.first_play_tiles(tile_plays) {
+                    Ok((next_state, score_breakdown)) => {
+                        Ok((Game::Next(next_state), Some(score_breakdown)))
+                    }
+                    Err((first_state, errors)) => {
+                        Err((Game::First(first_state), ActionError::FirstPlay(errors)))
+                    }
+                }
+            }
+            (Game::First(first_state), Action::Exchange(_) | Action::ExchangeTiles(_)) => {
+                Err((Game::First(first_state), ActionError::FirstExchange))
+            }
+            (Game::First(first_state), Action::Pass) => {
+                Err((Game::First(first_state), ActionError::FirstPass))
+            }
+            (Game::First(first_state), Action::ReorderHand { .. }) => {
+                Err((Game::First(first_state), ActionError::FirstReorderHand))
+            }
+            (Game::Next(next_state), Action::Play(plays)) => match next_state.next_play(plays) {
+                Ok((either, score_breakdown)) => Ok((Game::from(either), Some(score_breakdown))),
+                Err((next_state, errors)) => {
+                    Err((Game::Next(next_state), ActionError::NextPlay(errors)))
+                }
+            },
+            (Game::Next(next_state), Action::PlayTiles(tile_plays)) => {
+                match next_state.next_play_tiles(tile_plays) {
+                    Ok((either, score_breakdown)) => {
+                        Ok((Game::from(either), Some(score_breakdown)))
+                    }
+                    Err((next_state, errors)) => {
+                        Err((Game::Next(next_state), ActionError::NextPlay(errors)))
+                    }
+                }
+            }
+            (Game::Next(mut next_state), Action::Exchange(exchanges)) => {
+                match next_state.next_exchange(exchanges) {
+                    Ok(()) => Ok((Game::Next(next_state), None)),
+                    Err(errors) => Err((Game::Next(next_state), ActionError::NextExchange(errors))),
+                }
+            }
+            (Game::Next(mut next_state), Action::ExchangeTiles(tile_exchanges)) => {
+                match next_state.next_exchange_tiles(tile_exchanges) {
+                    Ok(()) => Ok((Game::Next(next_state), None)),
+                    Err(errors) => Err((Game::Next(next_state), ActionError::NextExchange(errors))),
+                }
+            }
+            (Game::Next(next_state), Action::Pass) => match next_state.next_pass() {
+                Ok(either) => Ok((Game::from(either), None)),
+                Err((next_state, errors)) => {
+                    Err((Game::Next(next_state), ActionError::NextPass(errors)))
+                }
+            },
+            (
+                Game::Next(mut next_state),
+                Action::ReorderHand {
+                    player,
+                    permutation,
+                },
+            ) => match next_state.reorder_hand(*player, permutation) {
+                Ok(()) => Ok((Game::Next(next_state), None)),
+                Err(errors) => Err((Game::Next(next_state), ActionError::ReorderHand(errors))),
+            },
+            (game @ Game::Last(_), _) => Err((game, ActionError::HasEnded)),
+        }
+    }
+
+    /// The index of the player whose turn it is or [None] if the game has ended.
+    pub fn current_player(&self) -> Option<usize> {
+        match self {
+            Game::First(first_state) => Some(first_state.current_player()),
+            Game::Next(next_state) => Some(next_state.current_player()),
+            Game::Last(_) => None,
+        }
+    }
+
+    /// A vector of [tiles](crate::Tile) held by the requesting player or [None] if out of bounds.
+    pub fn get_hand(&self, index: usize) -> Option<&Hand> {
+        match self {
+            Game::First(first_state) => first_state.get_hand(index),
+            Game::Next(next_state) => next_state.get_hand(index),
+            Game::Last(last_state) => last_state.last_view().hands.get(index),
+        }
+    }
+
+    /// The [identities](crate::TileId) of every [tile](crate::Tile) or [None] if
+    /// [tile ids](TileIds) are not tracked.
+    pub fn tile_ids(&self) -> Option<&TileIds> {
+        match self {
+            Game::First(first_state) => first_state.tile_ids(),
+            Game::Next(next_state) => next_state.tile_ids(),
+            Game::Last(last_state) => last_state.tile_ids(),
+        }
+    }
+
+    /// Whether the game has reached the `Last` phase.
+    pub fn has_ended(&self) -> bool {
+        matches!(self, Game::Last(_))
+    }
+
+    /// Takes every [event](Event) in the order they happened since the last call. The `First`
+    /// phase has no [events](Event).
+    pub fn drain_events(&mut self) -> Vec<Event> {
+        match self {
+            Game::First(_) => Vec::new(),
+            Game::Next(next_state) => next_state.drain_events(),
+            Game::Last(last_state) => last_state.drain_events(),
+        }
+    }
+}
+
+impl From<FirstState> for Game {
+    fn from(first_state: FirstState) -> Self {
+        Game::First(first_state)
+    }
+}
+
+impl From<NextState> for Game {
+    fn from(next_state: NextState) -> Self {
+        Game::Next(next_state)
+    }
+}
+
+impl From<LastState> for Game {
+    fn from(last_state: LastState) -> Self {
+        Game::Last(last_state)
+    }
+}
+
+impl From<Either<NextState, LastState>> for Game {
+    fn from(either: Either<NextState, LastState>) -> Self {
+        either.either(Game::Next, Game::Last)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color, Shape};
+    use map_macro::{btree_map, btree_set, hash_set};
+    use rand::Rng;
+
+    #[test]
+    fn first_play() {
+        let mut rng = rand::thread_rng();
+        let mut first_state = FirstState::empty_first_state();
+        first_state.random_players(&mut rng);
+        first_state.random_bag(&mut rng);
+        first_state.mut_hands()[0].push(rng.gen());
+        first_state.max_matches_to_hand_len();
+
+        let (game, score_breakdown) = Game::from(first_state)
+            .apply(&Action::Play([(0, (0, 0))].into_iter().collect()))
+            .expect("apply should return Ok");
+
+        assert!(matches!(game, Game::Next(_)));
+        assert_eq!(Some(1), game.current_player());
+        assert!(score_breakdown.is_some());
+    }
+
+    #[test]
+    fn first_play_error() {
+        let mut rng = rand::thread_rng();
+        let mut first_state = FirstState::empty_first_state();
+        first_state.random_players(&mut rng);
+        first_state.random_hands(&mut rng);
+
+        let (game, error) = Game::from(first_state)
+            .apply(&Action::Play(Plays::new()))
+            .expect_err("apply should return Err");
+
+        assert!(matches!(game, Game::First(_)));
+        assert!(matches!(error, ActionError::FirstPlay(_)));
+    }
+
+    #[test]
+    fn first_exchange() {
+        let mut rng = rand::thread_rng();
+        let mut first_state = FirstState::empty_first_state();
+        first_state.random_players(&mut rng);
+        first_state.random_bag(&mut rng);
+        first_state.random_hands(&mut rng);
+
+        let (game, error) = Game::from(first_state)
+            .apply(&Action::Exchange(btree_set! { 0 }))
+            .expect_err("apply should return Err");
+
+        assert!(matches!(game, Game::First(_)));
+        assert_eq!(ActionError::FirstExchange, error);
+    }
+
+    #[test]
+    fn first_play_tiles() {
+        let mut rng = rand::thread_rng();
+        let mut first_state = FirstState::empty_first_state();
+        first_state.random_players(&mut rng);
+        first_state.random_bag(&mut rng);
+        let tile = rng.gen();
+        first_state.mut_hands()[0].push(tile);
+        first_state.max_matches_to_hand_len();
+
+        let (game, score_breakdown) = Game::from(first_state)
+            .apply(&Action::PlayTiles(btree_map! { (0, 0) => tile }))
+            .expect("apply should return Ok");
+
+        assert!(matches!(game, Game::Next(_)));
+        assert!(score_breakdown.is_some());
+    }
+
+    #[test]
+    fn first_exchange_tiles() {
+        let mut rng = rand::thread_rng();
+        let mut first_state = FirstState::empty_first_state();
+        first_state.random_players(&mut rng);
+        first_state.random_bag(&mut rng);
+        first_state.random_hands(&mut rng);
+        let tile = first_state.mut_hands()[0][0];
+
+        let (game, error) = Game::from(first_state)
+            .apply(&Action::ExchangeTiles(vec![tile]))
+            .expect_err("apply should return Err");
+
+        assert!(matches!(game, Game::First(_)));
+        assert_eq!(ActionError::FirstExchange, error);
+    }
+
+    #[test]
+    fn next_play_tiles() {
+        let mut rng = rand::thread_rng();
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rng);
+        next_state.random_bag(&mut rng);
+        next_state
+            .mut_board()
+            .insert((0, 0), (Color::Red, Shape::Circle));
+        next_state.mut_hands()[0].extend([(Color::Blue, Shape::X), (Color::Red, Shape::Square)]);
+
+        let (game, score_breakdown) = Game::from(next_state)
+            .apply(&Action::PlayTiles(
+                btree_map! { (1, 0) => (Color::Red, Shape::Square) },
+            ))
+            .expect("apply should return Ok");
+
+        assert_eq!(Some(1), game.current_player());
+        assert_eq!(
+            (Color::Blue, Shape::X),
+            game.get_hand(0).expect("get_hand should return Some")[0]
+        );
+        assert!(score_breakdown.is_some());
+    }
+
+    #[test]
+    fn next_play_tiles_not_held() {
+        let mut rng = rand::thread_rng();
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rng);
+        next_state
+            .mut_board()
+            .insert((0, 0), (Color::Red, Shape::Circle));
+        next_state.mut_hands()[0].push((Color::Blue, Shape::X));
+        let tile_plays = btree_map! { (1, 0) => (Color::Red, Shape::Square) };
+
+        let (game, error) = Game::from(next_state)
+            .apply(&Action::PlayTiles(tile_plays.clone()))
+            .expect_err("apply should return Err");
+
+        assert!(matches!(game, Game::Next(_)));
+        assert_eq!(
+            ActionError::NextPlay(hash_set! {
+                NextPlayError::TilesNotHeld {
+                    tiles_not_held: tile_plays,
+                }
+            }),
+            error
+        );
+    }
+
+    #[test]
+    fn next_exchange_tiles() {
+        let mut rng = rand::thread_rng();
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rng);
+        next_state.random_bag(&mut rng);
+        next_state.random_board(&mut rng);
+        next_state.random_hands(&mut rng);
+        let tile = next_state.mut_hands()[0][0];
+
+        let (game, score_breakdown) = Game::from(next_state)
+            .apply(&Action::ExchangeTiles(vec![tile]))
+            .expect("apply should return Ok");
+
+        assert!(matches!(game, Game::Next(_)));
+        assert_eq!(Some(1), game.current_player());
+        assert!(score_breakdown.is_none());
+    }
+
+    #[test]
+    fn next_exchange_tiles_not_held() {
+        let mut rng = rand::thread_rng();
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rng);
+        next_state.random_bag(&mut rng);
+        next_state.random_board(&mut rng);
+        next_state.mut_hands()[0].push((Color::Blue, Shape::X));
+        let tile_exchanges = vec![(Color::Blue, Shape::X), (Color::Blue, Shape::X)];
+
+        let (game, error) = Game::from(next_state)
+            .apply(&Action::ExchangeTiles(tile_exchanges))
+            .expect_err("apply should return Err");
+
+        assert!(matches!(game, Game::Next(_)));
+        assert_eq!(
+            ActionError::NextExchange(hash_set! {
+                NextExchangeError::TilesNotHeld {
+                    tiles_not_held: vec![(Color::Blue, Shape::X)],
+                }
+            }),
+            error
+        );
+    }
+
+    #[test]
+    fn next_exchange() {
+        let mut rng = rand::thread_rng();
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rng);
+        next_state.random_bag(&mut rng);
+        next_state.random_board(&mut rng);
+        next_state.random_hands(&mut rng);
+
+        let (game, score_breakdown) = Game::from(next_state)
+            .apply(&Action::Exchange(btree_set! { 0 }))
+            .expect("apply should return Ok");
+
+        assert!(matches!(game, Game::Next(_)));
+        assert_eq!(Some(1), game.current_player());
+        assert!(score_breakdown.is_none());
+    }
+
+    #[test]
+    fn next_exchange_error() {
+        let mut rng = rand::thread_rng();
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rng);
+        next_state.random_board(&mut rng);
+        next_state.random_hands(&mut rng);
+
+        let (game, error) = Game::from(next_state)
+            .apply(&Action::Exchange(btree_set! { 0 }))
+            .expect_err("apply should return Err");
+
+        assert!(matches!(game, Game::Next(_)));
+        assert!(matches!(error, ActionError::NextExchange(_)));
+    }
+
+    #[test]
+    fn first_pass() {
+        let mut rng = rand::thread_rng();
+        let mut first_state = FirstState::empty_first_state();
+        first_state.random_players(&mut rng);
+        first_state.random_hands(&mut rng);
+
+        let (game, error) = Game::from(first_state)
+            .apply(&Action::Pass)
+            .expect_err("apply should return Err");
+
+        assert!(matches!(game, Game::First(_)));
+        assert_eq!(ActionError::FirstPass, error);
+    }
+
+    #[test]
+    fn next_pass() {
+        let mut rng = rand::thread_rng();
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rng);
+        next_state
+            .mut_board()
+            .insert((0, 0), (Color::Red, Shape::Circle));
+        next_state.mut_hands()[0].push((Color::Red, Shape::Square));
+
+        let (game, score_breakdown) = Game::from(next_state)
+            .apply(&Action::Pass)
+            .expect("apply should return Ok");
+
+        assert!(matches!(game, Game::Next(_)));
+        assert_eq!(Some(1), game.current_player());
+        assert!(score_breakdown.is_none());
+    }
+
+    #[test]
+    fn next_pass_error() {
+        let mut rng = rand::thread_rng();
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rng);
+        next_state.random_bag(&mut rng);
+        next_state.random_board(&mut rng);
+        next_state.random_hands(&mut rng);
+
+        let (game, error) = Game::from(next_state)
+            .apply(&Action::Pass)
+            .expect_err("apply should return Err");
+
+        assert!(matches!(game, Game::Next(_)));
+        assert!(matches!(error, ActionError::NextPass(_)));
+    }
+
+    #[test]
+    fn first_reorder_hand() {
+        let mut rng = rand::thread_rng();
+        let mut first_state = FirstState::empty_first_state();
+        first_state.random_players(&mut rng);
+        first_state.random_hands(&mut rng);
+
+        let (game, error) = Game::from(first_state)
+            .apply(&Action::ReorderHand {
+                player: 0,
+                permutation: vec![0],
+            })
+            .expect_err("apply should return Err");
+
+        assert!(matches!(game, Game::First(_)));
+        assert_eq!(ActionError::FirstReorderHand, error);
+    }
+
+    #[test]
+    fn next_reorder_hand() {
+        let mut rng = rand::thread_rng();
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rng);
+        next_state.random_board(&mut rng);
+        next_state.mut_hands()[1].extend([(Color::Red, Shape::Square), (Color::Blue, Shape::X)]);
+
+        let (game, score_breakdown) = Game::from(next_state)
+            .apply(&Action::ReorderHand {
+                player: 1,
+                permutation: vec![1, 0],
+            })
+            .expect("apply should return Ok");
+
+        assert_eq!(Some(0), game.current_player());
+        assert_eq!(
+            Some(
+                &[(Color::Blue, Shape::X), (Color::Red, Shape::Square)]
+                    .into_iter()
+                    .collect()
+            ),
+            game.get_hand(1)
+        );
+        assert!(score_breakdown.is_none());
+    }
+
+    #[test]
+    fn next_reorder_hand_error() {
+        let mut rng = rand::thread_rng();
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rng);
+        next_state.mut_hands()[0].push((Color::Red, Shape::Square));
+
+        let (game, error) = Game::from(next_state)
+            .apply(&Action::ReorderHand {
+                player: 0,
+                permutation: vec![0, 0],
+            })
+            .expect_err("apply should return Err");
+
+        assert!(matches!(game, Game::Next(_)));
+        assert!(matches!(error, ActionError::ReorderHand(_)));
+    }
+
+    #[test]
+    fn next_play_last() {
+        let mut rng = rand::thread_rng();
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rng);
+        next_state
+            .mut_board()
+            .insert((0, 0), (Color::Red, Shape::Circle));
+        next_state.mut_hands()[0].push((Color::Red, Shape::Square));
+
+        let (game, score_breakdown) = Game::from(next_state)
+            .apply(&Action::Play([(0, (1, 0))].into_iter().collect()))
+            .expect("apply should return Ok");
+
+        assert!(game.has_ended());
+        assert_eq!(None, game.current_player());
+        assert!(
+            score_breakdown
+                .expect("play should return some score_breakdown")
+                .last_play
+        );
+    }
+
+    #[test]
+    fn has_ended() {
+        let last_state = LastState::random_last_state(&mut rand::thread_rng());
+
+        let (game, error) = Game::from(last_state)
+            .apply(&Action::Exchange(btree_set! { 0 }))
+            .expect_err("apply should return Err");
+
+        assert!(game.has_ended());
+        assert_eq!(ActionError::HasEnded, error);
+    }
+
+    #[test]
+    fn get_hand() {
+        let mut rng = rand::thread_rng();
+        let mut next_state = NextState::empty_next_state();
+        let players = next_state.random_players(&mut rng);
+        next_state.random_hands(&mut rng);
+        let hand = next_state.mut_hands()[0].clone();
+
+        let game = Game::from(next_state);
+
+        assert_eq!(Some(&hand), game.get_hand(0));
+        assert!(game.get_hand(players).is_none());
+    }
+
+    #[test]
+    fn tile_ids() {
+        let first_state = FirstState::new_random_first_player(2, None, None, None)
+            .expect("new_random_first_player should return Ok");
+
+        assert!(Game::from(first_state.clone()).tile_ids().is_none());
+        assert!(Game::from(first_state.track_tile_ids())
+            .tile_ids()
+            .is_some());
+    }
+}
@@ -0,0 +1,284 @@
+use crate::{possible_plays, Action, Exchanges, GameRng, Hand, Player, PlayerView, Tile};
+use async_trait::async_trait;
+use itertools::Itertools;
+use rand::{Rng, SeedableRng};
+use std::collections::BTreeSet;
+
+/// A reference [player](Player) which always [plays](Action::Play) the highest-scoring legal
+/// [play](crate::Plays) from its hand. When nothing can be played, it
+/// [exchanges](Action::Exchange) its least useful [tiles](Tile), or [passes](Action::Pass) once
+/// the bag is empty.
+///
+/// The least useful [tiles](Tile) are every [tile](Tile) outside the largest group of unique
+/// [tiles](Tile) which match each other, or the whole hand when every [tile](Tile) is in that
+/// group. No more [tiles](Tile) are exchanged than are left in the bag.
+///
+/// Ties between equally good [plays](crate::Plays) or groups are broken by the first one in
+/// order unless the player was created with a [game rng](GameRng), which chooses uniformly
+/// between them instead.
+#[derive(Debug, Clone, Default)]
+pub struct GreedyPlayer {
+    /// Breaks ties when [Some], or [None] to take the first of the ties.
+    rng: Option<GameRng>,
+}
+
+impl GreedyPlayer {
+    /// A new [GreedyPlayer] struct which breaks ties deterministically.
+    pub fn new() -> GreedyPlayer {
+        GreedyPlayer { rng: None }
+    }
+
+    /// A new [GreedyPlayer] struct which breaks ties with `rng`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng`: Chooses uniformly between equally good [plays](crate::Plays) or groups.
+    pub fn new_with_rng(rng: GameRng) -> GreedyPlayer {
+        GreedyPlayer { rng: Some(rng) }
+    }
+
+    /// Same as [GreedyPlayer::new_with_rng], but the [game rng](GameRng) is seeded from `seed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed`: Seeds the [game rng](GameRng) which breaks ties.
+    pub fn new_with_seed(seed: u64) -> GreedyPlayer {
+        GreedyPlayer::new_with_rng(GameRng::seed_from_u64(seed))
+    }
+
+    /// Chooses the [action](Action) without waiting.
+    ///
+    /// # Arguments
+    ///
+    /// * `view`: The part of the game visible to every player.
+    /// * `hand`: The [tiles](Tile) held by the player.
+    ///
+    /// # See Also
+    ///
+    /// * [Player::choose_action]
+    pub fn choose(&mut self, view: PlayerView<'_>, hand: &[Tile]) -> Action {
+        match view {
+            PlayerView::First(first_view) => {
+                let max_match = first_view.max_matches[first_view.current_player];
+                // every max matching play is a single line scoring the same points
+                let indexes = self
+                    .break_tie(possible_plays::<Vec<Vec<usize>>, _>(
+                        hand.iter().copied(),
+                        max_match,
+                    ))
+                    .unwrap_or_default();
+                Action::Play(
+                    (0..)
+                        .zip(indexes)
+                        .map(|(x, index)| (index, (x, 0)))
+                        .collect(),
+                )
+            }
+            PlayerView::Next(next_view) => {
+                let legal_plays = next_view.legal_plays(hand);
+                let max_points = legal_plays
+                    .iter()
+                    .map(|(_, score_breakdown)| score_breakdown.points())
+                    .max();
+                let best_plays = legal_plays
+                    .into_iter()
+                    .filter(|(_, score_breakdown)| Some(score_breakdown.points()) == max_points)
+                    .map(|(plays, _)| plays)
+                    .collect();
+                if let Some(plays) = self.break_tie(best_plays) {
+                    Action::Play(plays)
+                } else if next_view.bag_len == 0 {
+                    Action::Pass
+                } else {
+                    Action::Exchange(self.least_useful(hand, next_view.bag_len))
+                }
+            }
+        }
+    }
+
+    /// Finds the indexes of [tiles](Tile) outside the largest group of unique matching
+    /// [tiles](Tile), or every index when the whole hand is that group.
+    ///
+    /// # Arguments
+    ///
+    /// * `hand`: The [tiles](Tile) held by the player.
+    /// * `bag_len`: The maximum number of indexes.
+    pub(crate) fn least_useful(&mut self, hand: &[Tile], bag_len: usize) -> Exchanges {
+        let kept = (1..=hand.len())
+            .rev()
+            .map(|k| possible_plays::<Vec<BTreeSet<usize>>, _>(hand.iter().copied(), k))
+            .find(|groups| !groups.is_empty())
+            .and_then(|groups| self.break_tie(groups))
+            .unwrap_or_default();
+
+        let exchanges = (0..hand.len())
+            .filter(|index| !kept.contains(index))
+            .collect_vec();
+        if exchanges.is_empty() {
+            (0..hand.len()).take(bag_len).collect()
+        } else {
+            exchanges.into_iter().take(bag_len).collect()
+        }
+    }
+
+    /// Takes the first of `ties` or a uniformly chosen one if there is a [game rng](GameRng).
+    fn break_tie<T>(&mut self, mut ties: Vec<T>) -> Option<T> {
+        match &mut self.rng {
+            Some(rng) if !ties.is_empty() => Some(ties.swap_remove(rng.gen_range(0..ties.len()))),
+            _ => ties.into_iter().next(),
+        }
+    }
+}
+
+#[async_trait]
+impl Player for GreedyPlayer {
+    async fn choose_action(&mut self, view: PlayerView<'_>, hand: &Hand) -> Action {
+        self.choose(view, hand)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{drive_game, Color, FirstState, NextState, Shape};
+    use futures::executor::block_on;
+    use map_macro::btree_set;
+
+    #[test]
+    fn choose_first_play() {
+        let mut first_state = FirstState::empty_first_state();
+        first_state.mut_hands().push(Hand::from_slice(&[
+            (Color::Blue, Shape::X),
+            (Color::Red, Shape::Circle),
+            (Color::Red, Shape::Square),
+        ]));
+        first_state.mut_max_matches().push(2);
+        let hand = first_state
+            .get_hand(0)
+            .expect("get_hand should return Some");
+
+        let action = GreedyPlayer::new().choose(PlayerView::First(first_state.first_view()), hand);
+
+        let plays = [(1, (0, 0)), (2, (1, 0))].into_iter().collect();
+        assert!(first_state.preview_play(&plays).is_ok());
+        assert_eq!(Action::Play(plays), action);
+    }
+
+    #[test]
+    fn choose_highest_scoring_play() {
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rand::thread_rng());
+        next_state
+            .mut_board()
+            .insert((0, 0), (Color::Red, Shape::Circle));
+        next_state.mut_hands()[0].extend([
+            (Color::Blue, Shape::Circle),
+            (Color::Red, Shape::Square),
+            (Color::Red, Shape::X),
+        ]);
+        let hand = next_state.mut_hands()[0].clone();
+        let max_points = next_state
+            .legal_plays()
+            .into_iter()
+            .map(|(_, score_breakdown)| score_breakdown.points())
+            .max();
+
+        let Action::Play(plays) =
+            GreedyPlayer::new().choose(PlayerView::Next(next_state.next_view()), &hand)
+        else {
+            panic!("choose should return Action::Play");
+        };
+
+        assert_eq!(2, plays.len());
+        assert_eq!(
+            max_points,
+            next_state
+                .preview_play(&plays)
+                .ok()
+                .map(|score_breakdown| score_breakdown.points())
+        );
+    }
+
+    #[test]
+    fn choose_exchange_least_useful() {
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rand::thread_rng());
+        next_state.mut_bag().push((Color::Green, Shape::Diamond));
+        next_state
+            .mut_board()
+            .insert((0, 0), (Color::Red, Shape::Circle));
+        let hand = [
+            (Color::Blue, Shape::Square),
+            (Color::Yellow, Shape::X),
+            (Color::Blue, Shape::X),
+        ];
+
+        let mut greedy_player = GreedyPlayer::new();
+
+        assert_eq!(
+            Action::Exchange(btree_set! { 1 }),
+            greedy_player.choose(PlayerView::Next(next_state.next_view()), &hand)
+        );
+        next_state.mut_bag().clear();
+        assert_eq!(
+            Action::Pass,
+            greedy_player.choose(PlayerView::Next(next_state.next_view()), &hand)
+        );
+    }
+
+    #[test]
+    fn choose_exchange_whole_hand() {
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rand::thread_rng());
+        next_state.random_bag(&mut rand::thread_rng());
+        next_state
+            .mut_board()
+            .insert((0, 0), (Color::Red, Shape::Circle));
+        let hand = [(Color::Blue, Shape::Square), (Color::Blue, Shape::X)];
+
+        assert_eq!(
+            Action::Exchange(btree_set! { 0, 1 }),
+            GreedyPlayer::new().choose(PlayerView::Next(next_state.next_view()), &hand)
+        );
+    }
+
+    #[test]
+    fn choose_with_seed() {
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rand::thread_rng());
+        next_state
+            .mut_board()
+            .insert((0, 0), (Color::Red, Shape::Circle));
+        let hand = [(Color::Red, Shape::Square)];
+
+        let choose = |seed| {
+            let mut greedy_player = GreedyPlayer::new_with_seed(seed);
+            (0..8)
+                .map(|_| greedy_player.choose(PlayerView::Next(next_state.next_view()), &hand))
+                .collect_vec()
+        };
+
+        let actions = choose(7);
+        assert_eq!(actions, choose(7));
+        assert!(actions.iter().unique().count() > 1);
+        assert_eq!(
+            GreedyPlayer::new().choose(PlayerView::Next(next_state.next_view()), &hand),
+            GreedyPlayer::new().choose(PlayerView::Next(next_state.next_view()), &hand)
+        );
+    }
+
+    #[test]
+    fn drive_game_greedy_players() {
+        let first_state = FirstState::new_random_first_player_with_seed(2, Some(1), None, None, 11)
+            .expect("new_random_first_player_with_seed should return Ok");
+        let mut players: Vec<Box<dyn Player>> = vec![
+            Box::new(GreedyPlayer::new()),
+            Box::new(GreedyPlayer::new_with_seed(11)),
+        ];
+
+        let mut last_state = block_on(drive_game(first_state, &mut players, 0, 0))
+            .expect("drive_game should return Ok");
+
+        assert!(!last_state.mut_board().is_empty());
+    }
+}
@@ -0,0 +1,309 @@
+use crate::{Action, ActionError, Event, Game, ScoreBreakdown};
+
+/// Owns a [game](Game) and every earlier and undone [game](Game) so that
+/// [applied](Game::apply) [actions](Action) can be [undone](GameHistory::undo) and
+/// [redone](GameHistory::redo).
+///
+/// Each [action](Action) keeps a copy of the whole [game](Game) from before it, so the bag,
+/// the board, the points, the hands, the current player, and the [game rng](crate::GameRng) are
+/// all restored exactly, including stepping back from the `Next` phase to the `First` phase and
+/// from the `Last` phase to the `Next` phase.
+#[derive(Debug, Clone)]
+pub struct GameHistory {
+    /// The current [game](Game).
+    game: Game,
+    /// Every earlier [game](Game) with the most recent last.
+    undos: Vec<Game>,
+    /// Every undone [game](Game) with the most recently undone last.
+    redos: Vec<Game>,
+}
+
+impl GameHistory {
+    /// A new [GameHistory] struct with nothing to undo or redo.
+    ///
+    /// # Arguments
+    ///
+    /// * `game`: The current [game](Game).
+    pub fn new(game: Game) -> GameHistory {
+        GameHistory {
+            game,
+            undos: Vec::new(),
+            redos: Vec::new(),
+        }
+    }
+
+    /// [Applies](Game::apply) the [action](Action) to the current [game](Game). If the
+    /// [action](Action) is applied, the previous [game](Game) can be
+    /// [undone](GameHistory::undo) and nothing can be [redone](GameHistory::redo).
+    ///
+    /// # Arguments
+    ///
+    /// * `action`: Describes how the current player advances the game, or how any player
+    /// reorders their hand.
+    ///
+    /// # Errors
+    ///
+    /// Same as [Game::apply], where the current [game](Game) is left unchanged.
+    ///
+    /// # Returns
+    ///
+    /// Same as [Game::apply] without the [game](Game).
+    pub fn apply(&mut self, action: &Action) -> Result<Option<ScoreBreakdown>, ActionError> {
+        let (game, score_breakdown) = self
+            .game
+            .clone()
+            .apply(action)
+            .map_err(|(_, error)| error)?;
+
+        self.undos.push(std::mem::replace(&mut self.game, game));
+        self.redos.clear();
+        Ok(score_breakdown)
+    }
+
+    /// Restores the [game](Game) from before the most recently [applied](GameHistory::apply)
+    /// or [redone](GameHistory::redo) [action](Action).
+    ///
+    /// # Returns
+    ///
+    /// Whether some [action](Action) was undone.
+    pub fn undo(&mut self) -> bool {
+        let Some(game) = self.undos.pop() else {
+            return false;
+        };
+        self.redos.push(std::mem::replace(&mut self.game, game));
+        true
+    }
+
+    /// Restores the [game](Game) from after the most recently [undone](GameHistory::undo)
+    /// [action](Action).
+    ///
+    /// # Returns
+    ///
+    /// Whether some [action](Action) was redone.
+    pub fn redo(&mut self) -> bool {
+        let Some(game) = self.redos.pop() else {
+            return false;
+        };
+        self.undos.push(std::mem::replace(&mut self.game, game));
+        true
+    }
+
+    /// Whether some [action](Action) can be [undone](GameHistory::undo).
+    pub fn can_undo(&self) -> bool {
+        !self.undos.is_empty()
+    }
+
+    /// Whether some [action](Action) can be [redone](GameHistory::redo).
+    pub fn can_redo(&self) -> bool {
+        !self.redos.is_empty()
+    }
+
+    /// The current [game](Game).
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// Takes every [event](Event) of the current [game](Game) in the order they happened since
+    /// the last call. Each earlier [game](Game) keeps the [events](Event) not yet taken when
+    /// the next [action](Action) was [applied](GameHistory::apply), so taking them after each
+    /// [action](Action) keeps them from being copied into every earlier [game](Game).
+    pub fn drain_events(&mut self) -> Vec<Event> {
+        self.game.drain_events()
+    }
+
+    /// Takes the current [game](Game) and drops every earlier and undone [game](Game).
+    pub fn into_game(self) -> Game {
+        self.game
+    }
+}
+
+impl From<Game> for GameHistory {
+    fn from(game: Game) -> Self {
+        GameHistory::new(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color, FirstState, LastState, NextState, Shape};
+    use map_macro::btree_set;
+    use rand::Rng;
+
+    #[test]
+    fn undo_first_play() {
+        let mut rng = rand::thread_rng();
+        let mut first_state = FirstState::empty_first_state();
+        first_state.random_players(&mut rng);
+        first_state.random_bag(&mut rng);
+        first_state.mut_hands()[0].push(rng.gen());
+        first_state.max_matches_to_hand_len();
+        let mut game_history = GameHistory::new(Game::from(first_state));
+        let expected = format!("{:?}", game_history.game());
+
+        game_history
+            .apply(&Action::Play([(0, (0, 0))].into_iter().collect()))
+            .expect("apply should return Ok");
+
+        assert!(matches!(game_history.game(), Game::Next(_)));
+        assert!(game_history.undo());
+        assert!(matches!(game_history.game(), Game::First(_)));
+        assert_eq!(expected, format!("{:?}", game_history.game()));
+        assert!(!game_history.undo());
+        assert!(game_history.can_redo());
+    }
+
+    #[test]
+    fn undo_redo_next_exchange() {
+        let mut rng = rand::thread_rng();
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rng);
+        next_state.random_bag(&mut rng);
+        next_state
+            .mut_board()
+            .insert((0, 0), (Color::Red, Shape::Circle));
+        next_state.mut_hands()[0].push((Color::Red, Shape::Square));
+        next_state.mut_hands()[1].push((Color::Red, Shape::Square));
+        let mut game_history = GameHistory::from(Game::from(next_state));
+        let before = format!("{:?}", game_history.game());
+
+        game_history
+            .apply(&Action::Exchange(btree_set! { 0 }))
+            .expect("apply should return Ok");
+        let after = format!("{:?}", game_history.game());
+
+        assert!(game_history.undo());
+        assert_eq!(before, format!("{:?}", game_history.game()));
+        assert!(game_history.redo());
+        assert_eq!(after, format!("{:?}", game_history.game()));
+        assert!(!game_history.redo());
+    }
+
+    #[test]
+    fn undo_redo_drain_events() {
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rand::thread_rng());
+        next_state
+            .mut_board()
+            .insert((0, 0), (Color::Red, Shape::Circle));
+        next_state.mut_hands()[1].extend([(Color::Red, Shape::Square), (Color::Blue, Shape::X)]);
+        let mut game_history = GameHistory::new(Game::from(next_state));
+        let action = Action::ReorderHand {
+            player: 1,
+            permutation: vec![1, 0],
+        };
+        let expected = vec![Event::HandReordered {
+            player: 1,
+            permutation: vec![1, 0],
+        }];
+
+        game_history.apply(&action).expect("apply should return Ok");
+        assert_eq!(expected, game_history.drain_events());
+        game_history.apply(&action).expect("apply should return Ok");
+
+        assert!(game_history.undo());
+        assert_eq!(Vec::<Event>::new(), game_history.drain_events());
+        assert!(game_history.redo());
+        assert_eq!(expected, game_history.drain_events());
+        assert!(game_history.undo());
+        assert!(game_history.redo());
+        assert_eq!(Vec::<Event>::new(), game_history.drain_events());
+    }
+
+    #[test]
+    fn undo_reorder_hand() {
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rand::thread_rng());
+        next_state
+            .mut_board()
+            .insert((0, 0), (Color::Red, Shape::Circle));
+        let hand = [(Color::Red, Shape::Square), (Color::Blue, Shape::X)];
+        next_state.mut_hands()[1].extend(hand);
+        let mut game_history = GameHistory::new(Game::from(next_state));
+
+        game_history
+            .apply(&Action::ReorderHand {
+                player: 1,
+                permutation: vec![1, 0],
+            })
+            .expect("apply should return Ok");
+
+        assert_eq!(Some(0), game_history.game().current_player());
+        assert_eq!(
+            Some(&hand[1]),
+            game_history
+                .game()
+                .get_hand(1)
+                .and_then(|hand| hand.first())
+        );
+        assert!(game_history.undo());
+        assert_eq!(
+            Some(&hand[..]),
+            game_history.game().get_hand(1).map(|hand| &hand[..])
+        );
+        assert!(game_history.redo());
+        assert_eq!(
+            Some(&hand[1]),
+            game_history
+                .game()
+                .get_hand(1)
+                .and_then(|hand| hand.first())
+        );
+    }
+
+    #[test]
+    fn undo_next_play_last() {
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rand::thread_rng());
+        next_state
+            .mut_board()
+            .insert((0, 0), (Color::Red, Shape::Circle));
+        next_state.mut_hands()[0].push((Color::Red, Shape::Square));
+        let mut game_history = GameHistory::new(Game::from(next_state));
+        let expected = format!("{:?}", game_history.game());
+
+        game_history
+            .apply(&Action::Play([(0, (1, 0))].into_iter().collect()))
+            .expect("apply should return Ok");
+
+        assert!(matches!(game_history.game(), Game::Last(_)));
+        assert!(game_history.undo());
+        assert!(matches!(game_history.game(), Game::Next(_)));
+        assert_eq!(expected, format!("{:?}", game_history.game()));
+    }
+
+    #[test]
+    fn apply_clears_redos() {
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rand::thread_rng());
+        next_state
+            .mut_board()
+            .insert((0, 0), (Color::Red, Shape::Circle));
+        next_state.mut_hands()[0].push((Color::Red, Shape::Square));
+        next_state.mut_hands()[1].push((Color::Red, Shape::Square));
+        let mut game_history = GameHistory::new(Game::from(next_state));
+
+        game_history
+            .apply(&Action::Play([(0, (1, 0))].into_iter().collect()))
+            .expect("apply should return Ok");
+        assert!(game_history.undo());
+        game_history
+            .apply(&Action::Play([(0, (0, 1))].into_iter().collect()))
+            .expect("apply should return Ok");
+
+        assert!(game_history.can_undo());
+        assert!(!game_history.can_redo());
+    }
+
+    #[test]
+    fn apply_error() {
+        let mut game_history = GameHistory::new(Game::from(LastState::empty_last_state()));
+
+        assert_eq!(
+            Err(ActionError::HasEnded),
+            game_history.apply(&Action::Pass)
+        );
+        assert!(!game_history.can_undo());
+        assert!(matches!(game_history.into_game(), Game::Last(_)));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Board, Hands, Points};
+use crate::{Board, Event, GameConfig, Hands, Points, TileIds};
 
 pub use last_view::*;
 
@@ -8,16 +8,35 @@ mod test_setup;
 
 /// Owns game state on the last turn and implements methods.
 /// Created from [NextState::next_play](crate::NextState::next_play).
-#[derive(Debug)]
+///
+/// Every [event](Event) is kept until it is [drained](LastState::drain_events), including
+/// [events](Event) of earlier turns which were never drained from
+/// the [next state](crate::NextState).
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "LastStateFields")
+)]
 pub struct LastState {
     /// This is a map of [coordinates](crate::Coordinate) to [tiles](crate::Tile) that
     /// have been played.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::board"))]
     board: Board,
     /// A vector of points for each player.
     points: Points,
     /// A vector of hands for each player, where each hand is
     /// a vector of [tiles](crate::Tile).
     hands: Hands,
+    /// Limits and bonuses of the game.
+    config: GameConfig,
+    /// Every [event](Event) which has not been [drained](LastState::drain_events) yet.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    events: Vec<Event>,
+    /// The [identities](crate::TileId) of every [tile](crate::Tile) or [None] if
+    /// [tile ids](crate::TileIds) are not tracked.
+    #[cfg_attr(feature = "serde", serde(default))]
+    tile_ids: Option<TileIds>,
 }
 
 impl LastState {
@@ -28,15 +47,100 @@ impl LastState {
     /// * `points`: A vector of points for each player.
     /// * `hands`: A vector of hands for each player, where each hand is
     /// a vector of [tiles](crate::Tile).
+    /// * `config`: Limits and bonuses of the game.
+    /// * `events`: Every [event](Event) which has not been
+    /// [drained](LastState::drain_events) yet.
+    /// * `tile_ids`: The [identities](crate::TileId) of every [tile](crate::Tile) or [None] if
+    /// [tile ids](crate::TileIds) are not tracked.
     ///
     /// # Returns
     ///
     /// A [LastState] struct with properties owned from arguments.
-    pub(super) fn new(board: Board, points: Points, hands: Hands) -> LastState {
+    pub(super) fn new(
+        board: Board,
+        points: Points,
+        hands: Hands,
+        config: GameConfig,
+        events: Vec<Event>,
+        tile_ids: Option<TileIds>,
+    ) -> LastState {
         LastState {
             board,
             points,
             hands,
+            config,
+            events,
+            tile_ids,
         }
     }
+
+    /// Takes every [event](Event) in the order they happened since the last call.
+    pub fn drain_events(&mut self) -> Vec<Event> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// The [identities](crate::TileId) of every [tile](crate::Tile) or [None] if
+    /// [tile ids](TileIds) are not tracked.
+    pub fn tile_ids(&self) -> Option<&TileIds> {
+        self.tile_ids.as_ref()
+    }
+}
+
+/// Every field of [LastState] except [events](Event), which is deserialized before
+/// being checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+pub(crate) struct LastStateFields {
+    #[serde(with = "crate::serialization::board")]
+    board: Board,
+    points: Points,
+    hands: Hands,
+    config: GameConfig,
+    #[serde(default)]
+    tile_ids: Option<TileIds>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<LastStateFields> for LastState {
+    type Error = crate::StateErrors;
+
+    /// Checks that the points line up with the players, that the board is within
+    /// the limits of the [config](GameConfig), and that any [tile ids](TileIds) line up with
+    /// the hands and the board, so that an unreachable state cannot be deserialized.
+    fn try_from(fields: LastStateFields) -> Result<Self, Self::Error> {
+        let mut errors = std::collections::HashSet::new();
+        crate::serialization::check_players(
+            &fields.hands,
+            None,
+            Some(fields.points.len()),
+            None,
+            &mut errors,
+        );
+        crate::serialization::check_tiles(
+            0,
+            &fields.board,
+            &fields.hands,
+            &fields.config,
+            &mut errors,
+        );
+        crate::serialization::check_tile_ids(
+            fields.tile_ids.as_ref(),
+            None,
+            &fields.board,
+            &fields.hands,
+            &mut errors,
+        );
+        if !errors.is_empty() {
+            return Err(crate::StateErrors(errors));
+        }
+
+        Ok(LastState {
+            board: fields.board,
+            points: fields.points,
+            hands: fields.hands,
+            config: fields.config,
+            events: Vec::new(),
+            tile_ids: fields.tile_ids,
+        })
+    }
 }
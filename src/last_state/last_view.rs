@@ -1,16 +1,23 @@
-use crate::{Board, Hands, LastState, Points};
+use crate::{Board, GameConfig, Hands, LastState, Points};
 
 /// Immutably borrows properties from [LastState].
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct LastView<'a> {
     /// This is a map of [coordinates](crate::Coordinate) to [tiles](crate::Tile) that
     /// have been played.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialization::board::serialize")
+    )]
     pub board: &'a Board,
     /// A vector of points for each player.
     pub points: &'a Points,
     /// A vector of hands for each player, where each hand is
     /// a vector of [tiles](crate::Tile).
     pub hands: &'a Hands,
+    /// Limits and bonuses of the game.
+    pub config: &'a GameConfig,
 }
 
 impl<'a> LastState {
@@ -20,6 +27,7 @@ impl<'a> LastState {
             board: &self.board,
             points: &self.points,
             hands: &self.hands,
+            config: &self.config,
         }
     }
 }
@@ -37,5 +45,6 @@ mod tests {
         assert_eq!(last_state.board, *last_view.board);
         assert_eq!(last_state.points, *last_view.points);
         assert_eq!(last_state.hands, *last_view.hands);
+        assert_eq!(last_state.config, *last_view.config);
     }
 }
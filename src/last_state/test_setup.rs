@@ -1,5 +1,6 @@
 use crate::{
-    random_board, random_players, Board, Hands, LastState, Points, PLAYER_CAPACITY, TILES_LEN,
+    random_board, random_players, Board, GameConfig, Hands, LastState, Points, PLAYER_CAPACITY,
+    TILES_LEN,
 };
 use rand::Rng;
 
@@ -12,12 +13,18 @@ impl LastState {
     /// * `board`: An empty board.
     /// * `points`: An empty points vector.
     /// * `hands`: An empty hands vector.
+    /// * `config`: The [default config](GameConfig::default).
+    /// * `events`: An empty events vector.
+    /// * `tile_ids`: [None].
     pub fn empty_last_state() -> LastState {
         // capacity hardcoded to highest expected demand during test cases
         LastState {
             board: Board::with_capacity(TILES_LEN),
             points: Points::with_capacity(PLAYER_CAPACITY),
             hands: Hands::with_capacity(PLAYER_CAPACITY),
+            config: GameConfig::default(),
+            events: Vec::new(),
+            tile_ids: None,
         }
     }
 
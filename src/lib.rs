@@ -33,15 +33,61 @@
 //! [FirstState::new] and [FirstState::new_random_first_player] create
 //! the game before the `First` phase.
 //!
+//! Each game state owns a [seedable rng](GameRng) which shuffles the bag and every
+//! [exchange](NextState::next_exchange). [FirstState::new_with_seed] and
+//! [FirstState::new_random_first_player_with_seed] reproduce the same game from the same seed.
+//!
+//! Limits and bonuses are chosen for each game by a [GameConfig], which is checked at runtime
+//! and carried by each game state. When no config is given,
+//! the [default config](GameConfig::default) is built from the compile time constants
+//! [TILE_LIMIT], [COORDINATE_LIMIT], [HOLES_LIMIT], [FULL_MATCH_BONUS], and [LAST_PLAY_BONUS].
+//!
 //! ## How is the game advanced?
 //!
 //! * `First`: The current player [plays](FirstState::first_play) first to advance the game
 //! to the `Next` phase.
-//! * `Next`: The current player either [plays](NextState::next_play) or
-//! [exchanges](NextState::next_exchange) [tiles](Tile)
-//! to advance the game to either the `Next` or `Last` phase (not respectively).
+//! * `Next`: The current player either [plays](NextState::next_play),
+//! [exchanges](NextState::next_exchange) [tiles](Tile), or [passes](NextState::next_pass)
+//! once the bag is empty to advance the game to either the `Next` or `Last` phase
+//! (not respectively).
 //! * `Last`: It is not possible to advance the game once the game has ended.
 //!
+//! [Game] wraps the state of any phase of the game, and [Game::apply] advances the game by
+//! any [action](Action) during any phase. Illegal [actions](Action) are reported by
+//! one [error type](ActionError).
+//!
+//! [drive_game] runs a whole game against a [Player] for each hand, which asynchronously
+//! [chooses actions](Player::choose_action) from a [view](PlayerView) and its own hand and is
+//! asked again whenever its [action](Action) is rejected or it
+//! [reorders its hand](Action::ReorderHand), up to a limit on each per turn.
+//!
+//! [GreedyPlayer] is a reference [Player] which always [plays](Action::Play) the highest-scoring
+//! legal [play](Plays) and [exchanges](Action::Exchange) its least useful [tiles](Tile) when
+//! nothing can be played.
+//! [MctsPlayer] is a stronger [Player] which searches with Monte Carlo tree search over samples
+//! of the hands and bag it cannot see within an iteration or time [budget](SearchBudget).
+//! The `tournament` binary plays many seeded games between these bots and reports win rates,
+//! average points, and average game lengths.
+//! [Elo] ranks the players of each finished game by their final points and updates a
+//! [RatingTable] of persistent players, which is written and parsed as plain text.
+//!
+//! Each transition records [events](Event) such as [tiles](Tile) being played, drawn, or
+//! exchanged, points being awarded, the turn advancing, and the game ending. The resulting state
+//! keeps them until they are taken by [Game::drain_events], [NextState::drain_events], or
+//! [LastState::drain_events].
+//!
+//! [GameHistory] wraps [Game] to [undo](GameHistory::undo) and [redo](GameHistory::redo)
+//! [applied](GameHistory::apply) [actions](Action), which restores the exact previous game
+//! even across phases.
+//!
+//! [FirstState::track_tile_ids] gives every [tile](Tile) a stable [identity](TileId) which
+//! the [tile](Tile) keeps from the bag, through a hand, to the board, so copies of the same
+//! [tile](Tile) can be told apart. [Game::tile_ids] and the `tile_ids` method of each state
+//! return the [identities](TileIds) in the same order as the bag, each hand, and the board.
+//! [FirstState::first_view_for] and [NextState::next_view_for] give a player the
+//! [identities](TileId) of their own hand, and each [event](Event) which moves [tiles](Tile)
+//! carries their [identities](TileId).
+//!
 //! The current player is represented as the index of the player whose turn it is in the range
 //! `0`..(the number of players) which either increments or loops back to `0` when necessary.
 //!
@@ -57,11 +103,49 @@
 //! [NextState::next_play](NextState::next_play) for the `First` and `Next` phases of
 //! the game respectively.
 //!
+//! [FirstState::first_play_tiles] and [NextState::next_play_tiles] play [tiles](Tile) by value
+//! from [tile plays](TilePlays) instead of by their index in the hand, which
+//! [resolves](resolve_plays) each [tile](Tile) against the current order of the hand and reports
+//! any [tile](Tile) the current player does not hold. [Action::PlayTiles] and
+//! [Action::ExchangeTiles] apply them through [Game::apply].
+//!
+//! [FirstState::preview_play] and [NextState::preview_play] check [plays](Plays) and return
+//! the points which would be earned without changing the game.
+//!
+//! [NextState::legal_plays] finds every [play](Plays) the current player could legally make
+//! along with the points earned by each [play](Plays).
+//! [NextState::hints] suggests the best of those [plays](Plays) for any player along with the
+//! lines each one forms, ranked by points and optionally by the [tiles](Tile) left in the hand.
+//!
+//! [NextView::unseen_tiles] finds the [tiles](Tile) some player cannot see from the board, their
+//! own hand, and the number of copies the game started with, along with how many of each are
+//! expected in the bag and in each opponent's hand.
+//! [UnseenTiles::draw_probability] is the hypergeometric probability of drawing [tiles](Tile) of
+//! some [draw target](DrawTarget) when the hand refills after the next play or exchange.
+//!
 //! ### How are tiles exchanged?
 //!
 //! [Exchange](NextState::next_exchange) [tiles](Tile) from the current player's hand with
 //! [tiles](Tile) from the bag, ignore points, and advance to the next player.
 //!
+//! [NextState::next_exchange_tiles] does the same by value from [tile exchanges](TileExchanges).
+//!
+//! ### How are hands reordered?
+//!
+//! Any player can [reorder](NextState::reorder_hand) or [sort](NextState::sort_hand) their hand
+//! by [color](HandSort::Color), [shape](HandSort::Shape), or
+//! [matching groups](HandSort::MatchingGroups) at any time during the `Next` phase. Reordering
+//! is not a turn, so the current player does not change, and every later [play](Plays) or
+//! [exchange](Exchanges) addresses the hand by its new order. [Action::ReorderHand] does the
+//! same through [Game::apply], so reorders can be [undone](GameHistory::undo) and
+//! [recorded](GameRecord) along with every turn.
+//!
+//! ### How are turns passed?
+//!
+//! Once the bag is empty, [pass](NextState::next_pass), ignore points, and advance to
+//! the next player. Passing prevents the game from getting stuck when the current player can
+//! neither play nor exchange [tiles](Tile).
+//!
 //! ## How are points calculated?
 //!
 //! The number of points earned by a [play](Plays) is the sum of points scored from each line that
@@ -71,13 +155,16 @@
 //! The number of points from a line is the number of [tiles](Tile) in that line. If the line
 //! creates a full match on the board where a line contains either
 //! [every color](Color::colors) or [every shape](Shape::shapes), an extra
-//! [full match bonus](FULL_MATCH_BONUS) is earned.
+//! [full match bonus](GameConfig::full_match_bonus) is earned.
 //!
 //! If the current player's hand is empty (and therefore no [tiles](Tile) in the bag
-//! are available) or the board becomes deadlocked (a filled rectangle of
-//! [every color](Color::colors) and [every shape](Shape::shapes)) where
-//! no additional [plays](Plays) are allowed despite players still holding some [tiles](Tile),
-//! an extra [last play bonus](LAST_PLAY_BONUS) is earned.
+//! are available) or no [tile](Tile) in any hand or the bag can be legally
+//! [played](Plays) anywhere on the board despite players still holding some [tiles](Tile),
+//! an extra [last play bonus](GameConfig::last_play_bonus) is earned.
+//!
+//! Every [play](Plays) and [preview](NextState::preview_play) returns
+//! a [score breakdown](ScoreBreakdown) which itemises each scored [line](LineScore) and
+//! the [last play bonus](GameConfig::last_play_bonus) to show where the points came from.
 //!
 //! ## How is the game viewed?
 //!
@@ -90,13 +177,48 @@
 //! [NextState::get_hand](NextState::get_hand) share private information for each
 //! individual player.
 //!
+//! [render_board] draws the board as a grid with labelled axes and optionally some pending
+//! [plays](Plays) from a hand, either with ANSI colors or as plain ASCII. Pending
+//! [plays](Plays) over [tiles](Tile) on the board and grids with more cells than
+//! the [tile limit](GameConfig::tile_limit) are rejected.
+//!
 //! ## How is the game ended?
 //!
-//! The game ends when either the current player's hand is empty or the board is
-//! a filled rectangle of [every color](Color::colors) and [every shape](Shape::shapes).
-//! It is impossible [to legally play](NextState::next_play) on the board since either
-//! the current player has no [tiles](Tile) [to play](NextState::next_play) or all [plays](Plays)
-//! will contain duplicate [tiles](Tile) in a line on the board.
+//! The game ends when either the current player's hand is empty or no [tile](Tile) in
+//! any hand or the bag can be legally placed on any empty [coordinate](Coordinate) next to
+//! the board. It is impossible [to legally play](NextState::next_play) on the board since either
+//! the current player has no [tiles](Tile) [to play](NextState::next_play) or every [play](Plays)
+//! would break some line on the board. Any legal [play](Plays) contains some legal play of
+//! a single [tile](Tile), so checking every single [tile](Tile) is enough.
+//!
+//! The game also ends when every player [passes](NextState::next_pass) in a row, but without
+//! a [last play bonus](GameConfig::last_play_bonus).
+//!
+//! ## How is the game serialized?
+//!
+//! The `serde` feature implements `Serialize` and `Deserialize` for each state, [Game],
+//! [Action], [GameConfig], [ScoreBreakdown], [tiles](Tile), and each error. The board and
+//! [plays](Plays) are serialized as sequences of pairs since JSON object keys cannot be tuples.
+//! Each view only implements `Serialize` since views borrow from their state. Each state is
+//! checked after deserializing, so a state which could not have been reached by playing, such
+//! as one where the current player is not in the game, is rejected with `StateErrors` holding
+//! each `StateError`.
+//!
+//! ## How is the game written as text?
+//!
+//! [Color] and [Shape] implement `Display` and `FromStr` with short codes, and [Notation]
+//! wraps [tiles](Tile), [coordinates](Coordinate), [plays](Plays), [exchanges](Exchanges),
+//! and boards to do the same. For example, `0@0,0 2@1,0` [plays](Plays) the first and third
+//! [tiles](Tile) in a hand, and `RCi@0,0 RSq@1,0` places a red circle and a red square.
+//! Each [NotationError] contains the bad token and its position in the text.
+//!
+//! ## How is the game recorded?
+//!
+//! A [GameRecord] holds the setup parameters of [FirstState::new_with_seed], every [action](Action)
+//! with the points it earned, and the final points. It is written and parsed in a format like PGN,
+//! and [GameRecord::replay] rebuilds every [NextState] while checking the recorded points.
+//! [FirstState::seed] gives the seed of a game however it was started, so any game can
+//! be recorded.
 //!
 //! ## How are game states tested when properties are private?
 //!
@@ -129,24 +251,60 @@
     unsafe_code
 )]
 
+pub use config::*;
 pub use consts::*;
 pub use coordinate::*;
+pub use draw::*;
+pub use event::*;
 pub use first_state::*;
+pub use game::*;
+pub use greedy::*;
+pub use history::*;
 pub use last_state::*;
+pub use mcts::*;
 pub use next_state::*;
+pub use notation::*;
 pub use play::*;
+pub use player::*;
 #[cfg(test)]
 pub use random::*;
+pub use rating::*;
+pub use record::*;
+pub use render::*;
+pub use resolve::*;
+pub use score::*;
+#[cfg(feature = "serde")]
+pub use serialization::{StateError, StateErrors};
 pub use tile::*;
+pub use tile_id::*;
 pub use types::*;
+pub use unseen::*;
 
+mod config;
 mod consts;
 mod coordinate;
+mod draw;
+mod event;
 mod first_state;
+mod game;
+mod greedy;
+mod history;
 mod last_state;
+mod mcts;
 mod next_state;
+mod notation;
 mod play;
+mod player;
 #[cfg(test)]
 mod random;
+mod rating;
+mod record;
+mod render;
+mod resolve;
+mod score;
+#[cfg(feature = "serde")]
+mod serialization;
 mod tile;
+mod tile_id;
 mod types;
+mod unseen;
@@ -0,0 +1,633 @@
+use crate::{
+    Action, Coordinate, Game, GameRng, GreedyPlayer, Hand, Hands, NextState, NextView, Player,
+    PlayerView, Points, Tile,
+};
+use async_trait::async_trait;
+use itertools::Itertools;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::time::{Duration, Instant};
+
+/// The exploration constant used by [MctsPlayer::new] when none is given.
+const EXPLORATION: f64 = 0.7;
+
+/// The number of turns simulated after each expansion used by [MctsPlayer::new] when none
+/// is given.
+const ROLLOUT_DEPTH: usize = 1;
+
+/// How long [MctsPlayer] searches before choosing an [action](Action).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum SearchBudget {
+    /// Searches a fixed number of iterations, which is reproducible from the same seed.
+    Iterations(usize),
+    /// Searches until the duration has passed since the search started.
+    Duration(Duration),
+}
+
+/// A [player](Player) which searches with information set Monte Carlo tree search.
+///
+/// Each iteration samples the opponents' hands and the order of the bag from the
+/// [tiles](Tile) the player cannot see, then searches
+/// [next_play](NextState::next_play), [next_exchange](NextState::next_exchange), and
+/// [next_pass](NextState::next_pass) transitions in that sample. Nodes are shared between
+/// samples by the [tiles](Tile) they move rather than their indexes in a hand, and each
+/// node is only chosen from samples where it is legal. The highest-scoring untried node is
+/// expanded first, and nodes are only widened as they are visited more often. After expanding
+/// a node, the game is simulated for a few turns with a [GreedyPlayer], which by default is only
+/// the next player's reply, and each player is rewarded by their share of the points gained
+/// since the search started. The most visited [action](Action) of the player is chosen.
+///
+/// Only the most useful [exchanges](Action::Exchange) found by [GreedyPlayer] and the whole hand
+/// are searched to keep the tree small. The `First` phase is played by a [GreedyPlayer] since
+/// nothing is hidden that changes which max matching [play](crate::Plays) scores most.
+///
+/// Searching blocks the current thread until the [budget](SearchBudget) runs out, even when
+/// [driven](crate::drive_game) on an async executor.
+#[derive(Debug, Clone)]
+pub struct MctsPlayer {
+    /// How long to search before choosing an [action](Action).
+    budget: SearchBudget,
+    /// How much less visited nodes are preferred over nodes with more rewards.
+    exploration: f64,
+    /// The number of turns simulated after each expansion.
+    rollout_depth: usize,
+    /// Samples hidden [tiles](Tile), chooses untried nodes, and breaks ties in simulations.
+    rng: GameRng,
+}
+
+/// An [action](Action) addressed by [tiles](Tile) instead of indexes, which is the same
+/// across hands sampled for the same player.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+enum Move {
+    /// [Coordinates](Coordinate) and [tiles](Tile) ordered by [coordinate](Coordinate).
+    Play(Vec<(Coordinate, Tile)>),
+    /// [Tiles](Tile) ordered by [color](crate::Color) and then [shape](crate::Shape).
+    Exchange(Vec<Tile>),
+    /// [Passes](Action::Pass).
+    Pass,
+}
+
+/// A node in the search tree stored in a vector by index.
+#[derive(Debug)]
+struct Node {
+    /// The [move](Move) from the parent node, or [None] for the root node.
+    next_move: Option<Move>,
+    /// The index of the player who made the [move](Move).
+    player: usize,
+    /// The index of the parent node, or [None] for the root node.
+    parent: Option<usize>,
+    /// The indexes of every expanded child node.
+    children: Vec<usize>,
+    /// The number of iterations which passed through the node.
+    visits: usize,
+    /// The sum of rewards for `player` over every visit.
+    rewards: f64,
+    /// The number of iterations where the [move](Move) was legal.
+    availability: usize,
+}
+
+impl MctsPlayer {
+    /// A new [MctsPlayer] struct seeded from [thread_rng](rand::thread_rng).
+    ///
+    /// # Arguments
+    ///
+    /// * `budget`: How long to search before choosing an [action](Action).
+    /// * `exploration`: How much less visited nodes are preferred over nodes with more rewards,
+    /// or `0.7` when [None].
+    /// * `rollout_depth`: The number of turns simulated after each expansion, or `1`
+    /// when [None].
+    pub fn new(
+        budget: SearchBudget,
+        exploration: Option<f64>,
+        rollout_depth: Option<usize>,
+    ) -> MctsPlayer {
+        MctsPlayer::new_with_rng(
+            budget,
+            exploration,
+            rollout_depth,
+            GameRng::from_rng(rand::thread_rng())
+                .unwrap_or_else(|_| unreachable!("thread_rng should not fail.")),
+        )
+    }
+
+    /// Same as [MctsPlayer::new], but searches with `rng`.
+    ///
+    /// # Arguments
+    ///
+    /// * `budget`: How long to search before choosing an [action](Action).
+    /// * `exploration`: How much less visited nodes are preferred over nodes with more rewards,
+    /// or `0.7` when [None].
+    /// * `rollout_depth`: The number of turns simulated after each expansion, or `1`
+    /// when [None].
+    /// * `rng`: Samples hidden [tiles](Tile), chooses untried nodes, and breaks ties
+    /// in simulations.
+    pub fn new_with_rng(
+        budget: SearchBudget,
+        exploration: Option<f64>,
+        rollout_depth: Option<usize>,
+        rng: GameRng,
+    ) -> MctsPlayer {
+        MctsPlayer {
+            budget,
+            exploration: exploration.unwrap_or(EXPLORATION),
+            rollout_depth: rollout_depth.unwrap_or(ROLLOUT_DEPTH),
+            rng,
+        }
+    }
+
+    /// Same as [MctsPlayer::new], but the [game rng](GameRng) is seeded from `seed`. Searching
+    /// with [SearchBudget::Iterations] chooses the same [actions](Action) from the same `seed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `budget`: How long to search before choosing an [action](Action).
+    /// * `exploration`: How much less visited nodes are preferred over nodes with more rewards,
+    /// or `0.7` when [None].
+    /// * `rollout_depth`: The number of turns simulated after each expansion, or `1`
+    /// when [None].
+    /// * `seed`: Seeds the [game rng](GameRng) which searches.
+    pub fn new_with_seed(
+        budget: SearchBudget,
+        exploration: Option<f64>,
+        rollout_depth: Option<usize>,
+        seed: u64,
+    ) -> MctsPlayer {
+        MctsPlayer::new_with_rng(
+            budget,
+            exploration,
+            rollout_depth,
+            GameRng::seed_from_u64(seed),
+        )
+    }
+
+    /// Chooses the [action](Action) by searching until the budget runs out. At least one
+    /// iteration is always searched.
+    ///
+    /// # Arguments
+    ///
+    /// * `view`: The part of the game visible to every player.
+    /// * `hand`: The [tiles](Tile) held by the player.
+    ///
+    /// # See Also
+    ///
+    /// * [Player::choose_action]
+    pub fn choose(&mut self, view: PlayerView<'_>, hand: &[Tile]) -> Action {
+        match view {
+            PlayerView::First(_) => self.greedy_player().choose(view, hand),
+            PlayerView::Next(next_view) => self.search(next_view, hand),
+        }
+    }
+
+    /// Searches the `Next` phase and chooses the most visited [action](Action) from the root,
+    /// or the [action](Action) of a [GreedyPlayer] if every sample had already ended.
+    fn search(&mut self, next_view: NextView<'_>, hand: &[Tile]) -> Action {
+        let mut nodes = vec![Node::new(None, next_view.current_player, None)];
+        let start = Instant::now();
+        let mut iterations = 0;
+        while iterations == 0
+            || match self.budget {
+                SearchBudget::Iterations(budget) => iterations < budget,
+                SearchBudget::Duration(budget) => start.elapsed() < budget,
+            }
+        {
+            iterations += 1;
+            let mut game = Game::Next(self.sample(&next_view, hand));
+            let mut node = 0;
+
+            // select tried nodes until some legal move is untried
+            while let Game::Next(next_state) = &game {
+                // the sample might have ended even though the game has not
+                if next_state.has_ended() {
+                    break;
+                }
+                let player = next_state.current_player();
+                let mut untried = Vec::new();
+                let mut tried = Vec::new();
+                for (next_move, points) in legal_moves(next_state) {
+                    match nodes[node]
+                        .children
+                        .iter()
+                        .find(|&&child| nodes[child].next_move.as_ref() == Some(&next_move))
+                    {
+                        Some(&child) => tried.push(child),
+                        None => untried.push((next_move, points)),
+                    }
+                }
+                for &child in &tried {
+                    nodes[child].availability += 1;
+                }
+
+                // expand the highest-scoring untried move first so that small budgets are
+                // no worse than greedy, but only widen as often as the node is visited
+                let max_points = untried.iter().map(|&(_, points)| points).max();
+                untried.retain(|&(_, points)| Some(points) == max_points);
+                if !untried.is_empty()
+                    && (tried.is_empty() || nodes[node].children.len() < nodes[node].width())
+                {
+                    let (next_move, _) = untried.swap_remove(self.rng.gen_range(0..untried.len()));
+                    game = apply(game, &next_move);
+                    let child = nodes.len();
+                    nodes.push(Node::new(Some(next_move), player, Some(node)));
+                    nodes[node].children.push(child);
+                    node = child;
+                    break;
+                }
+
+                let Some(child) = tried.into_iter().max_by(|&left, &right| {
+                    let left = nodes[left].upper_confidence_bound(self.exploration);
+                    let right = nodes[right].upper_confidence_bound(self.exploration);
+                    left.total_cmp(&right)
+                }) else {
+                    break;
+                };
+                let next_move = nodes[child]
+                    .next_move
+                    .as_ref()
+                    .unwrap_or_else(|| unreachable!("child node should have a move."));
+                game = apply(game, next_move);
+                node = child;
+            }
+
+            // simulate a few turns
+            let mut greedy_player = self.greedy_player();
+            for _ in 0..self.rollout_depth {
+                let Game::Next(next_state) = &game else {
+                    break;
+                };
+                if next_state.has_ended() {
+                    break;
+                }
+                let hand = next_state
+                    .get_hand(next_state.current_player())
+                    .unwrap_or_else(|| unreachable!("current player should have a hand."));
+                let action = greedy_player.choose(PlayerView::Next(next_state.next_view()), hand);
+                game = match game.apply(&action) {
+                    Ok((game, _)) | Err((game, _)) => game,
+                };
+            }
+
+            let rewards = rewards(next_view.points, &points(&game));
+            let mut current = Some(node);
+            while let Some(index) = current {
+                let node = &mut nodes[index];
+                node.visits += 1;
+                node.rewards += rewards[node.player];
+                current = node.parent;
+            }
+        }
+
+        nodes[0]
+            .children
+            .iter()
+            .rev()
+            .max_by(|&&left, &&right| {
+                let (left, right) = (&nodes[left], &nodes[right]);
+                left.visits.cmp(&right.visits).then_with(|| {
+                    let left = left.rewards / left.visits.max(1) as f64;
+                    left.total_cmp(&(right.rewards / right.visits.max(1) as f64))
+                })
+            })
+            .and_then(|&child| nodes[child].next_move.as_ref()?.to_action(hand))
+            .unwrap_or_else(|| {
+                self.greedy_player()
+                    .choose(PlayerView::Next(next_view), hand)
+            })
+    }
+
+    /// Samples the opponents' hands and the bag from every [tile](Tile) not on the board and not
+    /// in `hand`.
+    fn sample(&mut self, next_view: &NextView<'_>, hand: &[Tile]) -> NextState {
+        let mut unseen = next_view
+            .unseen_tiles(next_view.current_player, hand)
+            .to_bag();
+        unseen.shuffle(&mut self.rng);
+
+        let hands: Hands = next_view
+            .hand_lens
+            .iter()
+            .enumerate()
+            .map(|(player, &hand_len)| {
+                if player == next_view.current_player {
+                    Hand::from_slice(hand)
+                } else {
+                    unseen
+                        .drain(unseen.len().saturating_sub(hand_len)..)
+                        .collect()
+                }
+            })
+            .collect();
+        unseen.truncate(next_view.bag_len);
+        let rng = GameRng::seed_from_u64(self.rng.gen());
+        NextState::from_view(next_view, unseen, hands, rng)
+    }
+
+    /// A [GreedyPlayer] which breaks ties with a [game rng](GameRng) seeded from this player.
+    fn greedy_player(&mut self) -> GreedyPlayer {
+        GreedyPlayer::new_with_seed(self.rng.gen())
+    }
+}
+
+#[async_trait]
+impl Player for MctsPlayer {
+    async fn choose_action(&mut self, view: PlayerView<'_>, hand: &Hand) -> Action {
+        self.choose(view, hand)
+    }
+}
+
+impl Move {
+    /// Addresses the [action](Action) by the [tiles](Tile) at its indexes in `hand`, or by
+    /// its own [tiles](Tile) when they are given by value.
+    fn from_action(action: &Action, hand: &[Tile]) -> Move {
+        match action {
+            Action::Play(plays) => Move::Play(
+                plays
+                    .iter()
+                    .map(|(&index, &coordinate)| (coordinate, hand[index]))
+                    .sorted_by_key(|&(coordinate, _)| coordinate)
+                    .collect(),
+            ),
+            Action::Exchange(exchanges) => Move::Exchange(
+                exchanges
+                    .iter()
+                    .map(|&index| hand[index])
+                    .sorted_by_key(|&(color, shape)| (color as usize, shape as usize))
+                    .collect(),
+            ),
+            Action::PlayTiles(tile_plays) => Move::Play(
+                tile_plays
+                    .iter()
+                    .map(|(&coordinate, &tile)| (coordinate, tile))
+                    .collect(),
+            ),
+            Action::ExchangeTiles(tile_exchanges) => Move::Exchange(
+                tile_exchanges
+                    .iter()
+                    .copied()
+                    .sorted_by_key(|&(color, shape)| (color as usize, shape as usize))
+                    .collect(),
+            ),
+            Action::Pass => Move::Pass,
+            Action::ReorderHand { .. } => unreachable!("reordering a hand should not be a move."),
+        }
+    }
+
+    /// Addresses the [move](Move) by the lowest unused index of each [tile](Tile) in `hand`,
+    /// or [None] if some [tile](Tile) is not in `hand`.
+    fn to_action(&self, hand: &[Tile]) -> Option<Action> {
+        let mut used = vec![false; hand.len()];
+        let mut index_of = |tile: Tile| {
+            let index = (0..hand.len()).find(|&index| !used[index] && hand[index] == tile)?;
+            used[index] = true;
+            Some(index)
+        };
+        Some(match self {
+            Move::Play(tiles) => Action::Play(
+                tiles
+                    .iter()
+                    .map(|&(coordinate, tile)| Some((index_of(tile)?, coordinate)))
+                    .collect::<Option<_>>()?,
+            ),
+            Move::Exchange(tiles) => Action::Exchange(
+                tiles
+                    .iter()
+                    .map(|&tile| index_of(tile))
+                    .collect::<Option<_>>()?,
+            ),
+            Move::Pass => Action::Pass,
+        })
+    }
+}
+
+impl Node {
+    /// A new [Node] struct which has never been visited.
+    fn new(next_move: Option<Move>, player: usize, parent: Option<usize>) -> Node {
+        Node {
+            next_move,
+            player,
+            parent,
+            children: Vec::new(),
+            visits: 0,
+            rewards: 0.0,
+            availability: 1,
+        }
+    }
+
+    /// The number of child nodes allowed to be expanded, which grows with the square root of
+    /// the visits so that each child node is visited more than once.
+    fn width(&self) -> usize {
+        ((self.visits + 1) as f64).sqrt().ceil() as usize
+    }
+
+    /// The average reward plus a bonus which grows as the node is visited less often than
+    /// its [move](Move) is legal.
+    fn upper_confidence_bound(&self, exploration: f64) -> f64 {
+        let visits = self.visits.max(1) as f64;
+        self.rewards / visits + exploration * ((self.availability as f64).ln() / visits).sqrt()
+    }
+}
+
+/// Every [move](Move) searched for the current player and its points, which is every legal
+/// [play](crate::Plays), otherwise the [least useful](GreedyPlayer) [tiles](Tile) and the whole
+/// hand when the bag is not empty, otherwise [passing](Move::Pass).
+fn legal_moves(next_state: &NextState) -> Vec<(Move, usize)> {
+    let hand = next_state
+        .get_hand(next_state.current_player())
+        .unwrap_or_else(|| unreachable!("current player should have a hand."));
+    let bag_len = next_state.next_view().bag_len;
+
+    let mut moves = next_state
+        .legal_plays()
+        .into_iter()
+        .map(|(plays, score_breakdown)| {
+            let next_move = Move::from_action(&Action::Play(plays), hand);
+            (next_move, score_breakdown.points())
+        })
+        // playing any copy of the same tiles is the same move
+        .unique_by(|(next_move, _)| next_move.clone())
+        .collect_vec();
+    if bag_len > 0 {
+        let least_useful = GreedyPlayer::new().least_useful(hand, bag_len);
+        let whole_hand = (0..hand.len()).take(bag_len).collect();
+        for exchanges in [least_useful, whole_hand] {
+            let next_move = Move::from_action(&Action::Exchange(exchanges), hand);
+            if moves.iter().all(|(other, _)| *other != next_move) {
+                moves.push((next_move, 0));
+            }
+        }
+    } else if moves.is_empty() {
+        moves.push((Move::Pass, 0));
+    }
+    moves
+}
+
+/// [Applies](Game::apply) the [move](Move) for the current player.
+fn apply(game: Game, next_move: &Move) -> Game {
+    let action = game
+        .current_player()
+        .and_then(|player| next_move.to_action(game.get_hand(player)?))
+        .unwrap_or_else(|| unreachable!("legal moves should only use tiles in the hand."));
+    match game.apply(&action) {
+        Ok((game, _)) => game,
+        Err(_) => unreachable!("legal moves should be applied."),
+    }
+}
+
+/// The points of each player in the `Next` or `Last` phase.
+fn points(game: &Game) -> Points {
+    match game {
+        Game::Next(next_state) => next_state.next_view().points.clone(),
+        Game::Last(last_state) => last_state.last_view().points.clone(),
+        Game::First(_) => unreachable!("searched games should have started."),
+    }
+}
+
+/// Splits a reward of `1` between the players by their share of the points gained since
+/// `start`, or evenly when no points were gained.
+fn rewards(start: &Points, points: &Points) -> Vec<f64> {
+    let gained = points
+        .iter()
+        .zip(start)
+        .map(|(&points, &start)| points.saturating_sub(start) as f64)
+        .collect_vec();
+    let total = gained.iter().sum::<f64>();
+    if total == 0.0 {
+        return vec![1.0 / points.len() as f64; points.len()];
+    }
+    gained.into_iter().map(|gained| gained / total).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{drive_game, Color, FirstState, Shape};
+    use futures::executor::block_on;
+
+    fn set_up_next_state() -> NextState {
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rand::thread_rng());
+        next_state.random_bag(&mut rand::thread_rng());
+        next_state
+            .mut_board()
+            .insert((0, 0), (Color::Red, Shape::Circle));
+        for hand in next_state.mut_hands() {
+            hand.extend([
+                (Color::Red, Shape::Square),
+                (Color::Blue, Shape::Circle),
+                (Color::Red, Shape::X),
+            ]);
+        }
+        next_state
+    }
+
+    #[test]
+    fn move_round_trip_duplicates() {
+        let hand = [
+            (Color::Red, Shape::Square),
+            (Color::Blue, Shape::X),
+            (Color::Red, Shape::Square),
+        ];
+        let action = Action::Exchange([1, 2].into_iter().collect());
+
+        let next_move = Move::from_action(&action, &hand);
+
+        assert_eq!(
+            Move::Exchange(vec![(Color::Red, Shape::Square), (Color::Blue, Shape::X)]),
+            next_move
+        );
+        assert_eq!(
+            Some(Action::Exchange([0, 1].into_iter().collect())),
+            next_move.to_action(&hand)
+        );
+        assert_eq!(None, next_move.to_action(&hand[..1]));
+    }
+
+    #[test]
+    fn choose_legal_action() {
+        let next_state = set_up_next_state();
+        let hand = next_state
+            .get_hand(0)
+            .cloned()
+            .expect("get_hand should return Some");
+        let mut mcts_player = MctsPlayer::new(SearchBudget::Iterations(16), None, None);
+
+        let action = mcts_player.choose(PlayerView::Next(next_state.next_view()), &hand);
+
+        assert!(Game::from(next_state).apply(&action).is_ok());
+    }
+
+    #[test]
+    fn choose_with_seed() {
+        let next_state = set_up_next_state();
+        let hand = next_state
+            .get_hand(0)
+            .cloned()
+            .expect("get_hand should return Some");
+        let choose = |seed| {
+            MctsPlayer::new_with_seed(SearchBudget::Iterations(16), None, None, seed)
+                .choose(PlayerView::Next(next_state.next_view()), &hand)
+        };
+
+        assert_eq!(choose(3), choose(3));
+    }
+
+    #[test]
+    fn choose_duration() {
+        let next_state = set_up_next_state();
+        let hand = next_state
+            .get_hand(0)
+            .cloned()
+            .expect("get_hand should return Some");
+        let budget = SearchBudget::Duration(Duration::from_millis(20));
+
+        let action = MctsPlayer::new(budget, None, Some(0))
+            .choose(PlayerView::Next(next_state.next_view()), &hand);
+
+        assert!(Game::from(next_state).apply(&action).is_ok());
+    }
+
+    #[test]
+    fn choose_pass_empty_bag() {
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rand::thread_rng());
+        next_state
+            .mut_board()
+            .insert((0, 0), (Color::Red, Shape::Circle));
+        for hand in next_state.mut_hands() {
+            hand.push((Color::Blue, Shape::Square));
+        }
+        let hand = [(Color::Blue, Shape::Square)];
+
+        let action = MctsPlayer::new(SearchBudget::Iterations(4), None, None)
+            .choose(PlayerView::Next(next_state.next_view()), &hand);
+
+        assert_eq!(Action::Pass, action);
+    }
+
+    #[test]
+    fn rewards_share() {
+        let start: Points = [3, 5, 5].into_iter().collect();
+        let points: Points = [6, 5, 14].into_iter().collect();
+
+        assert_eq!(vec![0.25, 0.0, 0.75], rewards(&start, &points));
+        assert_eq!(
+            vec![0.5, 0.5],
+            rewards(&start[..2].into(), &start[..2].into())
+        );
+    }
+
+    #[test]
+    fn drive_game_mcts_player() {
+        let first_state = FirstState::new_random_first_player_with_seed(2, Some(1), None, None, 5)
+            .expect("new_random_first_player_with_seed should return Ok");
+        let budget = SearchBudget::Iterations(2);
+        let mut players: Vec<Box<dyn Player>> = vec![
+            Box::new(MctsPlayer::new_with_seed(budget, None, Some(1), 5)),
+            Box::new(GreedyPlayer::new()),
+        ];
+
+        let mut last_state = block_on(drive_game(first_state, &mut players, 0, 0))
+            .expect("drive_game should return Ok");
+
+        assert!(!last_state.mut_board().is_empty());
+    }
+}
@@ -1,25 +1,42 @@
 use crate::{
-    find_component_minimums_and_maximums, Bag, Board, Color, Hands, Points, Shape, TILES_LEN,
+    find_frontier, is_legal_tile_play, Bag, Board, Event, GameConfig, GameRng, Hands, Points, Tile,
+    TileIds,
 };
+use itertools::Itertools;
 
 pub use next_exchange::*;
+pub use next_hint::*;
+pub use next_pass::*;
 pub use next_play::*;
+pub use next_reorder::*;
 pub use next_view::*;
 
 mod next_exchange;
+mod next_hint;
+mod next_pass;
 mod next_play;
+mod next_reorder;
 mod next_view;
 #[cfg(test)]
 mod test_setup;
 
 /// Owns game state after the first turn but before the last turn and implements methods.
 /// Created from [FirstState::first_play](crate::FirstState::first_play).
-#[derive(Debug)]
+///
+/// Every [event](Event) is kept until it is [drained](NextState::drain_events), so a caller
+/// which never drains [events](Event) keeps a few [events](Event) for every turn of the game.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "NextStateFields")
+)]
 pub struct NextState {
     /// This is a bag of all the [tiles](crate::Tile) that haven't been removed yet.
     bag: Bag,
     /// This is a map of [coordinates](crate::Coordinate) to [tiles](crate::Tile) that
     /// have been played.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::board"))]
     board: Board,
     /// A vector of points for each player.
     points: Points,
@@ -28,6 +45,37 @@ pub struct NextState {
     hands: Hands,
     /// The index of the player whose turn it is.
     current_player: usize,
+    /// The number of players who have [passed](NextState::next_pass) in a row.
+    passes: usize,
+    /// Shuffles [exchanged](NextState::next_exchange) [tiles](crate::Tile) back into the bag.
+    rng: GameRng,
+    /// The number of copies of each [tile](crate::Tile) the game started with.
+    unique_tile_copied_count: usize,
+    /// Limits and bonuses of the game.
+    config: GameConfig,
+    /// Every [event](Event) which has not been [drained](NextState::drain_events) yet.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    events: Vec<Event>,
+    /// The [identities](crate::TileId) of every [tile](crate::Tile) or [None] if
+    /// [tile ids](crate::TileIds) are not tracked.
+    #[cfg_attr(feature = "serde", serde(default))]
+    tile_ids: Option<TileIds>,
+}
+
+/// Properties which the [first state](crate::FirstState) hands over to the [next state](NextState)
+/// without changing them on the first turn.
+pub(super) struct Carried {
+    /// Shuffles [exchanged](NextState::next_exchange) [tiles](crate::Tile) back into the bag.
+    pub(super) rng: GameRng,
+    /// The number of copies of each [tile](crate::Tile) the game started with.
+    pub(super) unique_tile_copied_count: usize,
+    /// Limits and bonuses of the game.
+    pub(super) config: GameConfig,
+    /// Every [event](Event) which has not been [drained](NextState::drain_events) yet.
+    pub(super) events: Vec<Event>,
+    /// The [identities](crate::TileId) of every [tile](crate::Tile) or [None] if
+    /// [tile ids](crate::TileIds) are not tracked.
+    pub(super) tile_ids: Option<TileIds>,
 }
 
 impl NextState {
@@ -40,6 +88,7 @@ impl NextState {
     /// * `hands`: A vector of hands for each player, where each hand is
     /// a vector of [tiles](crate::Tile).
     /// * `current_player`: The index of the player whose turn it is.
+    /// * `carried`: Properties handed over from the [first state](crate::FirstState).
     ///
     /// # Returns
     ///
@@ -50,6 +99,7 @@ impl NextState {
         points: Points,
         hands: Hands,
         current_player: usize,
+        carried: Carried,
     ) -> NextState {
         NextState {
             bag,
@@ -57,6 +107,12 @@ impl NextState {
             points,
             hands,
             current_player,
+            passes: 0,
+            rng: carried.rng,
+            unique_tile_copied_count: carried.unique_tile_copied_count,
+            config: carried.config,
+            events: carried.events,
+            tile_ids: carried.tile_ids,
         }
     }
 
@@ -65,25 +121,171 @@ impl NextState {
         self.current_player
     }
 
-    /// Whether the current player's hand is empty or the board is
-    /// a filled rectangle of [every color](Color::colors) and [every shape](Shape::shapes).
+    /// The [identities](crate::TileId) of every [tile](crate::Tile) or [None] if
+    /// [tile ids](TileIds) are not tracked.
+    pub fn tile_ids(&self) -> Option<&TileIds> {
+        self.tile_ids.as_ref()
+    }
+
+    /// Takes every [event](Event) in the order they happened since the last call.
+    pub fn drain_events(&mut self) -> Vec<Event> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Whether the player holds some [tile](crate::Tile) which can be legally
+    /// [played](NextState::next_play) on the board. If `player` is out of bounds,
+    /// returns `false`.
+    ///
+    /// # Arguments
+    ///
+    /// * `player`: The index of the player.
+    pub fn can_play(&self, player: usize) -> bool {
+        let Some(hand) = self.hands.get(player) else {
+            return false;
+        };
+        self.any_legal_tile_play(hand.iter().copied())
+    }
+
+    /// Whether the current player's hand is empty or no [tile](crate::Tile) held by any player
+    /// or left in the bag can be legally [played](NextState::next_play) on the board,
+    /// so no player can ever [play](NextState::next_play) again.
     pub(super) fn has_ended(&self) -> bool {
         if self.hands[self.current_player].is_empty() {
             return true;
         }
-        if self.board.len() != TILES_LEN {
-            return false;
+
+        !self.any_legal_tile_play(self.hands.iter().flatten().chain(&self.bag).copied())
+    }
+
+    /// Whether some [tile](crate::Tile) from `tiles` alone can be legally
+    /// [played](NextState::next_play) at some [coordinate](crate::Coordinate) in
+    /// the [frontier](find_frontier) of the board.
+    ///
+    /// # Arguments
+    ///
+    /// * `tiles`: An [iterator](Iterator) of [tiles](crate::Tile) which might be played.
+    fn any_legal_tile_play(&self, tiles: impl Iterator<Item = Tile>) -> bool {
+        let frontier = find_frontier(&self.board, self.config.coordinate_limit());
+        tiles.unique().any(|tile| {
+            frontier
+                .iter()
+                .any(|&coordinate| is_legal_tile_play(&self.board, coordinate, tile))
+        })
+    }
+}
+
+/// Every field of [NextState] except [events](Event), which is deserialized before
+/// being checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+pub(crate) struct NextStateFields {
+    bag: Bag,
+    #[serde(with = "crate::serialization::board")]
+    board: Board,
+    points: Points,
+    hands: Hands,
+    current_player: usize,
+    passes: usize,
+    rng: GameRng,
+    unique_tile_copied_count: usize,
+    config: GameConfig,
+    #[serde(default)]
+    tile_ids: Option<TileIds>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<NextStateFields> for NextState {
+    type Error = crate::StateErrors;
+
+    /// Checks that the current player and the points line up with the players, that
+    /// the board is within the limits of the [config](GameConfig), and that any
+    /// [tile ids](TileIds) line up with the [tiles](crate::Tile), so that an unreachable
+    /// state cannot be deserialized.
+    fn try_from(fields: NextStateFields) -> Result<Self, Self::Error> {
+        let mut errors = std::collections::HashSet::new();
+        crate::serialization::check_players(
+            &fields.hands,
+            Some(fields.current_player),
+            Some(fields.points.len()),
+            None,
+            &mut errors,
+        );
+        crate::serialization::check_tiles(
+            fields.bag.len(),
+            &fields.board,
+            &fields.hands,
+            &fields.config,
+            &mut errors,
+        );
+        crate::serialization::check_tile_ids(
+            fields.tile_ids.as_ref(),
+            Some(fields.bag.len()),
+            &fields.board,
+            &fields.hands,
+            &mut errors,
+        );
+        if !errors.is_empty() {
+            return Err(crate::StateErrors(errors));
         }
 
-        // More expensive (and still relatively cheap) check should be rarely reached
-        let Some((min_x, min_y, max_x, max_y)) =
-            find_component_minimums_and_maximums(self.board.keys().copied()) else {
-            return false;
-        };
+        Ok(NextState {
+            bag: fields.bag,
+            board: fields.board,
+            points: fields.points,
+            hands: fields.hands,
+            current_player: fields.current_player,
+            passes: fields.passes,
+            rng: fields.rng,
+            unique_tile_copied_count: fields.unique_tile_copied_count,
+            config: fields.config,
+            events: Vec::new(),
+            tile_ids: fields.tile_ids,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color, Hand, Shape};
+
+    #[test]
+    fn can_play() {
+        let mut next_state = NextState::empty_next_state();
+        next_state.points.extend([0, 0]);
+        next_state.board.insert((0, 0), (Color::Red, Shape::Circle));
+        next_state.hands.extend([
+            Hand::from_iter([(Color::Blue, Shape::Starburst), (Color::Red, Shape::Square)]),
+            Hand::from_iter([(Color::Blue, Shape::Starburst), (Color::Red, Shape::Circle)]),
+        ]);
+
+        assert!(next_state.can_play(0));
+        assert!(!next_state.can_play(1));
+        assert!(!next_state.can_play(2));
+    }
+
+    #[test]
+    fn has_ended_empty_hand() {
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rand::thread_rng());
+        next_state.board.insert((0, 0), (Color::Red, Shape::Circle));
+
+        assert!(next_state.has_ended());
+    }
+
+    #[test]
+    fn has_ended_bag_can_play() {
+        let mut next_state = NextState::empty_next_state();
+        next_state.points.push(0);
+        next_state.board.insert((0, 0), (Color::Red, Shape::Circle));
+        next_state
+            .hands
+            .push(Hand::from_iter([(Color::Blue, Shape::Starburst)]));
+
+        assert!(next_state.has_ended());
 
-        let (x_diff, y_diff) = (max_x.abs_diff(min_x), max_y.abs_diff(min_y));
+        next_state.bag.push((Color::Red, Shape::Square));
 
-        (x_diff == Color::COLORS_LEN - 1 && y_diff == Shape::SHAPES_LEN - 1)
-            || (y_diff == Color::COLORS_LEN - 1 && x_diff == Shape::SHAPES_LEN - 1)
+        assert!(!next_state.has_ended());
     }
 }
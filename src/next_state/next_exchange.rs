@@ -1,10 +1,12 @@
-use crate::{Exchanges, NextState};
+use crate::{resolve_exchanges, Event, Exchanges, NextState, Tile, TileExchanges};
 use itertools::Itertools;
+use map_macro::hash_set;
 use rand::distributions::{Distribution, Uniform};
 use std::collections::{BTreeSet, HashSet};
 
 /// Describes the reason why the [next exchange](NextState::next_exchange) could not be executed.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NextExchangeError {
     /// Attempting [to exchange](NextState::next_exchange) after the game has ended.
     HasEnded,
@@ -19,6 +21,12 @@ pub enum NextExchangeError {
         /// or the minimum illegal [exchange](Exchanges) index.
         hand_len: usize,
     },
+    /// Attempting [to exchange](NextState::next_exchange_tiles) [tiles](crate::Tile) by value
+    /// which are not held in the player's hand.
+    TilesNotHeld {
+        /// Each copy of a [tile](crate::Tile) which the current player does not hold.
+        tiles_not_held: TileExchanges,
+    },
     /// Attempting [to exchange](NextState::next_exchange) only illegal [tiles](crate::Tile).
     NoLegalTiles,
     /// Attempting [to exchange](NextState::next_exchange) more legal [tiles](crate::Tile)
@@ -36,7 +44,9 @@ impl NextState {
     /// returns all found errors. Otherwise, [exchanges](Exchanges) [tiles](crate::Tile)
     /// from the current player's hand with [tiles](crate::Tile)
     /// from the bag, ignores points,
-    /// and advances to the next player.
+    /// and advances to the next player. Returned [tiles](crate::Tile) are shuffled into the bag
+    /// with the [game rng](crate::GameRng), so [exchanges](Exchanges) are reproducible from
+    /// the seed that created the game.
     ///
     /// # Arguments
     ///
@@ -62,7 +72,8 @@ impl NextState {
 
         // Cannot filter or drain by tile since exchanges might request
         // a subset of duplicate tiles
-        let hand = &mut self.hands[self.current_player];
+        let player = self.current_player;
+        let hand = &mut self.hands[player];
         let tiles_from_hand = exchanges
             .iter()
             .rev()
@@ -71,23 +82,82 @@ impl NextState {
 
         // Drain the bag before adding tiles from the hand
         // so that tiles do not return into the hand
-        hand.extend(self.bag.drain(self.bag.len() - exchanges.len()..));
+        let drawn = self
+            .bag
+            .drain(self.bag.len() - exchanges.len()..)
+            .collect_vec();
+        hand.extend(drawn.iter().copied());
+        let (exchanged_ids, drawn_ids) = match &mut self.tile_ids {
+            Some(tile_ids) => {
+                let (exchanged_ids, drawn_ids) = tile_ids.exchange(player, exchanges);
+                (Some(exchanged_ids), Some(drawn_ids))
+            }
+            None => (None, None),
+        };
+
+        self.events.push(Event::TilesExchanged {
+            player,
+            tiles: exchanges
+                .iter()
+                .copied()
+                .zip(tiles_from_hand.iter().rev().copied())
+                .collect(),
+            ids: exchanged_ids,
+        });
+        self.events.push(Event::TilesDrawn {
+            player,
+            tiles: drawn,
+            ids: drawn_ids,
+        });
 
         // shuffle tiles in the bag, but in place
         // and without O(n log n) shuffle operation
-        let mut rng = rand::thread_rng();
         let start = self.bag.len();
         self.bag.extend(tiles_from_hand);
         let end = self.bag.len();
         let possible_indexes = Uniform::from(0..end);
         for index in start..end {
-            self.bag.swap(index, possible_indexes.sample(&mut rng));
+            let other_index = possible_indexes.sample(&mut self.rng);
+            self.bag.swap(index, other_index);
+            if let Some(tile_ids) = &mut self.tile_ids {
+                tile_ids.swap_bag(index, other_index);
+            }
         }
 
-        self.current_player = (self.current_player + 1) % self.hands.len();
+        self.current_player = (player + 1) % self.hands.len();
+        self.events.push(Event::TurnAdvanced {
+            player: self.current_player,
+        });
         Ok(())
     }
 
+    /// [Resolves](crate::resolve_exchanges) [tile exchanges](TileExchanges) against the current
+    /// order of the current player's hand and then [exchanges](NextState::next_exchange) them,
+    /// so [tiles](crate::Tile) are exchanged by value instead of by their index in the hand.
+    ///
+    /// # Arguments
+    ///
+    /// * `tile_exchanges`: A vector of [tiles](crate::Tile) to be exchanged, where each copy of
+    /// a [tile](crate::Tile) is exchanged once.
+    ///
+    /// # Errors
+    ///
+    /// * [NextExchangeError::TilesNotHeld] Attempting
+    /// [to exchange](NextState::next_exchange_tiles) [tiles](crate::Tile) by value which are not
+    /// held in the player's hand.
+    /// * Otherwise, any error returned by [next_exchange](NextState::next_exchange).
+    pub fn next_exchange_tiles(
+        &mut self,
+        tile_exchanges: &[Tile],
+    ) -> Result<(), HashSet<NextExchangeError>> {
+        match resolve_exchanges(&self.hands[self.current_player], tile_exchanges) {
+            Ok(exchanges) => self.next_exchange(&exchanges),
+            Err(tiles_not_held) => Err(hash_set! {
+                NextExchangeError::TilesNotHeld { tiles_not_held }
+            }),
+        }
+    }
+
     /// Checks whether [exchanges](Exchanges) matches various error conditions
     /// and returns all found errors.
     ///
@@ -149,9 +219,9 @@ impl NextState {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Color, NextState};
+    use crate::{Color, GameRng, NextState};
     use map_macro::{btree_set, hash_set};
-    use rand::Rng;
+    use rand::{Rng, SeedableRng};
     use tap::Tap;
 
     impl NextState {
@@ -333,6 +403,74 @@ mod tests {
         assert_eq!(1, counts[&second]);
     }
 
+    #[test]
+    fn exchange_tiles_by_value() {
+        let (mut next_state, _) = set_up_next_exchange();
+        let hand = next_state.hands[0].clone();
+        let tile_exchanges = hand.iter().rev().copied().collect_vec();
+        let bag_len = next_state.bag.len();
+
+        next_state
+            .next_exchange_tiles(&tile_exchanges)
+            .expect("next_exchange_tiles should return Ok");
+
+        assert_eq!(bag_len, next_state.bag.len());
+        assert_eq!(1, next_state.current_player);
+    }
+
+    #[test]
+    fn exchange_tiles_not_held() {
+        let (mut next_state, _) = set_up_next_exchange();
+        let hand = next_state.hands[0].clone();
+        let tile_exchanges = hand.iter().chain(&hand[..1]).copied().collect_vec();
+
+        let errors = next_state
+            .next_exchange_tiles(&tile_exchanges)
+            .expect_err("next_exchange_tiles should return Err");
+
+        assert_eq!(
+            hash_set! {
+                NextExchangeError::TilesNotHeld {
+                    tiles_not_held: vec![hand[0]],
+                }
+            },
+            errors
+        );
+        assert_eq!(hand, next_state.hands[0]);
+    }
+
+    #[test]
+    fn exchange_events() {
+        let (mut next_state, exchanges) = set_up_next_exchange();
+        let hand = next_state.hands[0].clone();
+
+        next_state
+            .next_exchange(&exchanges)
+            .expect("next_exchange should return Ok");
+
+        let tiles = exchanges
+            .iter()
+            .map(|&index| (index, hand[index]))
+            .collect();
+        let drawn = next_state.hands[0].to_vec();
+        assert_eq!(
+            vec![
+                Event::TilesExchanged {
+                    player: 0,
+                    tiles,
+                    ids: None,
+                },
+                Event::TilesDrawn {
+                    player: 0,
+                    tiles: drawn,
+                    ids: None,
+                },
+                Event::TurnAdvanced { player: 1 },
+            ],
+            next_state.drain_events()
+        );
+    }
+
     #[test]
     fn exchange_no_points() {
         let (mut next_state, exchanges) = set_up_next_exchange();
@@ -380,6 +518,23 @@ mod tests {
         assert_eq!(0, next_state.current_player);
     }
 
+    #[test]
+    fn exchange_reproducible() {
+        let seed = rand::thread_rng().gen();
+        let (mut first, exchanges) = set_up_next_exchange_from_seed(seed);
+        let (mut second, _) = set_up_next_exchange_from_seed(seed);
+
+        first
+            .next_exchange(&exchanges)
+            .expect("next_exchange should return Ok");
+        second
+            .next_exchange(&exchanges)
+            .expect("next_exchange should return Ok");
+
+        assert_eq!(first.bag, second.bag);
+        assert_eq!(first.hands, second.hands);
+    }
+
     fn set_up_next_exchange() -> (NextState, Exchanges) {
         let mut rng = rand::thread_rng();
         let mut next_state = NextState::empty_next_state();
@@ -392,4 +547,18 @@ mod tests {
 
         (next_state, exchanges)
     }
+
+    fn set_up_next_exchange_from_seed(seed: u64) -> (NextState, Exchanges) {
+        let mut rng = GameRng::seed_from_u64(seed);
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rng);
+        next_state.random_bag(&mut rng);
+        next_state.random_board(&mut rng);
+        let hand_len = next_state.random_hands(&mut rng);
+        next_state.rng = rng;
+
+        let exchanges = (0..hand_len).collect();
+
+        (next_state, exchanges)
+    }
 }
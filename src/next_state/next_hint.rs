@@ -0,0 +1,233 @@
+use crate::{Color, NextState, Plays, ScoreBreakdown, Shape, Tile, TilePlays};
+use itertools::Itertools;
+use std::cmp::{self, Reverse};
+
+/// A legal [play](Plays) suggested by [NextState::hints].
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hint {
+    /// A bimap of indexes of [tiles](Tile) in the player's hand to
+    /// [coordinates](crate::Coordinate) on the board.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::plays"))]
+    pub plays: Plays,
+    /// The points earned by the [play](Plays) and every line it forms.
+    pub score_breakdown: ScoreBreakdown,
+    /// The maximum number of unique [tiles](Tile) left in the hand after the [play](Plays)
+    /// which match each other, where a larger leave is more likely to score later.
+    pub leave: usize,
+}
+
+/// How [NextState::hints_ranked] orders [hints](Hint) from best to worst.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HintRanking {
+    /// By the most points, and then by the largest leave between equal points.
+    #[default]
+    Points,
+    /// By the most points plus leave, and then by the most points.
+    PointsAndLeave,
+}
+
+impl Hint {
+    /// The total points earned by the [play](Plays).
+    pub fn points(&self) -> usize {
+        self.score_breakdown.points()
+    }
+}
+
+impl NextState {
+    /// Same as [NextState::hints_ranked] with [HintRanking::Points].
+    ///
+    /// # Arguments
+    ///
+    /// * `player`: The index of the player whose hand is played from.
+    /// * `n`: The maximum number of [hints](Hint).
+    pub fn hints(&self, player: usize, n: usize) -> Vec<Hint> {
+        self.hints_ranked(player, n, HintRanking::Points)
+    }
+
+    /// Finds the `n` best legal [plays](Plays) from the player's hand, which would be accepted by
+    /// [next_play](NextState::next_play) if it were their turn. Plays of different copies of
+    /// the same [tiles](Tile) at the same [coordinates](crate::Coordinate) give a single
+    /// [hint](Hint). If `player` is out of bounds, returns an empty vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `player`: The index of the player whose hand is played from.
+    /// * `n`: The maximum number of [hints](Hint).
+    /// * `ranking`: How [hints](Hint) are ordered from best to worst.
+    ///
+    /// # See Also
+    ///
+    /// * [NextState::legal_plays]
+    /// * [NextView::legal_plays](crate::NextView::legal_plays)
+    ///
+    /// # Returns
+    ///
+    /// A vector of at most `n` [hints](Hint) from best to worst, where equally ranked
+    /// [hints](Hint) keep the order of [legal plays](NextState::legal_plays).
+    pub fn hints_ranked(&self, player: usize, n: usize, ranking: HintRanking) -> Vec<Hint> {
+        let Some(hand) = self.hands.get(player) else {
+            return Vec::new();
+        };
+
+        self.next_view()
+            .legal_plays(hand)
+            .into_iter()
+            // playing any copy of the same tiles gives the same hint
+            .unique_by(|(plays, _)| {
+                plays
+                    .iter()
+                    .map(|(&index, &coordinate)| (coordinate, hand[index]))
+                    .collect::<TilePlays>()
+            })
+            .map(|(plays, score_breakdown)| {
+                let leave = max_match(
+                    hand.iter()
+                        .enumerate()
+                        .filter(|(index, _)| !plays.contains_left(index))
+                        .map(|(_, &tile)| tile),
+                );
+                Hint {
+                    plays,
+                    score_breakdown,
+                    leave,
+                }
+            })
+            .sorted_by_key(|hint| match ranking {
+                HintRanking::Points => Reverse((hint.points(), hint.leave)),
+                HintRanking::PointsAndLeave => Reverse((hint.points() + hint.leave, hint.points())),
+            })
+            .take(n)
+            .collect()
+    }
+}
+
+/// The maximum number of unique [tiles](Tile) which match each other by either
+/// [color](Color) or [shape](Shape).
+fn max_match(tiles: impl IntoIterator<Item = Tile>) -> usize {
+    let mut count_colors = [0; Color::COLORS_LEN];
+    let mut count_shapes = [0; Shape::SHAPES_LEN];
+    let mut max_match = 0;
+    for (color, shape) in tiles.into_iter().unique() {
+        count_colors[color as usize] += 1;
+        count_shapes[shape as usize] += 1;
+        max_match = cmp::max(
+            max_match,
+            cmp::max(count_colors[color as usize], count_shapes[shape as usize]),
+        );
+    }
+    max_match
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set_up_next_state() -> NextState {
+        let mut next_state = NextState::empty_next_state();
+        next_state.mut_points().extend([0, 0]);
+        next_state
+            .mut_board()
+            .insert((0, 0), (Color::Red, Shape::Circle));
+        next_state.mut_hands().extend([
+            [(Color::Blue, Shape::X)].into_iter().collect(),
+            [
+                (Color::Red, Shape::Square),
+                (Color::Red, Shape::X),
+                (Color::Green, Shape::Diamond),
+                (Color::Green, Shape::Clover),
+            ]
+            .into_iter()
+            .collect(),
+        ]);
+        next_state
+    }
+
+    #[test]
+    fn hints_ranked_by_points() {
+        let next_state = set_up_next_state();
+
+        let hints = next_state.hints(1, 3);
+
+        assert_eq!(3, hints.len());
+        // both red tiles beside the red circle form 2 lines of 2
+        assert_eq!(4, hints[0].points());
+        assert_eq!(2, hints[0].plays.len());
+        assert_eq!(
+            vec![2, 2],
+            hints[0]
+                .score_breakdown
+                .lines
+                .iter()
+                .map(|line| line.len)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(2, hints[0].leave);
+        assert!(hints.windows(2).all(
+            |hints| (hints[0].points(), hints[0].leave) >= (hints[1].points(), hints[1].leave)
+        ));
+        for hint in &hints {
+            assert_eq!(
+                Ok(hint.score_breakdown.clone()),
+                next_state.next_view().preview_play(
+                    next_state.get_hand(1).expect("get_hand should return Some"),
+                    &hint.plays
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn hints_ranked_points_and_leave() {
+        let next_state = set_up_next_state();
+
+        let hints = next_state.hints_ranked(1, usize::MAX, HintRanking::PointsAndLeave);
+
+        assert_eq!(next_state.hints(1, usize::MAX).len(), hints.len());
+        assert!(hints.windows(2).all(|hints| {
+            hints[0].points() + hints[0].leave >= hints[1].points() + hints[1].leave
+        }));
+    }
+
+    #[test]
+    fn hints_copies_once() {
+        let mut next_state = set_up_next_state();
+        next_state.mut_hands()[1] = [(Color::Red, Shape::Square), (Color::Red, Shape::Square)]
+            .into_iter()
+            .collect();
+
+        let hints = next_state.hints(1, usize::MAX);
+
+        // a single red square beside the red circle in each of 4 directions
+        assert_eq!(4, hints.len());
+        let hand = next_state.get_hand(1).expect("get_hand should return Some");
+        assert_eq!(8, next_state.next_view().legal_plays(hand).len());
+    }
+
+    #[test]
+    fn hints_none() {
+        let next_state = set_up_next_state();
+
+        assert!(next_state.hints(0, 3).is_empty());
+        assert!(next_state.hints(1, 0).is_empty());
+        assert!(next_state.hints(2, 3).is_empty());
+    }
+
+    #[test]
+    fn max_match_unique() {
+        assert_eq!(0, max_match([]));
+        assert_eq!(
+            1,
+            max_match([(Color::Red, Shape::X), (Color::Red, Shape::X)])
+        );
+        assert_eq!(
+            2,
+            max_match([
+                (Color::Red, Shape::X),
+                (Color::Blue, Shape::Circle),
+                (Color::Red, Shape::Square),
+            ])
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use crate::{Event, LastState, NextState};
+use either::Either;
+use std::collections::HashSet;
+
+/// Describes the reason why the [next pass](NextState::next_pass) could not be executed.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NextPassError {
+    /// Attempting [to pass](NextState::next_pass) after the game has ended.
+    HasEnded,
+    /// Attempting [to pass](NextState::next_pass) while [tiles](crate::Tile) are still available
+    /// [to exchange](NextState::next_exchange).
+    NotEmptyBag {
+        /// The number of available [tiles](crate::Tile) in the bag.
+        bag_len: usize,
+    },
+}
+
+impl NextState {
+    /// Checks whether passing matches various error conditions and returns all found errors.
+    /// Otherwise, ignores points and advances to the next player. When every player has passed
+    /// in a row, no player can advance the game anymore, and the game ends without
+    /// a [last play bonus](crate::GameConfig::last_play_bonus).
+    ///
+    /// Passing is only allowed once the bag is empty. Until then, the current player can always
+    /// [exchange](NextState::next_exchange) instead.
+    ///
+    /// # Errors
+    ///
+    /// * [NextPassError::HasEnded] Attempting [to pass](NextState::next_pass)
+    /// after the game has ended.
+    /// * [NextPassError::NotEmptyBag] Attempting [to pass](NextState::next_pass)
+    /// while [tiles](crate::Tile) are still available [to exchange](NextState::next_exchange).
+    ///
+    /// # Returns
+    ///
+    /// Either the [next state](NextState) or the [last state](LastState) of the game
+    /// after the pass.
+    pub fn next_pass(
+        mut self,
+    ) -> Result<Either<NextState, LastState>, (Self, HashSet<NextPassError>)> {
+        if let Err(errors) = self.check_pass() {
+            return Err((self, errors));
+        }
+
+        self.passes += 1;
+        if self.passes >= self.hands.len() {
+            self.events.push(Event::GameEnded {
+                points: self.points.clone(),
+            });
+            Ok(Either::Right(LastState::new(
+                self.board,
+                self.points,
+                self.hands,
+                self.config,
+                self.events,
+                self.tile_ids,
+            )))
+        } else {
+            self.current_player = (self.current_player + 1) % self.hands.len();
+            self.events.push(Event::TurnAdvanced {
+                player: self.current_player,
+            });
+            Ok(Either::Left(self))
+        }
+    }
+
+    /// Checks whether passing matches various error conditions and returns all found errors.
+    ///
+    /// # Errors
+    ///
+    /// * [NextPassError::HasEnded] Attempting [to pass](NextState::next_pass)
+    /// after the game has ended.
+    /// * [NextPassError::NotEmptyBag] Attempting [to pass](NextState::next_pass)
+    /// while [tiles](crate::Tile) are still available [to exchange](NextState::next_exchange).
+    fn check_pass(&self) -> Result<(), HashSet<NextPassError>> {
+        let mut errors = HashSet::with_capacity(2);
+        if self.has_ended() {
+            errors.insert(NextPassError::HasEnded);
+        }
+
+        let bag_len = self.bag.len();
+        if bag_len > 0 {
+            errors.insert(NextPassError::NotEmptyBag { bag_len });
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color, Shape};
+    use map_macro::hash_set;
+
+    impl NextState {
+        fn test_next_pass_errors(self, expected_error: HashSet<NextPassError>) {
+            let (_, actual_error) = self.next_pass().expect_err("next_pass should return Err");
+
+            assert_eq!(expected_error, actual_error);
+        }
+    }
+
+    #[test]
+    fn has_ended() {
+        let mut rng = rand::thread_rng();
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rng);
+        next_state.deadlocked_board();
+        next_state.random_hands(&mut rng);
+
+        next_state.test_next_pass_errors(hash_set! { NextPassError::HasEnded });
+    }
+
+    #[test]
+    fn not_empty_bag() {
+        let mut rng = rand::thread_rng();
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rng);
+        let bag_len = next_state.random_bag(&mut rng);
+        next_state.random_board(&mut rng);
+        next_state.random_hands(&mut rng);
+
+        next_state.test_next_pass_errors(hash_set! { NextPassError::NotEmptyBag { bag_len } });
+    }
+
+    #[test]
+    fn has_ended_not_empty_bag() {
+        let mut rng = rand::thread_rng();
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rng);
+        let bag_len = next_state.random_bag(&mut rng);
+        next_state.random_board(&mut rng);
+
+        next_state.test_next_pass_errors(hash_set! {
+            NextPassError::HasEnded,
+            NextPassError::NotEmptyBag { bag_len },
+        });
+    }
+
+    #[test]
+    fn pass_no_points() {
+        let mut next_state = set_up_next_pass();
+        next_state.random_points(&mut rand::thread_rng());
+        let points = next_state.points.clone();
+
+        let next_state = next_state
+            .next_pass()
+            .expect("next_pass should return Ok")
+            .expect_left("Ok should contain next_state");
+
+        assert_eq!(points, next_state.points);
+        assert_eq!(1, next_state.passes);
+    }
+
+    #[test]
+    fn pass_increment_current_player() {
+        let next_state = set_up_next_pass();
+
+        let next_state = next_state
+            .next_pass()
+            .expect("next_pass should return Ok")
+            .expect_left("Ok should contain next_state");
+
+        assert_eq!(1, next_state.current_player);
+    }
+
+    #[test]
+    fn pass_wrap_current_player() {
+        let mut next_state = set_up_next_pass();
+        next_state.current_player = next_state.points.len() - 1;
+
+        let next_state = next_state
+            .next_pass()
+            .expect("next_pass should return Ok")
+            .expect_left("Ok should contain next_state");
+
+        assert_eq!(0, next_state.current_player);
+    }
+
+    #[test]
+    fn every_player_passes() {
+        let mut next_state = set_up_next_pass();
+        next_state.random_points(&mut rand::thread_rng());
+        let points = next_state.points.clone();
+        let players = points.len();
+
+        for _ in 1..players {
+            next_state = next_state
+                .next_pass()
+                .expect("next_pass should return Ok")
+                .expect_left("Ok should contain next_state");
+        }
+        let mut last_state = next_state
+            .next_pass()
+            .expect("next_pass should return Ok")
+            .expect_right("Ok should contain last_state");
+
+        assert_eq!(points, *last_state.mut_points());
+    }
+
+    #[test]
+    fn every_player_passes_events() {
+        let mut next_state = set_up_next_pass();
+        let players = next_state.points.len();
+
+        for player in 1..players {
+            next_state = next_state
+                .next_pass()
+                .expect("next_pass should return Ok")
+                .expect_left("Ok should contain next_state");
+
+            assert_eq!(
+                vec![Event::TurnAdvanced { player }],
+                next_state.drain_events()
+            );
+        }
+        let mut last_state = next_state
+            .next_pass()
+            .expect("next_pass should return Ok")
+            .expect_right("Ok should contain last_state");
+
+        let points = last_state.mut_points().clone();
+        assert_eq!(vec![Event::GameEnded { points }], last_state.drain_events());
+    }
+
+    #[test]
+    fn play_resets_passes() {
+        let mut next_state = set_up_next_pass();
+        next_state.passes = next_state.points.len() - 1;
+        let hand = &mut next_state.hands[next_state.current_player];
+        let index = hand.len();
+        hand.push((Color::Red, Shape::Square));
+
+        let next_state = next_state
+            .next_play(&[(index, (1, 0))].into_iter().collect())
+            .expect("next_play should return Ok")
+            .0
+            .expect_left("Ok should contain next_state");
+
+        assert_eq!(0, next_state.passes);
+    }
+
+    fn set_up_next_pass() -> NextState {
+        let mut rng = rand::thread_rng();
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rng);
+        next_state.random_hands(&mut rng);
+        next_state
+            .mut_board()
+            .insert((0, 0), (Color::Red, Shape::Circle));
+        // some player can still play, so the game has not ended
+        for hand in next_state.mut_hands() {
+            hand.push((Color::Red, Shape::Square));
+        }
+
+        next_state
+    }
+}
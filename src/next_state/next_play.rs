@@ -1,17 +1,19 @@
 use crate::{
     adjacent_coordinates, batch_continuous_decreasing_range, batch_continuous_increasing_range,
     check_line, find_component_minimums_and_maximums, find_coordinate_by_minimum_distance,
-    partition_by_coordinates, Board, Coordinate, LastState, NextState, Plays, Tile, HOLES_LIMIT,
-    LAST_PLAY_BONUS,
+    partition_by_coordinates, resolve_plays, Board, Coordinate, Event, GameConfig, LastState,
+    NextState, Plays, ScoreBreakdown, Tile, TilePlays,
 };
 use either::Either;
 use itertools::Itertools;
+use map_macro::hash_set;
 use std::collections::{BTreeSet, HashSet};
 use std::iter;
 use std::ops::Index;
 
 /// Describes the reason why the [next play](NextState::next_play) could not be executed.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NextPlayError {
     /// Attempting [to play](NextState::next_play) no [tiles](Tile).
     EmptyPlays,
@@ -19,13 +21,23 @@ pub enum NextPlayError {
     /// in the current player's hand.
     IndexesOutOfBounds {
         /// [Plays](Plays) where the index is greater than or equal to `hand_len`.
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::plays"))]
         indexes_out_of_bounds: Plays,
     },
+    /// Attempting [to play](NextState::next_play_tiles) [tiles](Tile) by value which are not
+    /// held in the current player's hand.
+    TilesNotHeld {
+        /// [Tile plays](TilePlays) where the current player does not hold another copy
+        /// of the [tile](Tile).
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::tile_plays"))]
+        tiles_not_held: TilePlays,
+    },
     /// Attempting [to play](NextState::next_play) [tiles](Tile) too far away from
     /// the center of the board.
     CoordinatesOutOfBounds {
         /// [Plays](Plays) where the absolute value of a component in a [coordinate](Coordinate) is
-        /// greater than or equal to the [coordinate limit](crate::COORDINATE_LIMIT).
+        /// greater than or equal to the [coordinate limit](crate::GameConfig::coordinate_limit).
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::plays"))]
         coordinates_out_of_bounds: Plays,
     },
     /// Attempting [to play](NextState::next_play) at already occupied [coordinates](Coordinate)
@@ -33,6 +45,7 @@ pub enum NextPlayError {
     CoordinatesOccupied {
         /// [Plays](Plays) where board already contains a [tile](Tile)
         /// at the [coordinate](Coordinate).
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::plays"))]
         coordinates_occupied: Plays,
     },
     /// Attempting [to play](NextState::next_play) [tiles](Tile) not connected
@@ -41,6 +54,7 @@ pub enum NextPlayError {
         /// [Plays](Plays) where there are no adjacent [tiles](Tile) on the board
         /// or no path through other connected [plays](Plays) to a [tile](Tile)
         /// on the board.
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::plays"))]
         not_connected: Plays,
     },
     /// Attempting to only [play](NextState::next_play) illegal [plays](Plays).
@@ -85,14 +99,12 @@ impl NextState {
     /// The number of points from a line is the number of [tiles](Tile) in that line. If the line
     /// creates a full match on the board where a line contains either
     /// [every color](crate::Color::colors) or [every shape](crate::Shape::shapes), an extra
-    /// [full match bonus](crate::FULL_MATCH_BONUS) is earned.
+    /// [full match bonus](crate::GameConfig::full_match_bonus) is earned.
     ///
     /// If the current player's hand is empty (and therefore no [tiles](Tile) in
-    /// the bag are available) or the board becomes deadlocked
-    /// (a filled rectangle of [every color](crate::Color::colors) and every
-    /// [shapes](crate::Shape::shapes)) where no additional [plays](Plays)
-    /// are allowed despite players still holding some [tiles](Tile), an extra
-    /// [last play bonus](LAST_PLAY_BONUS) is earned.
+    /// the bag are available) or no [tile](Tile) in any hand or the bag can be legally
+    /// [played](Plays) anywhere on the board despite players still holding some [tiles](Tile),
+    /// an extra [last play bonus](crate::GameConfig::last_play_bonus) is earned.
     ///
     /// # Arguments
     ///
@@ -126,42 +138,203 @@ impl NextState {
     /// # Returns
     ///
     /// Either the [next state](NextState) or the [last state](LastState) of the game
-    /// after the [play](Plays).
+    /// after the [play](Plays) and the [score breakdown](ScoreBreakdown) of the [play](Plays).
     pub fn next_play(
         mut self,
         plays: &Plays,
-    ) -> Result<Either<NextState, LastState>, (Self, HashSet<NextPlayError>)> {
-        let next_play_points = match self.check_plays(plays) {
-            Ok(points) => points,
-            Err(errors) => return Err((self, errors)),
-        };
-
-        let hand = &mut self.hands[self.current_player];
+    ) -> Result<(Either<NextState, LastState>, ScoreBreakdown), (Self, HashSet<NextPlayError>)>
+    {
+        let hand = &self.hands[self.current_player];
+        let mut score_breakdown =
+            match NextState::check_plays(&self.board, hand, &self.config, plays) {
+                Ok(score_breakdown) => score_breakdown,
+                Err(errors) => return Err((self, errors)),
+            };
+
+        let player = self.current_player;
+        let hand = &mut self.hands[player];
+        let mut tiles = plays
+            .iter()
+            .rev()
+            .map(|(&index, &coordinate)| (index, coordinate, hand.remove(index)))
+            .collect_vec();
+        tiles.reverse();
         self.board.extend(
-            plays
+            tiles
                 .iter()
-                .rev()
-                .map(|(&index, &coordinate)| (coordinate, hand.remove(index))),
+                .map(|&(_, coordinate, tile)| (coordinate, tile)),
         );
         // when the bag is empty, no more tiles will be drained
-        hand.extend(self.bag.drain(self.bag.len().saturating_sub(plays.len())..));
+        let drawn = self
+            .bag
+            .drain(self.bag.len().saturating_sub(plays.len())..)
+            .collect_vec();
+        hand.extend(drawn.iter().copied());
+        let (played_ids, drawn_ids) = match &mut self.tile_ids {
+            Some(tile_ids) => {
+                let (played_ids, drawn_ids) = tile_ids.play(player, plays, drawn.len());
+                (Some(played_ids), Some(drawn_ids))
+            }
+            None => (None, None),
+        };
+
+        self.events.push(Event::TilesPlayed {
+            player,
+            tiles,
+            ids: played_ids,
+        });
+        if !drawn.is_empty() {
+            self.events.push(Event::TilesDrawn {
+                player,
+                tiles: drawn,
+                ids: drawn_ids,
+            });
+        }
 
-        if self.has_ended() {
-            self.points[self.current_player] += next_play_points + LAST_PLAY_BONUS;
-            Ok(Either::Right(LastState::new(
+        let has_ended = self.has_ended();
+        if has_ended {
+            score_breakdown.last_play = true;
+            score_breakdown.last_play_bonus = self.config.last_play_bonus();
+        }
+        self.points[player] += score_breakdown.points();
+        self.events.push(Event::PointsAwarded {
+            player,
+            score_breakdown: score_breakdown.clone(),
+            total: self.points[player],
+        });
+
+        if has_ended {
+            self.events.push(Event::GameEnded {
+                points: self.points.clone(),
+            });
+            let last_state = LastState::new(
                 self.board,
                 self.points,
                 self.hands,
-            )))
+                self.config,
+                self.events,
+                self.tile_ids,
+            );
+            Ok((Either::Right(last_state), score_breakdown))
         } else {
-            self.points[self.current_player] += next_play_points;
-            self.current_player = (self.current_player + 1) % self.hands.len();
-            Ok(Either::Left(self))
+            self.passes = 0;
+            self.current_player = (player + 1) % self.hands.len();
+            self.events.push(Event::TurnAdvanced {
+                player: self.current_player,
+            });
+            Ok((Either::Left(self), score_breakdown))
         }
     }
 
+    /// [Resolves](crate::resolve_plays) [tile plays](TilePlays) against the current order of
+    /// the current player's hand and then [plays](NextState::next_play) them, so [tiles](Tile)
+    /// are played by value instead of by their index in the hand.
+    ///
+    /// # Arguments
+    ///
+    /// * `tile_plays`: A map of [coordinates](Coordinate) on the board to [tiles](Tile)
+    /// to be played.
+    ///
+    /// # Errors
+    ///
+    /// * [NextPlayError::TilesNotHeld] Attempting [to play](NextState::next_play_tiles)
+    /// [tiles](Tile) by value which are not held in the current player's hand.
+    /// * Otherwise, any error returned by [next_play](NextState::next_play).
+    ///
+    /// # Returns
+    ///
+    /// Either the [next state](NextState) or the [last state](LastState) of the game
+    /// after the [play](Plays) and the [score breakdown](ScoreBreakdown) of the [play](Plays).
+    pub fn next_play_tiles(
+        self,
+        tile_plays: &TilePlays,
+    ) -> Result<(Either<NextState, LastState>, ScoreBreakdown), (Self, HashSet<NextPlayError>)>
+    {
+        match resolve_plays(&self.hands[self.current_player], tile_plays) {
+            Ok(plays) => self.next_play(&plays),
+            Err(tiles_not_held) => Err((
+                self,
+                hash_set! { NextPlayError::TilesNotHeld { tiles_not_held } },
+            )),
+        }
+    }
+
+    /// Checks if the [plays](Plays) are valid and returns the [score breakdown](ScoreBreakdown)
+    /// which would be earned by [playing](NextState::next_play) them without changing the game.
+    ///
+    /// # Points Calculation
+    ///
+    /// The number of points earned by a [play](Plays) is the sum of points scored from each line
+    /// that contains played [tiles](Tile). Each [tile](Tile) can be counted twice if
+    /// the [tile](Tile) is a part of a vertical and horizontal line.
+    ///
+    /// The number of points from a line is the number of [tiles](Tile) in that line. If the line
+    /// creates a full match on the board where a line contains either
+    /// [every color](crate::Color::colors) or [every shape](crate::Shape::shapes), an extra
+    /// [full match bonus](crate::GameConfig::full_match_bonus) is earned.
+    ///
+    /// The [last play bonus](crate::GameConfig::last_play_bonus) is not included since whether
+    /// the game ends depends on the [tiles](Tile) drawn from the bag after the [play](Plays).
+    ///
+    /// # Arguments
+    ///
+    /// * `plays`: A bimap of indexes of [tiles](Tile) to be played to [coordinates](Coordinate)
+    /// on the board.
+    ///
+    /// # Errors
+    ///
+    /// * [NextPlayError::EmptyPlays] Attempting [to play](NextState::next_play) no [tiles](Tile).
+    /// * [NextPlayError::IndexesOutOfBounds] Attempting [to play](NextState::next_play)
+    /// [tiles](Tile) not in the current player's hand.
+    /// * [NextPlayError::CoordinatesOutOfBounds] Attempting [to play](NextState::next_play)
+    /// [tiles](Tile) too far away from the center of the board.
+    /// * [NextPlayError::CoordinatesOccupied] Attempting to
+    /// [play](NextState::next_play) at already occupied [coordinates](Coordinate)
+    /// on the board.
+    /// * [NextPlayError::NotConnected] Attempting [to play](NextState::next_play) [tiles](Tile)
+    /// not connected to the board.
+    /// * [NextPlayError::NoLegalPlays] Attempting to only [play](NextState::next_play)
+    /// illegal [plays](Plays).
+    /// * [NextPlayError::NoLegalLines] Not attempting [to play](NextState::next_play)
+    /// [tiles](Tile) in a point or a line.
+    /// * [NextPlayError::Holes] Attempting [to play](NextState::next_play) [tiles](Tile) in a line
+    /// but not the same connected line.
+    /// * [NextPlayError::Duplicates] Attempting [to play](NextState::next_play)
+    /// duplicate [tiles](Tile) in a line.
+    /// * [NextPlayError::MultipleMatching] Attempting [to play](NextState::next_play) a line
+    /// where [tiles](Tile) are not either the same [shape](crate::Shape)
+    /// or the same [color](crate::Color).
+    ///
+    /// # Returns
+    ///
+    /// The [score breakdown](ScoreBreakdown) of [plays](Plays).
+    pub fn preview_play(&self, plays: &Plays) -> Result<ScoreBreakdown, HashSet<NextPlayError>> {
+        let hand = &self.hands[self.current_player];
+        NextState::check_plays(&self.board, hand, &self.config, plays)
+    }
+
+    /// Finds every [play](Plays) from the current player's hand that
+    /// [next_play](NextState::next_play) would accept along with
+    /// the [score breakdown](ScoreBreakdown) of it.
+    /// When the hand holds copies of a [tile](Tile), every [play](Plays) of that [tile](Tile) is
+    /// given once for the index of each copy.
+    ///
+    /// # See Also
+    ///
+    /// * [legal_plays](crate::legal_plays)
+    ///
+    /// # Returns
+    ///
+    /// A vector of legal [plays](Plays) and the [score breakdown](ScoreBreakdown) of
+    /// each [play](Plays).
+    pub fn legal_plays(&self) -> Vec<(Plays, ScoreBreakdown)> {
+        let hand = &self.hands[self.current_player];
+        self.next_view().legal_plays(hand)
+    }
+
     /// Takes a bimap of indexes of [tiles](Tile) to be played to [coordinates](Coordinate)
-    /// on the board and returns earned points if the bimap only creates legal lines.
+    /// on the board and returns the [score breakdown](ScoreBreakdown) if the bimap only creates
+    /// legal lines.
     /// Otherwise, it returns the errors.
     ///
     /// # Points Calculation
@@ -173,10 +346,13 @@ impl NextState {
     /// The number of points from a line is the number of [tiles](Tile) in that line.
     /// If the line creates a full match on the board where a line contains either
     /// [every color](crate::Color::colors) or [every shape](crate::Shape::shapes),
-    /// an extra [FULL_MATCH_BONUS](crate::FULL_MATCH_BONUS) points are earned.
+    /// an extra [full match bonus](crate::GameConfig::full_match_bonus) is earned.
     ///
     /// # Arguments
     ///
+    /// * `board`: A map of [coordinates](Coordinate) to [tiles](Tile) that have been played.
+    /// * `hand`: A vector of [tiles](Tile) to be played.
+    /// * `config`: Limits and bonuses of the game.
     /// * `plays`: A bimap of indexes of [tiles](Tile) to be played to [coordinates](Coordinate)
     /// on the board.
     ///
@@ -184,7 +360,7 @@ impl NextState {
     ///
     /// * [NextPlayError::EmptyPlays] Attempting [to play](NextState::next_play) no [tiles](Tile).
     /// * [NextPlayError::IndexesOutOfBounds] Attempting [to play](NextState::next_play)
-    /// [tiles](Tile) not in the current player's hand.
+    /// [tiles](Tile) not in `hand`.
     /// * [NextPlayError::CoordinatesOutOfBounds] Attempting [to play](NextState::next_play)
     /// [tiles](Tile) too far away from the center of the board.
     /// * [NextPlayError::CoordinatesOccupied] Attempting to
@@ -206,16 +382,21 @@ impl NextState {
     ///
     /// # Returns
     ///   
-    /// The earned points from [plays](Plays).
-    fn check_plays(&self, plays: &Plays) -> Result<usize, HashSet<NextPlayError>> {
+    /// The [score breakdown](ScoreBreakdown) of [plays](Plays).
+    pub(super) fn check_plays(
+        board: &Board,
+        hand: &[Tile],
+        config: &GameConfig,
+        plays: &Plays,
+    ) -> Result<ScoreBreakdown, HashSet<NextPlayError>> {
         let mut errors = HashSet::with_capacity(10);
+        let holes_limit = config.holes_limit();
 
         if plays.is_empty() {
             errors.insert(NextPlayError::EmptyPlays);
             return Err(errors);
         }
 
-        let hand = &self.hands[self.current_player];
         let hand_len = hand.len();
         let indexes_out_of_bounds: Plays = plays
             .left_range(hand_len..)
@@ -227,7 +408,8 @@ impl NextState {
             });
         }
 
-        let (coordinates_in_bounds, coordinates_out_of_bounds) = partition_by_coordinates(plays);
+        let (coordinates_in_bounds, coordinates_out_of_bounds) =
+            partition_by_coordinates(plays, config.coordinate_limit());
         if !coordinates_out_of_bounds.is_empty() {
             errors.insert(NextPlayError::CoordinatesOutOfBounds {
                 coordinates_out_of_bounds,
@@ -236,14 +418,15 @@ impl NextState {
 
         let (coordinates_unoccupied, coordinates_occupied): (Plays, Plays) = coordinates_in_bounds
             .into_iter()
-            .partition(|(_, coordinate)| !self.board.contains_key(coordinate));
+            .partition(|(_, coordinate)| !board.contains_key(coordinate));
         if !coordinates_occupied.is_empty() {
             errors.insert(NextPlayError::CoordinatesOccupied {
                 coordinates_occupied,
             });
         }
 
-        let (connected, not_connected) = self.partition_connected(coordinates_unoccupied);
+        let (connected, not_connected) =
+            NextState::partition_connected(board, coordinates_unoccupied);
         if !not_connected.is_empty() {
             errors.insert(NextPlayError::NotConnected { not_connected });
         }
@@ -268,14 +451,14 @@ impl NextState {
         // If the range of coordinates is large, hole will be large
         // and slow performance down. Limiting the range of coordinates
         // is necessary to limiting time and memory cost.
-        // Also, COORDINATE_LIMIT and HOLES_LIMIT should prevent overflow.
+        // Also, the coordinate limit and the holes limit should prevent overflow.
         let (holes, lines): (BTreeSet<(Coordinate, Coordinate)>, Vec<Board>) = if min_x == max_x {
             let increasing = (mid_y + 1..=max_y)
                 .filter(|&y| {
                     let coordinate = (min_x, y);
-                    !self.board.contains_key(&coordinate) && !plays.contains_right(&coordinate)
+                    !board.contains_key(&coordinate) && !plays.contains_right(&coordinate)
                 })
-                .take((HOLES_LIMIT + 1) / 2)
+                .take((holes_limit + 1) / 2)
                 .peekable()
                 .batching(batch_continuous_increasing_range)
                 .map(|(first, last)| ((min_x, first), (min_x, last)));
@@ -283,16 +466,18 @@ impl NextState {
                 .rev()
                 .filter(|&y| {
                     let coordinate = (min_x, y);
-                    !self.board.contains_key(&coordinate) && !plays.contains_right(&coordinate)
+                    !board.contains_key(&coordinate) && !plays.contains_right(&coordinate)
                 })
-                .take((HOLES_LIMIT + HOLES_LIMIT % 2) / 2)
+                .take((holes_limit + holes_limit % 2) / 2)
                 .peekable()
                 .batching(batch_continuous_decreasing_range)
                 .map(|(first, last)| ((min_x, first), (min_x, last)));
             let holes = increasing.chain(decreasing).collect();
 
             // horizontal lines perpendicular to the vertical line legal_plays
-            let lines = self.find_lines(
+            let lines = NextState::find_lines(
+                board,
+                hand,
                 &legal_plays,
                 (mid_y..).map(|next_y| (mid_x, next_y)),
                 (1..)
@@ -311,9 +496,9 @@ impl NextState {
             let increasing = (mid_x + 1..=max_x)
                 .filter(|&x| {
                     let coordinate = (x, min_y);
-                    !self.board.contains_key(&coordinate) && !plays.contains_right(&coordinate)
+                    !board.contains_key(&coordinate) && !plays.contains_right(&coordinate)
                 })
-                .take((HOLES_LIMIT + 1) / 2)
+                .take((holes_limit + 1) / 2)
                 .peekable()
                 .batching(batch_continuous_increasing_range)
                 .map(|(first, last)| ((first, min_y), (last, min_y)));
@@ -321,16 +506,18 @@ impl NextState {
                 .rev()
                 .filter(|&x| {
                     let coordinate = (x, min_y);
-                    !self.board.contains_key(&coordinate) && !plays.contains_right(&coordinate)
+                    !board.contains_key(&coordinate) && !plays.contains_right(&coordinate)
                 })
-                .take((HOLES_LIMIT + HOLES_LIMIT % 2) / 2)
+                .take((holes_limit + holes_limit % 2) / 2)
                 .peekable()
                 .batching(batch_continuous_decreasing_range)
                 .map(|(first, last)| ((first, min_y), (last, min_y)));
             let holes = increasing.chain(decreasing).collect();
 
             // vertical lines perpendicular to the horizontal line legal_plays
-            let lines = self.find_lines(
+            let lines = NextState::find_lines(
+                board,
+                hand,
                 &legal_plays,
                 (mid_x..).map(|next_x| (next_x, mid_y)),
                 (1..)
@@ -354,10 +541,10 @@ impl NextState {
             errors.insert(NextPlayError::Holes { holes });
         }
 
-        let mut total_points = 0;
+        let mut score_breakdown = ScoreBreakdown::default();
 
         for line in lines {
-            match check_line(&line) {
+            match check_line(&line, config.full_match_bonus()) {
                 Err((duplicates, multiple_matching)) => {
                     if !duplicates.is_empty() {
                         errors.insert(NextPlayError::Duplicates { duplicates });
@@ -366,8 +553,8 @@ impl NextState {
                         errors.insert(NextPlayError::MultipleMatching { multiple_matching });
                     }
                 }
-                Ok(points) => {
-                    total_points += points;
+                Ok(line_score) => {
+                    score_breakdown.lines.push(line_score);
                 }
             }
         }
@@ -376,7 +563,7 @@ impl NextState {
             return Err(errors);
         }
 
-        Ok(total_points)
+        Ok(score_breakdown)
     }
 
     /// Partitions `possibly_connected` by whether the [coordinate](Coordinate) is connected
@@ -387,12 +574,13 @@ impl NextState {
     ///
     /// # Arguments
     ///
+    /// * `board`: A map of [coordinates](Coordinate) to [tiles](Tile) that have been played.
     /// * `possibly_connected`: [Plays] which may or may not be connected to the board.
     ///
     /// # Returns
     ///   
     /// A tuple of connected and not connected [plays](Plays).
-    fn partition_connected(&self, possibly_connected: Plays) -> (Plays, Plays) {
+    fn partition_connected(board: &Board, possibly_connected: Plays) -> (Plays, Plays) {
         let capacity = possibly_connected.len();
         let mut connected = HashSet::with_capacity(capacity);
 
@@ -416,7 +604,7 @@ impl NextState {
                 // overflow should not occur since coordinates in plays should
                 // not be isize::MIN or isize::MAX
                 for adjacent_coordinate in adjacent_coordinates(coordinate) {
-                    if self.board.contains_key(&adjacent_coordinate)
+                    if board.contains_key(&adjacent_coordinate)
                         || connected.contains(&adjacent_coordinate)
                     {
                         // connected directly or indirectly
@@ -460,7 +648,8 @@ impl NextState {
     /// A vector of the line containing `plays` plus the board lines extending from
     /// each [tile](Tile)in `plays`.
     fn find_lines<I, D>(
-        &self,
+        board: &Board,
+        hand: &[Tile],
         legal_plays: &Plays,
         increasing: impl Iterator<Item = Coordinate>,
         decreasing: impl Iterator<Item = Coordinate>,
@@ -471,12 +660,11 @@ impl NextState {
         I: Iterator<Item = Coordinate>,
         D: Iterator<Item = Coordinate>,
     {
-        let hand = &self.hands[self.current_player];
         let get_plays_or_board = |coordinate: Coordinate| -> Option<(Coordinate, Tile)> {
             legal_plays
                 .get_by_right(&coordinate)
                 .map(|&index| hand.index(index))
-                .or_else(|| self.board.get(&coordinate))
+                .or_else(|| board.get(&coordinate))
                 .map(|&tile| (coordinate, tile))
         };
         let increasing = increasing.map(get_plays_or_board).while_some();
@@ -487,10 +675,10 @@ impl NextState {
             .iter()
             .map(|(&index, &plays_coordinate)| {
                 let increasing = into_increasing(plays_coordinate)
-                    .map(|coordinate| self.board.get(&coordinate).map(|&tile| (coordinate, tile)))
+                    .map(|coordinate| board.get(&coordinate).map(|&tile| (coordinate, tile)))
                     .while_some();
                 let decreasing = into_decreasing(plays_coordinate)
-                    .map(|coordinate| self.board.get(&coordinate).map(|&tile| (coordinate, tile)))
+                    .map(|coordinate| board.get(&coordinate).map(|&tile| (coordinate, tile)))
                     .while_some();
                 iter::once((plays_coordinate, hand[index]))
                     .chain(increasing)
@@ -508,11 +696,11 @@ mod tests {
     use super::*;
     use crate::{
         random_different_color_same_shape, random_different_shape_same_color,
-        random_illegal_coordinates, Color, Hand, Shape, Tile, COORDINATE_LIMIT, FULL_MATCH_BONUS,
-        HAND_CAPACITY,
+        random_illegal_coordinates, Color, GameConfig, Hand, Shape, Tile, COORDINATE_LIMIT,
+        FULL_MATCH_BONUS, HAND_CAPACITY, HOLES_LIMIT, LAST_PLAY_BONUS,
     };
     use bimap::BiBTreeMap;
-    use map_macro::{btree_set, hash_set};
+    use map_macro::{btree_map, btree_set, hash_set};
     use rand::Rng;
     use tap::Tap;
 
@@ -969,7 +1157,8 @@ mod tests {
         let fourth = (Color::Green, Shape::Square);
         hand.extend([first, second, third, fourth]);
 
-        let bag_tile = (Color::Orange, rng.gen());
+        // bag_tile can still be played next to (0, 0) so that the game does not end
+        let bag_tile = (Color::Orange, Shape::X);
         let bag_len = rng.gen_range(hand.len()..10);
         next_state.bag.extend((0..bag_len).map(|_| bag_tile));
 
@@ -980,6 +1169,7 @@ mod tests {
         let next_state = next_state
             .next_play(&plays)
             .expect("next_play should return Ok")
+            .0
             .expect_left("Ok should contain next_state");
 
         let hand = &next_state.hands[0];
@@ -1001,6 +1191,7 @@ mod tests {
         let next_state = next_state
             .next_play(&plays)
             .expect("next_play should return Ok")
+            .0
             .expect_left("Ok should contain next_state");
 
         assert_eq!(2 + plays.len(), next_state.points[0]);
@@ -1011,15 +1202,52 @@ mod tests {
         let (mut next_state, plays) = set_up_next_play();
         next_state.bag.clear();
 
-        let mut last_state = next_state
+        let (last_state, score_breakdown) = next_state
             .next_play(&plays)
-            .expect("next_play should return Ok")
-            .expect_right("Ok should contain last_state");
+            .expect("next_play should return Ok");
+        let mut last_state = last_state.expect_right("Ok should contain last_state");
 
         assert_eq!(
             2 + plays.len() + LAST_PLAY_BONUS,
             last_state.mut_points()[0]
         );
+        assert_eq!(2, score_breakdown.lines.len());
+        assert!(score_breakdown.last_play);
+        assert_eq!(LAST_PLAY_BONUS, score_breakdown.last_play_bonus);
+    }
+
+    #[test]
+    fn next_play_events_last_play() {
+        let (mut next_state, plays) = set_up_next_play();
+        next_state.bag.clear();
+        let hand = next_state.hands[0].clone();
+
+        let (last_state, score_breakdown) = next_state
+            .next_play(&plays)
+            .expect("next_play should return Ok");
+        let mut last_state = last_state.expect_right("Ok should contain last_state");
+
+        let tiles = plays
+            .iter()
+            .map(|(&index, &coordinate)| (index, coordinate, hand[index]))
+            .collect();
+        let points = last_state.mut_points().clone();
+        assert_eq!(
+            vec![
+                Event::TilesPlayed {
+                    player: 0,
+                    tiles,
+                    ids: None,
+                },
+                Event::PointsAwarded {
+                    player: 0,
+                    score_breakdown,
+                    total: points[0],
+                },
+                Event::GameEnded { points },
+            ],
+            last_state.drain_events()
+        );
     }
 
     #[test]
@@ -1029,6 +1257,7 @@ mod tests {
         let next_state = next_state
             .next_play(&plays)
             .expect("next_play should return Ok")
+            .0
             .expect_left("Ok should contain next_state");
 
         assert_eq!(2 + plays.len() + FULL_MATCH_BONUS, next_state.points[0]);
@@ -1041,6 +1270,7 @@ mod tests {
         let next_state = next_state
             .next_play(&plays)
             .expect("next_play should return Ok")
+            .0
             .expect_left("Ok should contain next_state");
 
         assert_eq!(
@@ -1057,6 +1287,7 @@ mod tests {
         let mut last_state = next_state
             .next_play(&plays)
             .expect("next_play should return Ok")
+            .0
             .expect_right("Ok should contain last_state");
 
         assert_eq!(
@@ -1073,6 +1304,7 @@ mod tests {
         let mut last_state = next_state
             .next_play(&plays)
             .expect("next_play should return Ok")
+            .0
             .expect_right("Ok should contain last_state");
 
         assert_eq!(
@@ -1081,6 +1313,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn next_play_full_match_last_play_config() {
+        let (mut next_state, plays) = set_up_next_play_full_match();
+        next_state.bag.clear();
+        next_state.config =
+            GameConfig::new(None, None, None, Some(1), Some(2)).expect("new should return Ok");
+
+        let mut last_state = next_state
+            .next_play(&plays)
+            .expect("next_play should return Ok")
+            .0
+            .expect_right("Ok should contain last_state");
+
+        assert_eq!(2 + plays.len() + 1 + 2, last_state.mut_points()[0]);
+    }
+
     #[test]
     fn next_play_increment_current_player() {
         let (next_state, plays) = set_up_next_play();
@@ -1088,6 +1336,7 @@ mod tests {
         let next_state = next_state
             .next_play(&plays)
             .expect("next_play should return Ok")
+            .0
             .expect_left("Ok should contain next_state");
 
         assert_eq!(1, next_state.current_player);
@@ -1104,6 +1353,7 @@ mod tests {
         let next_state = next_state
             .next_play(&plays)
             .expect("next_play should return Ok")
+            .0
             .expect_left("Ok should contain next_state");
 
         assert_eq!(0, next_state.current_player);
@@ -1146,6 +1396,7 @@ mod tests {
         let mut last_state = next_state
             .next_play(&plays)
             .expect("next_play should return Ok")
+            .0
             .expect_right("Ok should contain last_state");
 
         assert_eq!(
@@ -1189,6 +1440,160 @@ mod tests {
         }
     }
 
+    #[test]
+    fn next_play_no_legal_tile_plays() {
+        let mut next_state = NextState::empty_next_state();
+        next_state.points.extend([0, 0]);
+        next_state.board.insert((0, 0), (Color::Red, Shape::Circle));
+        next_state.hands.extend([
+            Hand::from_iter([(Color::Red, Shape::Square), (Color::Blue, Shape::Starburst)]),
+            Hand::from_iter([(Color::Blue, Shape::Starburst)]),
+        ]);
+        let plays = [(0, (1, 0))].into_iter().collect();
+
+        let mut last_state = next_state
+            .next_play(&plays)
+            .expect("next_play should return Ok")
+            .0
+            .expect_right("Ok should contain last_state");
+
+        assert_eq!(
+            2 + plays.len() + LAST_PLAY_BONUS,
+            last_state.mut_points()[0]
+        );
+        assert_eq!(0, last_state.mut_points()[1]);
+    }
+
+    #[test]
+    fn preview_play_some_points() {
+        let (next_state, plays) = set_up_next_play();
+        let hand = next_state.hands[0].clone();
+        let board = next_state.board.clone();
+
+        let score_breakdown = next_state
+            .preview_play(&plays)
+            .expect("preview_play should return Ok");
+
+        assert_eq!(2 + plays.len(), score_breakdown.points());
+        assert!(!score_breakdown.last_play);
+        assert_eq!(hand, next_state.hands[0]);
+        assert_eq!(board, next_state.board);
+        assert_eq!(0, next_state.current_player);
+    }
+
+    #[test]
+    fn next_play_tiles_by_value() {
+        let (next_state, plays) = set_up_next_play();
+        let hand = next_state.hands[0].clone();
+        let tile_plays: TilePlays = plays
+            .iter()
+            .map(|(&index, &coordinate)| (coordinate, hand[index]))
+            .collect();
+        let mut board = next_state.board.clone();
+        board.extend(tile_plays.clone());
+
+        let (next_state, _) = next_state
+            .next_play_tiles(&tile_plays)
+            .expect("next_play_tiles should return Ok");
+
+        let next_state = next_state.expect_left("next_play_tiles should return NextState");
+        assert_eq!(board, next_state.board);
+    }
+
+    #[test]
+    fn next_play_tiles_not_held() {
+        let (next_state, _) = set_up_next_play();
+        let tile = next_state.hands[0][0];
+        let tile_plays = btree_map! {
+            (0, 0) => tile,
+            (1, 0) => tile,
+        };
+
+        let (_, errors) = next_state
+            .next_play_tiles(&tile_plays)
+            .expect_err("next_play_tiles should return Err");
+
+        assert_eq!(
+            hash_set! {
+                NextPlayError::TilesNotHeld {
+                    tiles_not_held: btree_map! { (1, 0) => tile },
+                }
+            },
+            errors
+        );
+    }
+
+    #[test]
+    fn preview_play_errors() {
+        let mut rng = rand::thread_rng();
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rng);
+        next_state.random_board(&mut rng);
+        next_state.random_hands(&mut rng);
+
+        let errors = next_state
+            .preview_play(&Plays::new())
+            .expect_err("preview_play should return Err");
+
+        assert_eq!(hash_set! { NextPlayError::EmptyPlays }, errors);
+    }
+
+    #[test]
+    fn legal_plays_single_tiles() {
+        let mut next_state = NextState::empty_next_state();
+        next_state.points.extend([0, 0]);
+        next_state.board.insert((0, 0), (Color::Red, Shape::Circle));
+        next_state.hands.extend([
+            Hand::from_iter([(Color::Red, Shape::Square), (Color::Blue, Shape::Circle)]),
+            Hand::from_iter([(Color::Red, Shape::X)]),
+        ]);
+
+        let legal_plays = next_state.legal_plays();
+
+        assert_eq!(8, legal_plays.len());
+        for (plays, _) in legal_plays {
+            assert_eq!(1, plays.len());
+        }
+    }
+
+    #[test]
+    fn legal_plays_accepted() {
+        let set_up_legal_plays = || {
+            let mut next_state = NextState::empty_next_state();
+            next_state.points.extend([0, 0]);
+            next_state.board.insert((0, 0), (Color::Red, Shape::Circle));
+            next_state.hands.extend([
+                Hand::from_iter([(Color::Red, Shape::Square), (Color::Red, Shape::X)]),
+                Hand::from_iter([(Color::Red, Shape::X)]),
+            ]);
+            next_state
+        };
+
+        let legal_plays = set_up_legal_plays().legal_plays();
+
+        assert_eq!(36, legal_plays.len());
+        for (plays, score_breakdown) in legal_plays {
+            let (_, actual_score_breakdown) = set_up_legal_plays()
+                .next_play(&plays)
+                .expect("next_play should return Ok");
+
+            assert_eq!(score_breakdown.lines, actual_score_breakdown.lines);
+        }
+    }
+
+    #[test]
+    fn legal_plays_no_legal_plays() {
+        let mut next_state = NextState::empty_next_state();
+        next_state.points.extend([0, 0]);
+        next_state.board.insert((0, 0), (Color::Red, Shape::Circle));
+        next_state.hands.extend([
+            Hand::from_iter([(Color::Blue, Shape::Square)]),
+            Hand::from_iter([(Color::Red, Shape::X)]),
+        ]);
+
+        assert!(next_state.legal_plays().is_empty());
+    }
+
     fn set_up_next_play() -> (NextState, Plays) {
         let mut rng = rand::thread_rng();
         // avoid playing full match
@@ -0,0 +1,400 @@
+use crate::{Color, Event, NextState, Shape, Tile};
+use itertools::Itertools;
+use map_macro::hash_set;
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashSet};
+
+/// Describes the reason why [reordering](NextState::reorder_hand) a hand could not be executed.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReorderHandError {
+    /// Attempting [to reorder](NextState::reorder_hand) the hand of a player not in the game.
+    PlayerOutOfBounds {
+        /// The number of players in the game, or the minimum illegal player index.
+        players_len: usize,
+    },
+    /// Attempting [to reorder](NextState::reorder_hand) the hand with a permutation of
+    /// a different length than the hand.
+    WrongLength {
+        /// The number of indexes in the permutation.
+        permutation_len: usize,
+        /// The number of [tiles](Tile) in the player's hand.
+        hand_len: usize,
+    },
+    /// Attempting [to reorder](NextState::reorder_hand) the hand with indexes not in the hand.
+    IndexesOutOfBounds {
+        /// Indexes in the permutation which are greater than or equal to `hand_len`.
+        indexes_out_of_bounds: BTreeSet<usize>,
+    },
+    /// Attempting [to reorder](NextState::reorder_hand) the hand with some index more than once.
+    DuplicateIndexes {
+        /// Indexes which appear in the permutation more than once.
+        duplicate_indexes: BTreeSet<usize>,
+    },
+}
+
+/// How [NextState::sort_hand] orders a hand.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HandSort {
+    /// By [color](Color), and then by [shape](Shape) between equal [colors](Color).
+    #[default]
+    Color,
+    /// By [shape](Shape), and then by [color](Color) between equal [shapes](Shape).
+    Shape,
+    /// By the largest group of [tiles](Tile) which share a [color](Color) or
+    /// a [shape](Shape), repeated for the [tiles](Tile) left over, where each group is
+    /// ordered by the other property. Groups of [colors](Color) come before groups of
+    /// [shapes](Shape) of the same size.
+    MatchingGroups,
+}
+
+impl NextState {
+    /// Checks whether `permutation` is a permutation of the indexes of the player's hand and
+    /// returns all found errors. Otherwise, moves the [tile](Tile) at index `permutation[i]`
+    /// to index `i` of the hand. Reordering is not a turn, so any player may reorder their hand
+    /// at any time without advancing to the next player, and every later [play](crate::Plays)
+    /// or [exchange](crate::Exchanges) addresses the hand by its new order.
+    ///
+    /// # Arguments
+    ///
+    /// * `player`: The index of the player whose hand is reordered.
+    /// * `permutation`: The index in the hand before reordering of each [tile](Tile) in
+    /// the new order.
+    ///
+    /// # Errors
+    ///
+    /// * [ReorderHandError::PlayerOutOfBounds] Attempting [to reorder](NextState::reorder_hand)
+    /// the hand of a player not in the game.
+    /// * [ReorderHandError::WrongLength] Attempting [to reorder](NextState::reorder_hand)
+    /// the hand with a permutation of a different length than the hand.
+    /// * [ReorderHandError::IndexesOutOfBounds] Attempting
+    /// [to reorder](NextState::reorder_hand) the hand with indexes not in the hand.
+    /// * [ReorderHandError::DuplicateIndexes] Attempting [to reorder](NextState::reorder_hand)
+    /// the hand with some index more than once.
+    pub fn reorder_hand(
+        &mut self,
+        player: usize,
+        permutation: &[usize],
+    ) -> Result<(), HashSet<ReorderHandError>> {
+        self.check_permutation(player, permutation)?;
+
+        let hand = &mut self.hands[player];
+        *hand = permutation.iter().map(|&index| hand[index]).collect();
+        if let Some(tile_ids) = &mut self.tile_ids {
+            tile_ids.reorder_hand(player, permutation);
+        }
+
+        self.events.push(Event::HandReordered {
+            player,
+            permutation: permutation.to_vec(),
+        });
+        Ok(())
+    }
+
+    /// [Reorders](NextState::reorder_hand) the player's hand by `hand_sort`. Sorting is stable,
+    /// so copies of the same [tile](Tile) keep their order.
+    ///
+    /// # Arguments
+    ///
+    /// * `player`: The index of the player whose hand is sorted.
+    /// * `hand_sort`: How the hand is ordered.
+    ///
+    /// # Errors
+    ///
+    /// * [ReorderHandError::PlayerOutOfBounds] Attempting [to reorder](NextState::reorder_hand)
+    /// the hand of a player not in the game.
+    pub fn sort_hand(
+        &mut self,
+        player: usize,
+        hand_sort: HandSort,
+    ) -> Result<(), HashSet<ReorderHandError>> {
+        let Some(hand) = self.hands.get(player) else {
+            return Err(hash_set! { ReorderHandError::PlayerOutOfBounds {
+                players_len: self.hands.len(),
+            }});
+        };
+
+        let permutation = match hand_sort {
+            HandSort::Color => (0..hand.len())
+                .sorted_by_key(|&index| {
+                    let (color, shape) = hand[index];
+                    (color as usize, shape as usize)
+                })
+                .collect_vec(),
+            HandSort::Shape => (0..hand.len())
+                .sorted_by_key(|&index| {
+                    let (color, shape) = hand[index];
+                    (shape as usize, color as usize)
+                })
+                .collect_vec(),
+            HandSort::MatchingGroups => NextState::sort_by_matching_groups(hand),
+        };
+        self.reorder_hand(player, &permutation)
+    }
+
+    /// Checks whether `permutation` is a permutation of the indexes of the player's hand and
+    /// returns all found errors.
+    ///
+    /// # Arguments
+    ///
+    /// * `player`: The index of the player whose hand is reordered.
+    /// * `permutation`: The index in the hand before reordering of each [tile](Tile) in
+    /// the new order.
+    ///
+    /// # Errors
+    ///
+    /// Same as [NextState::reorder_hand].
+    fn check_permutation(
+        &self,
+        player: usize,
+        permutation: &[usize],
+    ) -> Result<(), HashSet<ReorderHandError>> {
+        let mut errors = HashSet::with_capacity(3);
+        let Some(hand) = self.hands.get(player) else {
+            errors.insert(ReorderHandError::PlayerOutOfBounds {
+                players_len: self.hands.len(),
+            });
+            return Err(errors);
+        };
+
+        let hand_len = hand.len();
+        let permutation_len = permutation.len();
+        if permutation_len != hand_len {
+            errors.insert(ReorderHandError::WrongLength {
+                permutation_len,
+                hand_len,
+            });
+        }
+
+        let indexes_out_of_bounds: BTreeSet<usize> = permutation
+            .iter()
+            .copied()
+            .filter(|&index| index >= hand_len)
+            .collect();
+        if !indexes_out_of_bounds.is_empty() {
+            errors.insert(ReorderHandError::IndexesOutOfBounds {
+                indexes_out_of_bounds,
+            });
+        }
+
+        let duplicate_indexes: BTreeSet<usize> = permutation.iter().copied().duplicates().collect();
+        if !duplicate_indexes.is_empty() {
+            errors.insert(ReorderHandError::DuplicateIndexes { duplicate_indexes });
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(())
+    }
+
+    /// Repeatedly takes the largest group of [tiles](Tile) left in `hand` which share
+    /// a [color](Color) or a [shape](Shape) and orders each group by the other property.
+    ///
+    /// # Arguments
+    ///
+    /// * `hand`: A vector of [tiles](Tile) to be sorted.
+    ///
+    /// # Returns
+    ///
+    /// The index in `hand` of each [tile](Tile) in sorted order.
+    fn sort_by_matching_groups(hand: &[Tile]) -> Vec<usize> {
+        let mut left = (0..hand.len()).collect_vec();
+        let mut permutation = Vec::with_capacity(hand.len());
+        while !left.is_empty() {
+            let color_groups = Color::colors().into_iter().map(|color| {
+                left.iter()
+                    .copied()
+                    .filter(|&index| hand[index].0 == color)
+                    .sorted_by_key(|&index| hand[index].1 as usize)
+                    .collect_vec()
+            });
+            let shape_groups = Shape::shapes().into_iter().map(|shape| {
+                left.iter()
+                    .copied()
+                    .filter(|&index| hand[index].1 == shape)
+                    .sorted_by_key(|&index| hand[index].0 as usize)
+                    .collect_vec()
+            });
+            // min_by_key keeps the first of equally large groups
+            let group = color_groups
+                .chain(shape_groups)
+                .min_by_key(|group| Reverse(group.len()))
+                .unwrap_or_default();
+
+            left.retain(|index| !group.contains(index));
+            permutation.extend(group);
+        }
+        permutation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color, Hand, Plays, Shape};
+    use map_macro::btree_set;
+
+    const RED_CIRCLE: Tile = (Color::Red, Shape::Circle);
+    const RED_SQUARE: Tile = (Color::Red, Shape::Square);
+    const BLUE_CIRCLE: Tile = (Color::Blue, Shape::Circle);
+    const BLUE_X: Tile = (Color::Blue, Shape::X);
+    const GREEN_CIRCLE: Tile = (Color::Green, Shape::Circle);
+
+    fn set_up_reorder_hand() -> NextState {
+        let mut next_state = NextState::empty_next_state();
+        next_state.points.extend([0, 0]);
+        next_state.board.insert((0, 0), (Color::Red, Shape::X));
+        next_state.hands.extend([
+            Hand::from_iter([BLUE_X, RED_CIRCLE, GREEN_CIRCLE, RED_SQUARE, BLUE_CIRCLE]),
+            Hand::from_iter([RED_CIRCLE]),
+        ]);
+        next_state
+    }
+
+    #[test]
+    fn player_out_of_bounds() {
+        let mut next_state = set_up_reorder_hand();
+
+        let errors = next_state
+            .reorder_hand(2, &[0])
+            .expect_err("reorder_hand should return Err");
+
+        assert_eq!(
+            hash_set! { ReorderHandError::PlayerOutOfBounds { players_len: 2 } },
+            errors
+        );
+    }
+
+    #[test]
+    fn not_permutation() {
+        let mut next_state = set_up_reorder_hand();
+
+        let errors = next_state
+            .reorder_hand(0, &[1, 1, 7])
+            .expect_err("reorder_hand should return Err");
+
+        assert_eq!(
+            hash_set! {
+                ReorderHandError::WrongLength {
+                    permutation_len: 3,
+                    hand_len: 5,
+                },
+                ReorderHandError::IndexesOutOfBounds {
+                    indexes_out_of_bounds: btree_set! { 7 },
+                },
+                ReorderHandError::DuplicateIndexes {
+                    duplicate_indexes: btree_set! { 1 },
+                },
+            },
+            errors
+        );
+        assert_eq!(set_up_reorder_hand().hands, next_state.hands);
+    }
+
+    #[test]
+    fn reorder_hand_not_a_turn() {
+        let mut next_state = set_up_reorder_hand();
+
+        next_state
+            .reorder_hand(1, &[0])
+            .expect("reorder_hand should return Ok");
+        next_state
+            .reorder_hand(0, &[4, 3, 2, 1, 0])
+            .expect("reorder_hand should return Ok");
+
+        assert_eq!(
+            Hand::from_iter([BLUE_CIRCLE, RED_SQUARE, GREEN_CIRCLE, RED_CIRCLE, BLUE_X]),
+            next_state.hands[0]
+        );
+        assert_eq!(0, next_state.current_player);
+        assert_eq!(0, next_state.passes);
+        assert_eq!(
+            vec![
+                Event::HandReordered {
+                    player: 1,
+                    permutation: vec![0],
+                },
+                Event::HandReordered {
+                    player: 0,
+                    permutation: vec![4, 3, 2, 1, 0],
+                },
+            ],
+            next_state.drain_events()
+        );
+    }
+
+    #[test]
+    fn reorder_hand_plays_new_order() {
+        let mut next_state = set_up_reorder_hand();
+        next_state
+            .reorder_hand(0, &[3, 0, 1, 2, 4])
+            .expect("reorder_hand should return Ok");
+        let plays: Plays = [(0, (1, 0))].into_iter().collect();
+
+        let (next_state, _) = next_state
+            .next_play(&plays)
+            .expect("next_play should return Ok");
+
+        let mut next_state = next_state.expect_left("next_play should return NextState");
+        assert_eq!(RED_SQUARE, next_state.mut_board()[&(1, 0)]);
+    }
+
+    #[test]
+    fn sort_hand_by_color() {
+        let mut next_state = set_up_reorder_hand();
+
+        next_state
+            .sort_hand(0, HandSort::Color)
+            .expect("sort_hand should return Ok");
+
+        assert_eq!(
+            Hand::from_iter([RED_CIRCLE, RED_SQUARE, GREEN_CIRCLE, BLUE_CIRCLE, BLUE_X]),
+            next_state.hands[0]
+        );
+    }
+
+    #[test]
+    fn sort_hand_by_shape() {
+        let mut next_state = set_up_reorder_hand();
+
+        next_state
+            .sort_hand(0, HandSort::Shape)
+            .expect("sort_hand should return Ok");
+
+        assert_eq!(
+            Hand::from_iter([RED_CIRCLE, GREEN_CIRCLE, BLUE_CIRCLE, RED_SQUARE, BLUE_X]),
+            next_state.hands[0]
+        );
+    }
+
+    #[test]
+    fn sort_hand_by_matching_groups() {
+        let mut next_state = set_up_reorder_hand();
+
+        next_state
+            .sort_hand(0, HandSort::MatchingGroups)
+            .expect("sort_hand should return Ok");
+
+        assert_eq!(
+            Hand::from_iter([RED_CIRCLE, GREEN_CIRCLE, BLUE_CIRCLE, RED_SQUARE, BLUE_X]),
+            next_state.hands[0]
+        );
+    }
+
+    #[test]
+    fn sort_hand_player_out_of_bounds() {
+        let mut next_state = set_up_reorder_hand();
+
+        let errors = next_state
+            .sort_hand(2, HandSort::Shape)
+            .expect_err("sort_hand should return Err");
+
+        assert_eq!(
+            hash_set! { ReorderHandError::PlayerOutOfBounds { players_len: 2 } },
+            errors
+        );
+    }
+}
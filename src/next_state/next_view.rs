@@ -1,13 +1,22 @@
-use crate::{Board, Hand, HandLens, NextState, Points};
+use crate::{
+    legal_plays, Bag, Board, GameConfig, GameRng, Hand, HandLens, Hands, NextPlayError, NextState,
+    Plays, Points, ScoreBreakdown, Tile, TileId,
+};
 use smallvec::SmallVec;
+use std::collections::HashSet;
 
 /// Immutably borrows properties from [NextState].
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct NextView<'a> {
     /// This is the number of [tiles](crate::Tile) that haven't been removed yet.
     pub bag_len: usize,
     /// This is a map of [coordinates](crate::Coordinate) to [tiles](crate::Tile) that
     /// have been played.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialization::board::serialize")
+    )]
     pub board: &'a Board,
     /// A vector of points for each player.
     pub points: &'a Points,
@@ -15,6 +24,70 @@ pub struct NextView<'a> {
     pub hand_lens: HandLens,
     /// The index of the player whose turn it is.
     pub current_player: usize,
+    /// The number of players who have [passed](NextState::next_pass) in a row.
+    pub passes: usize,
+    /// The number of copies of each [tile](crate::Tile) the game started with.
+    pub unique_tile_copied_count: usize,
+    /// Limits and bonuses of the game.
+    pub config: &'a GameConfig,
+    /// The [identities](TileId) of the viewer's own hand in the same order as the hand,
+    /// or [None] if the view is [for every player](NextState::next_view) or
+    /// [tile ids](crate::TileIds) are not tracked.
+    pub hand_ids: Option<&'a [TileId]>,
+}
+
+impl NextView<'_> {
+    /// Checks whether [next_play](NextState::next_play) would accept `plays` from `hand` without
+    /// changing anything, so any player can score [plays](Plays) from their own hand.
+    ///
+    /// # Arguments
+    ///
+    /// * `hand`: A vector of [tiles](Tile) to be played.
+    /// * `plays`: A bimap of indexes of [tiles](Tile) to be played to
+    /// [coordinates](crate::Coordinate) on the board.
+    ///
+    /// # Errors
+    ///
+    /// Same as [NextState::preview_play] where `hand` is the current player's hand.
+    ///
+    /// # Returns
+    ///
+    /// The [score breakdown](ScoreBreakdown) of [plays](Plays).
+    pub fn preview_play(
+        &self,
+        hand: &[Tile],
+        plays: &Plays,
+    ) -> Result<ScoreBreakdown, HashSet<NextPlayError>> {
+        NextState::check_plays(self.board, hand, self.config, plays)
+    }
+
+    /// Finds every [play](Plays) from `hand` that [next_play](NextState::next_play) would accept
+    /// along with the [score breakdown](ScoreBreakdown) of it.
+    /// When the hand holds copies of a [tile](Tile), every [play](Plays) of that [tile](Tile) is
+    /// given once for the index of each copy.
+    ///
+    /// # Arguments
+    ///
+    /// * `hand`: A vector of [tiles](Tile) to be played.
+    ///
+    /// # See Also
+    ///
+    /// * [legal_plays](crate::legal_plays)
+    /// * [NextState::legal_plays]
+    ///
+    /// # Returns
+    ///
+    /// A vector of legal [plays](Plays) and the [score breakdown](ScoreBreakdown) of
+    /// each [play](Plays).
+    pub fn legal_plays(&self, hand: &[Tile]) -> Vec<(Plays, ScoreBreakdown)> {
+        legal_plays(self.board, hand, self.config.coordinate_limit())
+            .into_iter()
+            .filter_map(|plays| {
+                let score_breakdown = self.preview_play(hand, &plays).ok()?;
+                Some((plays, score_breakdown))
+            })
+            .collect()
+    }
 }
 
 impl<'a> NextState {
@@ -28,6 +101,57 @@ impl<'a> NextState {
             points: &self.points,
             hand_lens: self.hands.iter().map(SmallVec::len).collect(),
             current_player: self.current_player,
+            passes: self.passes,
+            unique_tile_copied_count: self.unique_tile_copied_count,
+            config: &self.config,
+            hand_ids: None,
+        }
+    }
+
+    /// Same as [next_view](NextState::next_view), but with the [identities](TileId) of
+    /// the requesting player's own hand, which only that player should see.
+    ///
+    /// # Arguments
+    ///
+    /// * `player`: The index of the player who views the game.
+    pub fn next_view_for(&'a self, player: usize) -> NextView<'a> {
+        NextView {
+            hand_ids: self
+                .tile_ids
+                .as_ref()
+                .and_then(|tile_ids| tile_ids.hand(player)),
+            ..self.next_view()
+        }
+    }
+
+    /// A new [NextState] struct which agrees with `next_view` and fills in the properties
+    /// hidden from it, such as when sampling what the current player cannot see.
+    ///
+    /// # Arguments
+    ///
+    /// * `next_view`: The part of the game visible to every player.
+    /// * `bag`: This is a bag of all the [tiles](Tile) that haven't been removed yet.
+    /// * `hands`: A vector of hands for each player, where each hand is
+    /// a vector of [tiles](Tile).
+    /// * `rng`: Shuffles [exchanged](NextState::next_exchange) [tiles](Tile) back into the bag.
+    pub(crate) fn from_view(
+        next_view: &NextView<'_>,
+        bag: Bag,
+        hands: Hands,
+        rng: GameRng,
+    ) -> NextState {
+        NextState {
+            bag,
+            board: next_view.board.clone(),
+            points: next_view.points.clone(),
+            hands,
+            current_player: next_view.current_player,
+            passes: next_view.passes,
+            rng,
+            unique_tile_copied_count: next_view.unique_tile_copied_count,
+            config: *next_view.config,
+            events: Vec::new(),
+            tile_ids: None,
         }
     }
 
@@ -40,6 +164,7 @@ impl<'a> NextState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Color, Shape};
 
     #[test]
     fn next_view() {
@@ -60,6 +185,73 @@ mod tests {
         assert_eq!(next_state.points, *next_view.points);
         assert_eq!(hands, next_view.hand_lens);
         assert_eq!(next_state.current_player, next_view.current_player);
+        assert_eq!(next_state.passes, next_view.passes);
+        assert_eq!(
+            next_state.unique_tile_copied_count,
+            next_view.unique_tile_copied_count
+        );
+        assert_eq!(next_state.config, *next_view.config);
+    }
+
+    #[test]
+    fn from_view() {
+        let mut rng = rand::thread_rng();
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rng);
+        next_state.random_bag(&mut rng);
+        next_state.random_board(&mut rng);
+        next_state.random_hands(&mut rng);
+        next_state.random_points(&mut rng);
+        next_state.random_current_player(&mut rng);
+        next_state.passes = 1;
+
+        let from_view = NextState::from_view(
+            &next_state.next_view(),
+            next_state.bag.clone(),
+            next_state.hands.clone(),
+            next_state.rng.clone(),
+        );
+
+        assert_eq!(format!("{next_state:?}"), format!("{from_view:?}"));
+    }
+
+    #[test]
+    fn preview_play_other_hand() {
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rand::thread_rng());
+        next_state
+            .mut_board()
+            .insert((0, 0), (Color::Red, Shape::Circle));
+        next_state.mut_hands()[0].push((Color::Blue, Shape::Square));
+        let hand = [(Color::Red, Shape::Square)];
+        let plays = [(0, (1, 0))].into_iter().collect();
+
+        let next_view = next_state.next_view();
+
+        assert_eq!(
+            Ok(3),
+            next_view
+                .preview_play(&hand, &plays)
+                .map(|score_breakdown| score_breakdown.points())
+        );
+        assert!(next_state.preview_play(&plays).is_err());
+    }
+
+    #[test]
+    fn legal_plays_other_hand() {
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rand::thread_rng());
+        next_state
+            .mut_board()
+            .insert((0, 0), (Color::Red, Shape::Circle));
+        let hand = [(Color::Blue, Shape::Square), (Color::Red, Shape::Square)];
+
+        let legal_plays = next_state.next_view().legal_plays(&hand);
+
+        assert!(next_state.legal_plays().is_empty());
+        next_state.mut_hands()[0].extend(hand);
+        assert!(!legal_plays.is_empty());
+        assert_eq!(next_state.legal_plays(), legal_plays);
     }
 
     #[test]
@@ -1,8 +1,9 @@
 use crate::{
     random_bag, random_board, random_current_player, random_hands, random_players, random_points,
-    Bag, Board, Color, Hands, NextState, Points, Shape, PLAYER_CAPACITY, TILES_LEN,
+    Bag, Board, Color, GameConfig, GameRng, Hands, NextState, Points, Shape,
+    DEFAULT_UNIQUE_TILE_COPIED_COUNT, PLAYER_CAPACITY, TILES_LEN,
 };
-use rand::Rng;
+use rand::{Rng, SeedableRng};
 
 impl NextState {
     /// Generates an empty [NextState] with no players.
@@ -15,6 +16,12 @@ impl NextState {
     /// * `points`: An empty points vector.
     /// * `hands`: An empty hands vector.
     /// * `current_player`: `0`.
+    /// * `passes`: `0`.
+    /// * `rng`: A [game rng](GameRng) seeded from entropy.
+    /// * `unique_tile_copied_count`: [DEFAULT_UNIQUE_TILE_COPIED_COUNT].
+    /// * `config`: The [default config](GameConfig::default).
+    /// * `events`: An empty events vector.
+    /// * `tile_ids`: [None].
     pub fn empty_next_state() -> NextState {
         // capacity hardcoded to highest expected demand during test cases
         NextState {
@@ -23,6 +30,12 @@ impl NextState {
             points: Points::with_capacity(PLAYER_CAPACITY),
             hands: Hands::with_capacity(PLAYER_CAPACITY),
             current_player: 0,
+            passes: 0,
+            rng: GameRng::from_entropy(),
+            unique_tile_copied_count: DEFAULT_UNIQUE_TILE_COPIED_COUNT,
+            config: GameConfig::default(),
+            events: Vec::new(),
+            tile_ids: None,
         }
     }
 
@@ -51,6 +64,26 @@ impl NextState {
         &mut self.current_player
     }
 
+    /// A mutable reference to `self.passes`.
+    pub fn mut_passes(&mut self) -> &mut usize {
+        &mut self.passes
+    }
+
+    /// A mutable reference to `self.rng`.
+    pub fn mut_rng(&mut self) -> &mut GameRng {
+        &mut self.rng
+    }
+
+    /// A mutable reference to `self.unique_tile_copied_count`.
+    pub fn mut_unique_tile_copied_count(&mut self) -> &mut usize {
+        &mut self.unique_tile_copied_count
+    }
+
+    /// A mutable reference to `self.config`.
+    pub fn mut_config(&mut self) -> &mut GameConfig {
+        &mut self.config
+    }
+
     /// It inserts a random, small, non-zero number of empty hands into hands and
     /// `0`s into points.
     ///
@@ -0,0 +1,596 @@
+use crate::{Board, Color, Coordinate, Exchanges, Plays, Shape, Tile, TileExchanges, TilePlays};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// Wraps a [tile](Tile), a [coordinate](Coordinate), [plays](Plays), [exchanges](Exchanges),
+/// or a board in a compact, human-readable text notation with [Display] and [FromStr].
+/// The wrapper is needed since those types are tuples or collections defined outside of
+/// this crate.
+///
+/// * [Tile]: A [color](Color) code followed by a [shape](Shape) code such as `RCi`.
+/// * [Coordinate]: Both components separated by a comma such as `-1,0`.
+/// * [Plays]: Indexes in the hand and [coordinates](Coordinate) joined by `@` and
+/// separated by whitespace such as `0@0,0 2@1,0`.
+/// * [Exchanges]: Indexes in the hand separated by whitespace such as `0 2`.
+/// * [Board]: [Tiles](Tile) and [coordinates](Coordinate) joined by `@` and separated by
+/// whitespace such as `RCi@0,0 RSq@1,0`, which also describes [tiles](Tile) being played.
+/// * [TilePlays]: The same as [Board].
+/// * [TileExchanges]: [Tiles](Tile) separated by whitespace such as `RCi RCi BX`.
+/// * [`Vec<usize>`]: Indexes in order separated by whitespace such as `2 0 1`, which describes
+/// a [reordered](crate::NextState::reorder_hand) hand.
+///
+/// # See Also
+///
+/// * [NotationError]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Notation<T>(pub T);
+
+/// Describes the reason why some [notation](Notation) could not be parsed. Each error
+/// contains the bad `token` and its `position`, which is the byte offset of the `token` in
+/// the parsed text.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NotationError {
+    /// Attempting to parse a [color](Color) code which is not `R`, `O`, `Y`, `G`, `B`, or `P`.
+    Color {
+        /// The bad [color](Color) code.
+        token: String,
+        /// The byte offset of `token`.
+        position: usize,
+    },
+    /// Attempting to parse a [shape](Shape) code which is not `Ci`, `Cl`, `Di`, `Sq`, `St`,
+    /// or `X`.
+    Shape {
+        /// The bad [shape](Shape) code.
+        token: String,
+        /// The byte offset of `token`.
+        position: usize,
+    },
+    /// Attempting to parse a [coordinate](Coordinate) which is not two integers separated by
+    /// a comma.
+    Coordinate {
+        /// The bad [coordinate](Coordinate).
+        token: String,
+        /// The byte offset of `token`.
+        position: usize,
+    },
+    /// Attempting to parse an index which is not a non-negative integer.
+    Index {
+        /// The bad index.
+        token: String,
+        /// The byte offset of `token`.
+        position: usize,
+    },
+    /// Attempting to parse a [play](Plays) without `@` between the index or [tile](Tile) and
+    /// the [coordinate](Coordinate).
+    Separator {
+        /// The [play](Plays) without `@`.
+        token: String,
+        /// The byte offset of `token`.
+        position: usize,
+    },
+    /// Attempting to parse an index or a [coordinate](Coordinate) which was already parsed.
+    Duplicate {
+        /// The repeated index or [coordinate](Coordinate).
+        token: String,
+        /// The byte offset of `token`.
+        position: usize,
+    },
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Color::Red => "R",
+            Color::Orange => "O",
+            Color::Yellow => "Y",
+            Color::Green => "G",
+            Color::Blue => "B",
+            Color::Purple => "P",
+        })
+    }
+}
+
+impl FromStr for Color {
+    type Err = NotationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_color(s, 0)
+    }
+}
+
+impl Display for Shape {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Shape::Circle => "Ci",
+            Shape::Clover => "Cl",
+            Shape::Diamond => "Di",
+            Shape::Square => "Sq",
+            Shape::Starburst => "St",
+            Shape::X => "X",
+        })
+    }
+}
+
+impl FromStr for Shape {
+    type Err = NotationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_shape(s, 0)
+    }
+}
+
+impl Display for Notation<Tile> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Notation((color, shape)) = self;
+        write!(f, "{color}{shape}")
+    }
+}
+
+impl FromStr for Notation<Tile> {
+    type Err = NotationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_tile(s, 0).map(Notation)
+    }
+}
+
+impl Display for Notation<Coordinate> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Notation((x, y)) = self;
+        write!(f, "{x},{y}")
+    }
+}
+
+impl FromStr for Notation<Coordinate> {
+    type Err = NotationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_coordinate(s, 0).map(Notation)
+    }
+}
+
+impl Display for Notation<Plays> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        for (index, &coordinate) in &self.0 {
+            write!(f, "{separator}{index}@{}", Notation(coordinate))?;
+            separator = " ";
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Notation<Plays> {
+    type Err = NotationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut plays = Plays::new();
+        for (position, token) in tokens(s) {
+            let (index, coordinate) = split_play(token, position)?;
+            let index = parse_index(index, position)?;
+            let coordinate_position = position + token.len() - coordinate.len();
+            let coordinate = parse_coordinate(coordinate, coordinate_position)?;
+            plays
+                .insert_no_overwrite(index, coordinate)
+                .map_err(|_| duplicate(token, position))?;
+        }
+        Ok(Notation(plays))
+    }
+}
+
+impl Display for Notation<Exchanges> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        for index in &self.0 {
+            write!(f, "{separator}{index}")?;
+            separator = " ";
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Notation<Exchanges> {
+    type Err = NotationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut exchanges = Exchanges::new();
+        for (position, token) in tokens(s) {
+            if !exchanges.insert(parse_index(token, position)?) {
+                return Err(duplicate(token, position));
+            }
+        }
+        Ok(Notation(exchanges))
+    }
+}
+
+impl Display for Notation<Board> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        // ordered by coordinate so that the same board is always written the same way
+        for (&coordinate, &tile) in self.0.iter().collect::<BTreeMap<_, _>>() {
+            write!(f, "{separator}{}@{}", Notation(tile), Notation(coordinate))?;
+            separator = " ";
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Notation<Board> {
+    type Err = NotationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut board = Board::new();
+        for (position, token) in tokens(s) {
+            let (tile, coordinate) = split_play(token, position)?;
+            let tile = parse_tile(tile, position)?;
+            let coordinate_position = position + token.len() - coordinate.len();
+            let coordinate = parse_coordinate(coordinate, coordinate_position)?;
+            if board.insert(coordinate, tile).is_some() {
+                return Err(duplicate(token, position));
+            }
+        }
+        Ok(Notation(board))
+    }
+}
+
+impl Display for Notation<TilePlays> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        for (&coordinate, &tile) in &self.0 {
+            write!(f, "{separator}{}@{}", Notation(tile), Notation(coordinate))?;
+            separator = " ";
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Notation<TilePlays> {
+    type Err = NotationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Notation(board) = s.parse::<Notation<Board>>()?;
+        Ok(Notation(board.into_iter().collect()))
+    }
+}
+
+impl Display for Notation<TileExchanges> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        for &tile in &self.0 {
+            write!(f, "{separator}{}", Notation(tile))?;
+            separator = " ";
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Notation<TileExchanges> {
+    type Err = NotationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        tokens(s)
+            .map(|(position, token)| parse_tile(token, position))
+            .collect::<Result<_, _>>()
+            .map(Notation)
+    }
+}
+
+impl Display for Notation<Vec<usize>> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        for index in &self.0 {
+            write!(f, "{separator}{index}")?;
+            separator = " ";
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Notation<Vec<usize>> {
+    type Err = NotationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        tokens(s)
+            .map(|(position, token)| parse_index(token, position))
+            .collect::<Result<_, _>>()
+            .map(Notation)
+    }
+}
+
+/// Splits `s` by whitespace into tokens and the byte offset of each token.
+fn tokens(s: &str) -> impl Iterator<Item = (usize, &str)> {
+    s.split_whitespace()
+        .map(move |token| (token.as_ptr() as usize - s.as_ptr() as usize, token))
+}
+
+/// Splits a [play](Plays) `token` at `@`.
+fn split_play(token: &str, position: usize) -> Result<(&str, &str), NotationError> {
+    token
+        .split_once('@')
+        .ok_or_else(|| NotationError::Separator {
+            token: token.to_owned(),
+            position,
+        })
+}
+
+fn parse_color(token: &str, position: usize) -> Result<Color, NotationError> {
+    match token {
+        "R" => Ok(Color::Red),
+        "O" => Ok(Color::Orange),
+        "Y" => Ok(Color::Yellow),
+        "G" => Ok(Color::Green),
+        "B" => Ok(Color::Blue),
+        "P" => Ok(Color::Purple),
+        _ => Err(NotationError::Color {
+            token: token.to_owned(),
+            position,
+        }),
+    }
+}
+
+fn parse_shape(token: &str, position: usize) -> Result<Shape, NotationError> {
+    match token {
+        "Ci" => Ok(Shape::Circle),
+        "Cl" => Ok(Shape::Clover),
+        "Di" => Ok(Shape::Diamond),
+        "Sq" => Ok(Shape::Square),
+        "St" => Ok(Shape::Starburst),
+        "X" => Ok(Shape::X),
+        _ => Err(NotationError::Shape {
+            token: token.to_owned(),
+            position,
+        }),
+    }
+}
+
+fn parse_tile(token: &str, position: usize) -> Result<Tile, NotationError> {
+    // every color code is a single ascii character
+    let split = token
+        .char_indices()
+        .nth(1)
+        .map_or(token.len(), |(index, _)| index);
+    let (color, shape) = token.split_at(split);
+    Ok((
+        parse_color(color, position)?,
+        parse_shape(shape, position + split)?,
+    ))
+}
+
+fn parse_coordinate(token: &str, position: usize) -> Result<Coordinate, NotationError> {
+    let error = || NotationError::Coordinate {
+        token: token.to_owned(),
+        position,
+    };
+    let (x, y) = token.split_once(',').ok_or_else(error)?;
+    Ok((
+        x.parse().map_err(|_| error())?,
+        y.parse().map_err(|_| error())?,
+    ))
+}
+
+fn parse_index(token: &str, position: usize) -> Result<usize, NotationError> {
+    token.parse().map_err(|_| NotationError::Index {
+        token: token.to_owned(),
+        position,
+    })
+}
+
+fn duplicate(token: &str, position: usize) -> NotationError {
+    NotationError::Duplicate {
+        token: token.to_owned(),
+        position,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tiles;
+    use map_macro::{btree_map, btree_set, hash_map};
+
+    #[test]
+    fn tile_round_trip() {
+        for tile in tiles() {
+            let text = Notation(tile).to_string();
+
+            assert_eq!(Ok(Notation(tile)), text.parse());
+        }
+    }
+
+    #[test]
+    fn tile_display() {
+        assert_eq!("RCi", Notation((Color::Red, Shape::Circle)).to_string());
+        assert_eq!("PX", Notation((Color::Purple, Shape::X)).to_string());
+    }
+
+    #[test]
+    fn tile_bad_color() {
+        assert_eq!(
+            Err(NotationError::Color {
+                token: "Z".to_owned(),
+                position: 0,
+            }),
+            "ZCi".parse::<Notation<Tile>>()
+        );
+    }
+
+    #[test]
+    fn tile_bad_shape() {
+        assert_eq!(
+            Err(NotationError::Shape {
+                token: "Zz".to_owned(),
+                position: 1,
+            }),
+            "RZz".parse::<Notation<Tile>>()
+        );
+    }
+
+    #[test]
+    fn coordinate_round_trip() {
+        assert_eq!("-1,20", Notation((-1, 20)).to_string());
+        assert_eq!(Ok(Notation((-1, 20))), "-1,20".parse());
+    }
+
+    #[test]
+    fn coordinate_bad_coordinate() {
+        for token in ["", "1", "1,", ",1", "1;1", "a,1"] {
+            assert_eq!(
+                Err(NotationError::Coordinate {
+                    token: token.to_owned(),
+                    position: 0,
+                }),
+                token.parse::<Notation<Coordinate>>()
+            );
+        }
+    }
+
+    #[test]
+    fn plays_round_trip() {
+        let plays: Plays = [(0, (0, 0)), (2, (1, 0))].into_iter().collect();
+
+        assert_eq!("0@0,0 2@1,0", Notation(plays.clone()).to_string());
+        assert_eq!(Ok(Notation(plays)), " 2@1,0\t0@0,0 ".parse());
+        assert_eq!(Ok(Notation(Plays::new())), "".parse());
+    }
+
+    #[test]
+    fn plays_bad_tokens() {
+        assert_eq!(
+            Err(NotationError::Separator {
+                token: "2".to_owned(),
+                position: 6,
+            }),
+            "0@0,0 2".parse::<Notation<Plays>>()
+        );
+        assert_eq!(
+            Err(NotationError::Index {
+                token: "x".to_owned(),
+                position: 6,
+            }),
+            "0@0,0 x@1,0".parse::<Notation<Plays>>()
+        );
+        assert_eq!(
+            Err(NotationError::Coordinate {
+                token: "1,".to_owned(),
+                position: 8,
+            }),
+            "0@0,0 2@1,".parse::<Notation<Plays>>()
+        );
+        assert_eq!(
+            Err(NotationError::Duplicate {
+                token: "2@0,0".to_owned(),
+                position: 6,
+            }),
+            "0@0,0 2@0,0".parse::<Notation<Plays>>()
+        );
+    }
+
+    #[test]
+    fn exchanges_round_trip() {
+        let exchanges = btree_set! { 0, 3, 5 };
+
+        assert_eq!("0 3 5", Notation(exchanges.clone()).to_string());
+        assert_eq!(Ok(Notation(exchanges)), "5 0 3".parse());
+    }
+
+    #[test]
+    fn exchanges_bad_tokens() {
+        assert_eq!(
+            Err(NotationError::Index {
+                token: "-1".to_owned(),
+                position: 2,
+            }),
+            "0 -1".parse::<Notation<Exchanges>>()
+        );
+        assert_eq!(
+            Err(NotationError::Duplicate {
+                token: "0".to_owned(),
+                position: 4,
+            }),
+            "0 1 0".parse::<Notation<Exchanges>>()
+        );
+    }
+
+    #[test]
+    fn board_round_trip() {
+        let board = hash_map! {
+            (1, 0) => (Color::Red, Shape::Square),
+            (0, 0) => (Color::Red, Shape::Circle),
+        };
+
+        assert_eq!("RCi@0,0 RSq@1,0", Notation(board.clone()).to_string());
+        assert_eq!(Ok(Notation(board)), "RSq@1,0 RCi@0,0".parse());
+    }
+
+    #[test]
+    fn board_bad_tokens() {
+        assert_eq!(
+            Err(NotationError::Shape {
+                token: "Sx".to_owned(),
+                position: 9,
+            }),
+            "RCi@0,0 RSx@1,0".parse::<Notation<Board>>()
+        );
+        assert_eq!(
+            Err(NotationError::Duplicate {
+                token: "RSq@0,0".to_owned(),
+                position: 8,
+            }),
+            "RCi@0,0 RSq@0,0".parse::<Notation<Board>>()
+        );
+    }
+
+    #[test]
+    fn indexes_round_trip() {
+        let indexes = vec![2, 0, 1, 0];
+
+        assert_eq!("2 0 1 0", Notation(indexes.clone()).to_string());
+        assert_eq!(Ok(Notation(indexes)), "2 0 1 0".parse());
+        assert_eq!(
+            Err(NotationError::Index {
+                token: "x".to_owned(),
+                position: 2,
+            }),
+            "2 x".parse::<Notation<Vec<usize>>>()
+        );
+    }
+
+    #[test]
+    fn tile_plays_round_trip() {
+        let tile_plays = btree_map! {
+            (1, 0) => (Color::Red, Shape::Square),
+            (0, 0) => (Color::Red, Shape::Circle),
+        };
+
+        assert_eq!("RCi@0,0 RSq@1,0", Notation(tile_plays.clone()).to_string());
+        assert_eq!(Ok(Notation(tile_plays)), "RSq@1,0 RCi@0,0".parse());
+        assert_eq!(
+            Err(NotationError::Duplicate {
+                token: "RSq@0,0".to_owned(),
+                position: 8,
+            }),
+            "RCi@0,0 RSq@0,0".parse::<Notation<TilePlays>>()
+        );
+    }
+
+    #[test]
+    fn tile_exchanges_round_trip() {
+        let tile_exchanges = vec![
+            (Color::Red, Shape::Circle),
+            (Color::Blue, Shape::X),
+            (Color::Red, Shape::Circle),
+        ];
+
+        assert_eq!("RCi BX RCi", Notation(tile_exchanges.clone()).to_string());
+        assert_eq!(Ok(Notation(tile_exchanges)), "RCi BX RCi".parse());
+        assert_eq!(
+            Err(NotationError::Color {
+                token: "W".to_owned(),
+                position: 4,
+            }),
+            "RCi WX".parse::<Notation<TileExchanges>>()
+        );
+    }
+}
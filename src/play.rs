@@ -1,11 +1,19 @@
-use crate::{Board, Color, Coordinate, Plays, Shape, Tile, COORDINATE_LIMIT, FULL_MATCH_BONUS};
+use crate::{adjacent_coordinates, Board, Color, Coordinate, LineScore, Plays, Shape, Tile};
 use itertools::Itertools;
 use map_macro::btree_set;
 use std::collections::{BTreeSet, HashMap, HashSet};
+use std::iter;
 use std::iter::Peekable;
 
 /// Partitions [plays](Plays) by whether each [play](Plays) is inside
-/// the [coordinate limit](COORDINATE_LIMIT) or not.
+/// the `coordinate_limit` or not.
+///
+/// # Arguments
+///
+/// * `plays`: A bimap of indexes of [tiles](Tile) to be played to [coordinates](Coordinate)
+/// on the board.
+/// * `coordinate_limit`: The exclusive maximum absolute value of a component in
+/// a [coordinate](Coordinate).
 ///
 /// # See Also
 ///
@@ -15,18 +23,18 @@ use std::iter::Peekable;
 /// # Returns
 ///
 /// A tuple two collections of [plays](Plays) where the first collection contains
-/// [coordinates](Coordinate) inside the [coordinate limit](COORDINATE_LIMIT) and
+/// [coordinates](Coordinate) inside the `coordinate_limit` and
 /// the second collection contains [coordinates](Coordinate) on or outside
-/// the [coordinate limit](COORDINATE_LIMIT).
-pub fn partition_by_coordinates(plays: &Plays) -> (Plays, Plays) {
+/// the `coordinate_limit`.
+pub fn partition_by_coordinates(plays: &Plays, coordinate_limit: isize) -> (Plays, Plays) {
     let x_outside_coordinate_limit = plays
-        .right_range(..=(-COORDINATE_LIMIT, isize::MAX))
-        .chain(plays.right_range((COORDINATE_LIMIT, isize::MIN)..))
+        .right_range(..=(-coordinate_limit, isize::MAX))
+        .chain(plays.right_range((coordinate_limit, isize::MIN)..))
         .map(|(&index, &coordinate)| (index, coordinate));
     let (y_outside_coordinate_limit, y_inside_coordinate_limit): (Plays, Plays) = plays
-        .right_range((-COORDINATE_LIMIT + 1, isize::MIN)..(COORDINATE_LIMIT, isize::MIN))
+        .right_range((-coordinate_limit + 1, isize::MIN)..(coordinate_limit, isize::MIN))
         .map(|(&index, &coordinate)| (index, coordinate))
-        .partition(|&(_, (_, y))| -COORDINATE_LIMIT >= y || y >= COORDINATE_LIMIT);
+        .partition(|&(_, (_, y))| -coordinate_limit >= y || y >= coordinate_limit);
     (
         y_inside_coordinate_limit,
         x_outside_coordinate_limit
@@ -84,20 +92,22 @@ where
 }
 
 /// Takes a `line` of [tiles](Tile) being played on the board and returns
-/// earned points if the `line` is legal. Otherwise, it returns duplicate groups
-/// and/or multiple matching groups.
+/// the [score](LineScore) of the `line` if the `line` is legal. Otherwise, it returns
+/// duplicate groups and/or multiple matching groups.
 ///
 /// # Points Calculation
 ///
 /// The number of points from a line is the number of [tiles](Tile) in that line. If the
 /// line creates a full match on the board where a line contains either
 /// [every color](Color::colors) or [every shape](Shape::shapes), an extra
-/// [full match bonus](FULL_MATCH_BONUS) is earned.
+/// `full_match_bonus` is earned.
 ///
 /// # Arguments
 ///
 /// * `line`: A map of [coordinates](Coordinate) to [tiles](Tile) being played
 /// on the board.
+/// * `full_match_bonus`: The amount of extra points given for each line completed with either
+/// [every shape](Shape::shapes) or [every color](Color::colors).
 ///
 /// # Errors
 ///
@@ -113,11 +123,12 @@ where
 ///
 /// # Returns
 ///
-/// The earned points of the `line`.
+/// The [score](LineScore) of the `line`.
 pub fn check_line(
     line: &Board,
+    full_match_bonus: usize,
 ) -> Result<
-    usize,
+    LineScore,
     (
         BTreeSet<BTreeSet<Coordinate>>,
         BTreeSet<BTreeSet<Coordinate>>,
@@ -191,10 +202,237 @@ pub fn check_line(
     // it is a shape line. If the line is both (single tile), then line is
     // not long enough for bonus anyways. Checks if full match has been played for bonus.
     let is_color_line = !matching_shapes.is_empty();
-    if (is_color_line && len == Color::COLORS_LEN) || len == Shape::SHAPES_LEN {
-        Ok(len + FULL_MATCH_BONUS)
+    let full_match = (is_color_line && len == Color::COLORS_LEN) || len == Shape::SHAPES_LEN;
+    Ok(LineScore {
+        coordinates: line.keys().copied().collect(),
+        len,
+        full_match,
+        full_match_bonus: if full_match { full_match_bonus } else { 0 },
+    })
+}
+
+/// Finds every empty [coordinate](Coordinate) which is adjacent to some [tile](Tile)
+/// on the board and inside the `coordinate_limit`. When the board is empty, only the origin
+/// is returned.
+///
+/// # Arguments
+///
+/// * `board`: A map of [coordinates](Coordinate) to [tiles](Tile) that have been played.
+/// * `coordinate_limit`: The exclusive maximum absolute value of a component in
+/// a [coordinate](Coordinate).
+///
+/// # See Also
+///
+/// * [is_legal_tile_play]
+/// * [NextState::can_play](crate::NextState::can_play)
+///
+/// # Returns
+///
+/// An ordered set of [coordinates](Coordinate) where some [tile](Tile) could be played.
+pub fn find_frontier(board: &Board, coordinate_limit: isize) -> BTreeSet<Coordinate> {
+    if board.is_empty() {
+        return btree_set! { (0, 0) };
+    }
+
+    board
+        .keys()
+        .flat_map(|&coordinate| adjacent_coordinates(coordinate))
+        .filter(|coordinate| !board.contains_key(coordinate))
+        .filter(|&(x, y)| {
+            -coordinate_limit < x
+                && x < coordinate_limit
+                && -coordinate_limit < y
+                && y < coordinate_limit
+        })
+        .collect()
+}
+
+/// Whether the `tile` alone can be legally played at the empty `coordinate` on the board.
+/// Both the horizontal line and the vertical line through `coordinate`, made of
+/// the `tile` and the connected [tiles](Tile) on the board, must be legal lines.
+///
+/// Any legal [play](Plays) contains some [tile](Tile) which is also a legal play alone,
+/// so the current player can play if and only if some [tile](Tile) in their hand
+/// can be played alone at some [coordinate](Coordinate) in the [frontier](find_frontier).
+///
+/// # Arguments
+///
+/// * `board`: A map of [coordinates](Coordinate) to [tiles](Tile) that have been played.
+/// * `coordinate`: An empty [coordinate](Coordinate) on the board.
+/// * `tile`: The [tile](Tile) being played.
+///
+/// # See Also
+///
+/// * [find_frontier]
+/// * [check_line]
+/// * [NextState::can_play](crate::NextState::can_play)
+///
+/// # Returns
+///
+/// Whether the `tile` can be legally played at `coordinate`.
+pub fn is_legal_tile_play(board: &Board, (x, y): Coordinate, tile: Tile) -> bool {
+    let find_line = |next: fn(Coordinate, isize) -> Coordinate| -> Board {
+        let increasing = (1..)
+            .map(|offset| next((x, y), offset))
+            .map(|coordinate| board.get(&coordinate).map(|&tile| (coordinate, tile)))
+            .while_some();
+        let decreasing = (1..)
+            .map(|offset| next((x, y), -offset))
+            .map(|coordinate| board.get(&coordinate).map(|&tile| (coordinate, tile)))
+            .while_some();
+        iter::once(((x, y), tile))
+            .chain(increasing)
+            .chain(decreasing)
+            .collect()
+    };
+
+    // bonuses are ignored since only legality matters
+    check_line(&find_line(|(x, y), offset| (x + offset, y)), 0).is_ok()
+        && check_line(&find_line(|(x, y), offset| (x, y + offset)), 0).is_ok()
+}
+
+/// Finds every [play](Plays) of [tiles](Tile) from the hand which would be legal on the board.
+/// Each [play](Plays) starts from some [coordinate](Coordinate) in
+/// the [frontier](find_frontier) and grows one [tile](Tile) at a time from either end of
+/// its line, so every [play](Plays) is connected to the board and has no holes.
+/// When the hand holds copies of a [tile](Tile), every [play](Plays) of that [tile](Tile) is
+/// given once for the index of each copy.
+///
+/// # Arguments
+///
+/// * `board`: A map of [coordinates](Coordinate) to [tiles](Tile) that have been played.
+/// * `hand`: The [tiles](Tile) which might be played.
+/// * `coordinate_limit`: The exclusive maximum absolute value of a component in
+/// a [coordinate](Coordinate).
+///
+/// # See Also
+///
+/// * [find_frontier]
+/// * [is_legal_tile_play]
+/// * [NextState::legal_plays](crate::NextState::legal_plays)
+///
+/// # Returns
+///
+/// An ordered set of legal [plays](Plays).
+pub fn legal_plays(board: &Board, hand: &[Tile], coordinate_limit: isize) -> BTreeSet<Plays> {
+    let unique_hand: Vec<(usize, Tile)> = hand
+        .iter()
+        .copied()
+        .enumerate()
+        .unique_by(|&(_, tile)| tile)
+        .collect();
+    let horizontal: fn(Coordinate, isize) -> Coordinate = |(x, y), offset| (x + offset, y);
+    let vertical: fn(Coordinate, isize) -> Coordinate = |(x, y), offset| (x, y + offset);
+
+    // played tiles are inserted into and removed from a copy of the board while searching
+    let mut board = board.clone();
+    let mut plays = Plays::new();
+    let mut legal_plays = BTreeSet::new();
+    for anchor in find_frontier(&board, coordinate_limit) {
+        for next in [horizontal, vertical] {
+            extend_legal_plays(
+                &mut board,
+                &unique_hand,
+                (anchor, next),
+                coordinate_limit,
+                &mut plays,
+                &mut legal_plays,
+            );
+        }
+    }
+
+    legal_plays
+        .iter()
+        .flat_map(|plays| copy_variants(hand, plays))
+        .collect()
+}
+
+/// Every [play](Plays) which places the same [tiles](Tile) at the same
+/// [coordinates](Coordinate) as `plays` by using any copy of each [tile](Tile) in the hand.
+/// A line never contains duplicate [tiles](Tile), so the copies chosen always have different
+/// indexes.
+///
+/// # Arguments
+///
+/// * `hand`: The [tiles](Tile) which might be played.
+/// * `plays`: A bimap of indexes of [tiles](Tile) in `hand` to [coordinates](Coordinate).
+fn copy_variants<'a>(hand: &'a [Tile], plays: &'a Plays) -> impl Iterator<Item = Plays> + 'a {
+    plays
+        .iter()
+        .map(|(&index, &coordinate)| {
+            hand.iter()
+                .positions(move |&tile| tile == hand[index])
+                .map(move |copy| (copy, coordinate))
+        })
+        .multi_cartesian_product()
+        .map(|plays| plays.into_iter().collect())
+}
+
+/// Inserts `plays` into `legal_plays` and then tries every unused [tile](Tile) at either end of
+/// the line through `anchor`, searching deeper after every legal [tile](Tile).
+///
+/// # Arguments
+///
+/// * `board`: A map of [coordinates](Coordinate) to [tiles](Tile) that have been played,
+/// including the [tiles](Tile) from `plays`.
+/// * `unique_hand`: Indexes and unique [tiles](Tile) from the hand.
+/// * `(anchor, next)`: The first [coordinate](Coordinate) played and a function which offsets
+/// a [coordinate](Coordinate) along the line.
+/// * `coordinate_limit`: The exclusive maximum absolute value of a component in
+/// a [coordinate](Coordinate).
+/// * `plays`: The [plays](Plays) made so far.
+/// * `legal_plays`: Every legal [play](Plays) found so far.
+fn extend_legal_plays(
+    board: &mut Board,
+    unique_hand: &[(usize, Tile)],
+    (anchor, next): (Coordinate, fn(Coordinate, isize) -> Coordinate),
+    coordinate_limit: isize,
+    plays: &mut Plays,
+    legal_plays: &mut BTreeSet<Plays>,
+) {
+    if !plays.is_empty() && !legal_plays.insert(plays.clone()) && plays.len() > 1 {
+        // a play of multiple tiles has a single line, so it has already been searched
+        return;
+    }
+
+    let ends = if plays.is_empty() {
+        vec![anchor]
     } else {
-        Ok(len)
+        [1, -1]
+            .into_iter()
+            .filter_map(|direction| {
+                (1..)
+                    .map(|offset| next(anchor, direction * offset))
+                    .find(|coordinate| !board.contains_key(coordinate))
+            })
+            .filter(|&(x, y)| {
+                -coordinate_limit < x
+                    && x < coordinate_limit
+                    && -coordinate_limit < y
+                    && y < coordinate_limit
+            })
+            .collect()
+    };
+
+    for coordinate in ends {
+        for &(index, tile) in unique_hand {
+            if plays.contains_left(&index) || !is_legal_tile_play(board, coordinate, tile) {
+                continue;
+            }
+
+            board.insert(coordinate, tile);
+            plays.insert(index, coordinate);
+            extend_legal_plays(
+                board,
+                unique_hand,
+                (anchor, next),
+                coordinate_limit,
+                plays,
+                legal_plays,
+            );
+            plays.remove_by_left(&index);
+            board.remove(&coordinate);
+        }
     }
 }
 
@@ -261,8 +499,11 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{random_illegal_coordinates, random_legal_coordinates, Hand};
-    use map_macro::hash_map;
+    use crate::{
+        random_illegal_coordinates, random_legal_coordinates, Hand, COORDINATE_LIMIT,
+        FULL_MATCH_BONUS,
+    };
+    use map_macro::{btree_set, hash_map};
     use rand::Rng;
     use std::iter;
 
@@ -577,13 +818,131 @@ mod tests {
         assert!(peekable.eq(next));
     }
 
+    #[test]
+    fn find_frontier_empty_board() {
+        assert_eq!(
+            btree_set! { (0, 0) },
+            find_frontier(&Board::new(), COORDINATE_LIMIT)
+        );
+    }
+
+    #[test]
+    fn find_frontier_some_board() {
+        let board = hash_map! {
+            (0, 0) => (Color::Red, Shape::Circle),
+            (1, 0) => (Color::Red, Shape::Square),
+        };
+
+        assert_eq!(
+            btree_set! { (-1, 0), (0, -1), (0, 1), (1, -1), (1, 1), (2, 0) },
+            find_frontier(&board, COORDINATE_LIMIT)
+        );
+    }
+
+    #[test]
+    fn find_frontier_coordinate_limit() {
+        let board = hash_map! { (0, 0) => (Color::Red, Shape::Circle) };
+
+        assert!(find_frontier(&board, 1).is_empty());
+    }
+
+    #[test]
+    fn is_legal_tile_play_some_lines() {
+        let board = hash_map! {
+            (0, 0) => (Color::Red, Shape::Circle),
+            (1, 0) => (Color::Red, Shape::Square),
+            (2, 1) => (Color::Blue, Shape::Starburst),
+        };
+
+        let matching_both_lines = (Color::Red, Shape::Starburst);
+        let matching_color_only = (Color::Red, Shape::Diamond);
+        let matching_shape_only = (Color::Blue, Shape::Starburst);
+        let duplicate = (Color::Red, Shape::Square);
+
+        assert!(is_legal_tile_play(&board, (2, 0), matching_both_lines));
+        assert!(!is_legal_tile_play(&board, (2, 0), matching_color_only));
+        assert!(!is_legal_tile_play(&board, (2, 0), matching_shape_only));
+        assert!(!is_legal_tile_play(&board, (-1, 0), duplicate));
+    }
+
+    #[test]
+    fn legal_plays_single_tiles() {
+        let board = hash_map! { (0, 0) => (Color::Red, Shape::Circle) };
+        let hand = [(Color::Red, Shape::Square), (Color::Blue, Shape::Circle)];
+
+        let expected_plays: BTreeSet<Plays> = [0, 1]
+            .into_iter()
+            .cartesian_product([(1, 0), (-1, 0), (0, 1), (0, -1)])
+            .map(|play| iter::once(play).collect())
+            .collect();
+
+        assert_eq!(expected_plays, legal_plays(&board, &hand, COORDINATE_LIMIT));
+    }
+
+    #[test]
+    fn legal_plays_copies() {
+        let board = hash_map! { (0, 0) => (Color::Red, Shape::Circle) };
+        let hand = [(Color::Red, Shape::Square), (Color::Red, Shape::Square)];
+
+        let expected_plays: BTreeSet<Plays> = [0, 1]
+            .into_iter()
+            .cartesian_product([(1, 0), (-1, 0), (0, 1), (0, -1)])
+            .map(|play| iter::once(play).collect())
+            .collect();
+
+        assert_eq!(expected_plays, legal_plays(&board, &hand, COORDINATE_LIMIT));
+    }
+
+    #[test]
+    fn legal_plays_some_lines() {
+        let board = hash_map! { (0, 0) => (Color::Red, Shape::Circle) };
+        let hand = [(Color::Red, Shape::Square), (Color::Red, Shape::X)];
+
+        let legal_plays = legal_plays(&board, &hand, COORDINATE_LIMIT);
+
+        // 8 single tiles, 12 lines through the board, and 16 lines next to the board
+        assert_eq!(36, legal_plays.len());
+        assert!(legal_plays.contains(&[(0, (-1, 0)), (1, (1, 0))].into_iter().collect()));
+        assert!(legal_plays.contains(&[(0, (1, 0)), (1, (1, 1))].into_iter().collect()));
+        assert!(!legal_plays.contains(&[(0, (1, 0)), (1, (2, 1))].into_iter().collect()));
+    }
+
+    #[test]
+    fn legal_plays_coordinate_limit() {
+        let board = hash_map! { (0, 0) => (Color::Red, Shape::Circle) };
+        let hand = [(Color::Red, Shape::Square), (Color::Red, Shape::X)];
+
+        assert!(legal_plays(&board, &hand, 1).is_empty());
+    }
+
+    #[test]
+    fn legal_plays_copies_in_lines() {
+        let board = hash_map! { (0, 0) => (Color::Red, Shape::Circle) };
+        let hand = [
+            (Color::Red, Shape::Square),
+            (Color::Red, Shape::X),
+            (Color::Red, Shape::Square),
+        ];
+
+        let legal_plays = legal_plays(&board, &hand, COORDINATE_LIMIT);
+
+        let first_copy = legal_plays.iter().filter(|plays| plays.contains_left(&0));
+        let second_copy = legal_plays.iter().filter(|plays| plays.contains_left(&2));
+        assert_eq!(first_copy.count(), second_copy.count());
+        assert!(legal_plays.contains(&[(0, (-1, 0)), (1, (1, 0))].into_iter().collect()));
+        assert!(legal_plays.contains(&[(1, (1, 0)), (2, (-1, 0))].into_iter().collect()));
+        assert!(!legal_plays
+            .iter()
+            .any(|plays| plays.contains_left(&0) && plays.contains_left(&2)));
+    }
+
     fn test_partition_by_coordinates(
         plays: Plays,
         expected_coordinates_in_bounds: Plays,
         expected_coordinates_out_of_bounds: Plays,
     ) {
         let (actual_coordinates_in_bounds, actual_coordinates_out_of_bounds) =
-            partition_by_coordinates(&plays);
+            partition_by_coordinates(&plays, COORDINATE_LIMIT);
 
         assert_eq!(expected_coordinates_in_bounds, actual_coordinates_in_bounds);
         assert_eq!(
@@ -613,16 +972,21 @@ mod tests {
         expected_multiple_matching: BTreeSet<BTreeSet<Coordinate>>,
     ) {
         let (actual_duplicates, actual_multiple_matching) =
-            check_line(&line).expect_err("check_line should return Err");
+            check_line(&line, FULL_MATCH_BONUS).expect_err("check_line should return Err");
 
         assert_eq!(expected_duplicates, actual_duplicates);
         assert_eq!(expected_multiple_matching, actual_multiple_matching);
     }
 
     fn test_check_line(line: Board, expected_points: usize) {
-        let actual_points = check_line(&line).expect("check_line should return Ok");
+        let line_score = check_line(&line, FULL_MATCH_BONUS).expect("check_line should return Ok");
 
-        assert_eq!(expected_points, actual_points);
+        assert_eq!(
+            line.keys().copied().collect::<BTreeSet<_>>(),
+            line_score.coordinates
+        );
+        assert_eq!(line.len(), line_score.len);
+        assert_eq!(expected_points, line_score.points());
     }
 
     fn test_batch_continuous_decreasing_range<I>(
@@ -0,0 +1,329 @@
+use crate::{Action, ActionError, FirstState, FirstView, Game, Hand, LastState, NextView};
+use async_trait::async_trait;
+
+/// The part of the game visible to the current player when they are asked to
+/// [choose an action](Player::choose_action).
+#[derive(Debug)]
+pub enum PlayerView<'a> {
+    /// The `First` phase of the game. Represented by [FirstView].
+    First(FirstView<'a>),
+    /// The `Next` phase of the game. Represented by [NextView].
+    Next(NextView<'a>),
+}
+
+/// Chooses [actions](Action) for a single player, such as a bot, a network client, or
+/// a test harness. Driven by [drive_game].
+#[async_trait]
+pub trait Player: Send {
+    /// Chooses how to advance the game on the player's turn.
+    ///
+    /// # Arguments
+    ///
+    /// * `view`: The part of the game visible to the player, including the
+    /// [identities](crate::TileId) of their own hand when [tile ids](crate::TileIds)
+    /// are tracked.
+    /// * `hand`: The [tiles](crate::Tile) held by the player.
+    async fn choose_action(&mut self, view: PlayerView<'_>, hand: &Hand) -> Action;
+
+    /// Tells the player why their [action](Action) could not be [applied](Game::apply)
+    /// before they are asked to [choose an action](Player::choose_action) again. Does nothing
+    /// by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `action`: The rejected [action](Action).
+    /// * `error`: The reason the [action](Action) could not be [applied](Game::apply).
+    async fn reject_action(&mut self, action: &Action, error: &ActionError) {
+        let _ = (action, error);
+    }
+}
+
+/// Describes the reason why [drive_game] could not finish the game.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum DriveError {
+    /// Attempting to drive the game with a different number of [players](Player) than hands.
+    PlayersLen {
+        /// The number of hands in the game.
+        expected: usize,
+        /// The number of [players](Player).
+        actual: usize,
+    },
+    /// Attempting to keep the game going after a [player](Player) chose rejected
+    /// [actions](Action) more than `max_rejections` times in a row.
+    TooManyRejections {
+        /// The index of the player whose turn it is.
+        player: usize,
+        /// The reason the last [action](Action) could not be [applied](Game::apply).
+        error: ActionError,
+    },
+    /// Attempting to keep the game going after a [player](Player)
+    /// [reordered their hand](Action::ReorderHand) more than `max_reorders` times in one turn.
+    TooManyReorders {
+        /// The index of the player whose turn it is.
+        player: usize,
+    },
+}
+
+/// Runs the game from the [first state](FirstState) until it ends by asking the current
+/// [player](Player) to [choose an action](Player::choose_action) and [applying](Game::apply) it.
+/// If the [action](Action) is rejected, such as by [FirstPlayError](crate::FirstPlayError),
+/// [NextPlayError](crate::NextPlayError), or [NextExchangeError](crate::NextExchangeError),
+/// the [player](Player) is [told why](Player::reject_action) and asked again. So is a player
+/// who [reorders](Action::ReorderHand) the hand of [another player](ActionError::ReorderOtherHand).
+/// A player who [reorders their hand](Action::ReorderHand) is also asked again, since reordering is not
+/// a turn, until they have reordered `max_reorders` times in that turn.
+///
+/// # Arguments
+///
+/// * `first_state`: The game created from [FirstState::new] or one of its variants.
+/// * `players`: One [player](Player) for each hand in the same order.
+/// * `max_rejections`: The number of rejected [actions](Action) in a row allowed for each turn.
+/// * `max_reorders`: The number of [reorders](Action::ReorderHand) allowed for each turn.
+///
+/// # Errors
+///
+/// * [DriveError::PlayersLen] Attempting to drive the game with a different number of
+/// [players](Player) than hands.
+/// * [DriveError::TooManyRejections] Attempting to keep the game going after a [player](Player)
+/// chose rejected [actions](Action) more than `max_rejections` times in a row.
+/// * [DriveError::TooManyReorders] Attempting to keep the game going after a [player](Player)
+/// [reordered their hand](Action::ReorderHand) more than `max_reorders` times in one turn.
+///
+/// # Returns
+///
+/// The [last state](LastState) of the game.
+pub async fn drive_game(
+    first_state: FirstState,
+    players: &mut [Box<dyn Player>],
+    max_rejections: usize,
+    max_reorders: usize,
+) -> Result<LastState, DriveError> {
+    let expected = first_state.first_view().hand_lens.len();
+    if players.len() != expected {
+        return Err(DriveError::PlayersLen {
+            expected,
+            actual: players.len(),
+        });
+    }
+
+    let mut game = Game::from(first_state);
+    let mut rejections = 0;
+    let mut reorders = 0;
+    loop {
+        let (player, action) = match &game {
+            Game::First(first_state) => {
+                let player = first_state.current_player();
+                let hand = first_state
+                    .get_hand(player)
+                    .unwrap_or_else(|| unreachable!("current player should have a hand."));
+                let view = PlayerView::First(first_state.first_view_for(player));
+                (player, players[player].choose_action(view, hand).await)
+            }
+            Game::Next(next_state) => {
+                let player = next_state.current_player();
+                let hand = next_state
+                    .get_hand(player)
+                    .unwrap_or_else(|| unreachable!("current player should have a hand."));
+                let view = PlayerView::Next(next_state.next_view_for(player));
+                (player, players[player].choose_action(view, hand).await)
+            }
+            Game::Last(_) => break,
+        };
+
+        let result = match &action {
+            Action::ReorderHand {
+                player: reordered, ..
+            } if *reordered != player => Err((
+                game,
+                ActionError::ReorderOtherHand {
+                    player: *reordered,
+                    current_player: player,
+                },
+            )),
+            _ => game.apply(&action),
+        };
+        game = match result {
+            Ok((game, _)) if matches!(action, Action::ReorderHand { .. }) => {
+                reorders += 1;
+                if reorders > max_reorders {
+                    return Err(DriveError::TooManyReorders { player });
+                }
+                game
+            }
+            Ok((game, _)) => {
+                rejections = 0;
+                reorders = 0;
+                game
+            }
+            Err((game, error)) => {
+                rejections += 1;
+                if rejections > max_rejections {
+                    return Err(DriveError::TooManyRejections { player, error });
+                }
+                players[player].reject_action(&action, &error).await;
+                game
+            }
+        };
+    }
+
+    match game {
+        Game::Last(last_state) => Ok(last_state),
+        _ => unreachable!("game should have ended."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color, NextExchangeError, Shape};
+    use futures::executor::block_on;
+    use map_macro::{btree_set, hash_set};
+    use std::collections::VecDeque;
+    use std::sync::{Arc, Mutex};
+
+    /// Chooses [actions](Action) in order and remembers every rejection.
+    struct ScriptedPlayer {
+        actions: VecDeque<Action>,
+        rejections: Arc<Mutex<Vec<ActionError>>>,
+    }
+
+    #[async_trait]
+    impl Player for ScriptedPlayer {
+        async fn choose_action(&mut self, _: PlayerView<'_>, _: &Hand) -> Action {
+            self.actions.pop_front().unwrap_or(Action::Pass)
+        }
+
+        async fn reject_action(&mut self, _: &Action, error: &ActionError) {
+            self.rejections
+                .lock()
+                .expect("lock should return Ok")
+                .push(error.clone());
+        }
+    }
+
+    fn scripted_player(
+        actions: impl IntoIterator<Item = Action>,
+    ) -> (Box<dyn Player>, Arc<Mutex<Vec<ActionError>>>) {
+        let rejections = Arc::new(Mutex::new(Vec::new()));
+        let player = ScriptedPlayer {
+            actions: actions.into_iter().collect(),
+            rejections: Arc::clone(&rejections),
+        };
+        (Box::new(player), rejections)
+    }
+
+    fn set_up_first_state() -> FirstState {
+        let mut first_state = FirstState::empty_first_state();
+        for tile in [(Color::Red, Shape::Circle), (Color::Red, Shape::Square)] {
+            first_state.mut_hands().push(Hand::from_slice(&[tile]));
+            first_state.mut_max_matches().push(1);
+        }
+        first_state
+    }
+
+    #[test]
+    fn drive_game_to_last_state() {
+        let (first, _) = scripted_player([Action::Play([(0, (0, 0))].into_iter().collect())]);
+        let (second, rejections) = scripted_player([
+            Action::Exchange(btree_set! { 0 }),
+            Action::Play([(0, (1, 0))].into_iter().collect()),
+        ]);
+
+        let mut last_state = block_on(drive_game(set_up_first_state(), &mut [first, second], 1, 0))
+            .expect("drive_game should return Ok");
+
+        assert_eq!(2, last_state.mut_board().len());
+        assert_eq!(
+            vec![ActionError::NextExchange(hash_set! {
+                NextExchangeError::NotEnoughTiles {
+                    legal_exchanges: 1,
+                    bag_len: 0,
+                },
+            })],
+            *rejections.lock().expect("lock should return Ok")
+        );
+    }
+
+    #[test]
+    fn drive_game_too_many_rejections() {
+        let (first, rejections) = scripted_player([]);
+        let (second, _) = scripted_player([]);
+
+        let error = block_on(drive_game(set_up_first_state(), &mut [first, second], 2, 0))
+            .expect_err("drive_game should return Err");
+
+        assert_eq!(
+            DriveError::TooManyRejections {
+                player: 0,
+                error: ActionError::FirstPass,
+            },
+            error
+        );
+        assert_eq!(2, rejections.lock().expect("lock should return Ok").len());
+    }
+
+    #[test]
+    fn drive_game_too_many_reorders() {
+        let (first, _) = scripted_player([Action::Play([(0, (0, 0))].into_iter().collect())]);
+        let reorder = Action::ReorderHand {
+            player: 1,
+            permutation: vec![0],
+        };
+        let (second, rejections) = scripted_player(vec![reorder; 3]);
+
+        let error = block_on(drive_game(set_up_first_state(), &mut [first, second], 0, 2))
+            .expect_err("drive_game should return Err");
+
+        assert_eq!(DriveError::TooManyReorders { player: 1 }, error);
+        assert!(rejections.lock().expect("lock should return Ok").is_empty());
+    }
+
+    #[test]
+    fn drive_game_reorder_other_hand() {
+        let (first, _) = scripted_player([Action::Play([(0, (0, 0))].into_iter().collect())]);
+        let (second, rejections) = scripted_player([
+            Action::ReorderHand {
+                player: 0,
+                permutation: vec![],
+            },
+            Action::Play([(0, (1, 0))].into_iter().collect()),
+        ]);
+
+        let mut last_state = block_on(drive_game(set_up_first_state(), &mut [first, second], 1, 1))
+            .expect("drive_game should return Ok");
+
+        assert_eq!(2, last_state.mut_board().len());
+        assert_eq!(
+            vec![ActionError::ReorderOtherHand {
+                player: 0,
+                current_player: 1,
+            }],
+            *rejections.lock().expect("lock should return Ok")
+        );
+    }
+
+    #[test]
+    fn drive_game_send() {
+        fn assert_send<T: Send>(_: T) {}
+        let (first, _) = scripted_player([]);
+        let (second, _) = scripted_player([]);
+
+        assert_send(drive_game(set_up_first_state(), &mut [first, second], 0, 0));
+    }
+
+    #[test]
+    fn drive_game_players_len() {
+        let (first, _) = scripted_player([]);
+
+        let error = block_on(drive_game(set_up_first_state(), &mut [first], 0, 0))
+            .expect_err("drive_game should return Err");
+
+        assert_eq!(
+            DriveError::PlayersLen {
+                expected: 2,
+                actual: 1,
+            },
+            error
+        );
+    }
+}
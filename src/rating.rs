@@ -0,0 +1,681 @@
+use crate::LastState;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// The rating change for a single expected loss used by [Elo::new] when none is given.
+pub const DEFAULT_K_FACTOR: f64 = 32.0;
+
+/// The rating of a player who has not been rated used by [Elo::new] when none is given.
+pub const DEFAULT_INITIAL_RATING: f64 = 1500.0;
+
+/// The rating of a single player.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rating {
+    /// The estimated strength of the player, where a difference of `400` means the stronger
+    /// player is expected to score `10` times as often.
+    pub rating: f64,
+    /// The number of rated games the player has played.
+    pub games: usize,
+}
+
+/// Ratings of persistent players by name. Written with [Display] and parsed with [FromStr]
+/// as one player on each line ordered by name, where the rating and the number of games come
+/// before the name so that names may contain spaces:
+///
+/// ```text
+/// 1516 1 alice
+/// 1484 1 bob the builder
+/// ```
+///
+/// # See Also
+///
+/// * [Elo]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RatingTableFields")
+)]
+pub struct RatingTable {
+    /// Every rated player by name.
+    ratings: BTreeMap<String, Rating>,
+}
+
+/// Every field of [RatingTable], which is deserialized before each rating is
+/// [inserted](RatingTable::insert).
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RatingTableFields {
+    ratings: BTreeMap<String, Rating>,
+}
+
+/// Updates a [rating table](RatingTable) from finished games with multiplayer Elo, where each
+/// game is scored as every pair of players playing each other. The player with more points wins
+/// the pair and equal points tie the pair. The changes are scaled by `1 / (players - 1)` so that
+/// a game counts the same as a single two player game.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Elo {
+    /// The rating change for a single expected loss.
+    k_factor: f64,
+    /// The rating of a player who has not been rated.
+    initial_rating: f64,
+}
+
+/// Describes the reason why a game could not be [rated](Elo::rate_points).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RatingError {
+    /// Attempting to rate a game with a different number of players than points.
+    PlayersLen {
+        /// The number of points.
+        expected: usize,
+        /// The number of players.
+        actual: usize,
+    },
+    /// Attempting to rate a game where some player has more than one seat.
+    DuplicatePlayers {
+        /// Every player with more than one seat.
+        players: BTreeSet<String>,
+    },
+    /// Attempting to rate a game where some name could not be
+    /// [inserted](RatingTable::insert) into the [rating table](RatingTable).
+    InvalidPlayers {
+        /// Every name which could not be inserted.
+        players: BTreeSet<String>,
+    },
+    /// Attempting to rate a game where some new rating is not a finite number, such as when
+    /// the k-factor or the initial rating of the [Elo] struct is not a finite number.
+    InvalidRatings {
+        /// Every player whose new rating is not a finite number.
+        players: BTreeSet<String>,
+    },
+}
+
+/// Describes the reason why a rating could not be [inserted](RatingTable::insert), because
+/// the [rating table](RatingTable) could not be parsed back after being written.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RatingInsertError {
+    /// Attempting to insert a name which is empty, starts or ends with whitespace,
+    /// or contains a newline.
+    Player {
+        /// The rejected name.
+        player: String,
+    },
+    /// Attempting to insert a rating which is not a finite number.
+    Rating {
+        /// The written rating.
+        value: String,
+    },
+}
+
+/// Describes the reason why a [rating table](RatingTable) could not be parsed. Each `line`
+/// starts from `1`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RatingTableError {
+    /// Attempting to parse a line without a rating, a number of games, and a name.
+    Line {
+        /// The line of the player.
+        line: usize,
+    },
+    /// Attempting to parse a rating which is not a finite number.
+    Rating {
+        /// The unparsed rating.
+        value: String,
+        /// The line of the player.
+        line: usize,
+    },
+    /// Attempting to parse a number of games which is not a number.
+    Games {
+        /// The unparsed number of games.
+        value: String,
+        /// The line of the player.
+        line: usize,
+    },
+    /// Attempting to parse the same name more than once.
+    DuplicatePlayer {
+        /// The repeated name.
+        player: String,
+        /// The line of the repeated name.
+        line: usize,
+    },
+}
+
+impl RatingTable {
+    /// A new [RatingTable] struct without any rated players.
+    pub fn new() -> RatingTable {
+        RatingTable::default()
+    }
+
+    /// The rating of the player or [None] if the player has not been rated.
+    pub fn get(&self, player: &str) -> Option<&Rating> {
+        self.ratings.get(player)
+    }
+
+    /// Sets the rating of the player and returns the previous rating if there was one.
+    ///
+    /// # Arguments
+    ///
+    /// * `player`: The name of the player.
+    /// * `rating`: The new rating of the player.
+    ///
+    /// # Errors
+    ///
+    /// * [RatingInsertError::Player] Attempting to insert a name which is empty, starts or ends
+    /// with whitespace, or contains a newline.
+    /// * [RatingInsertError::Rating] Attempting to insert a rating which is not a finite number.
+    pub fn insert(
+        &mut self,
+        player: String,
+        rating: Rating,
+    ) -> Result<Option<Rating>, RatingInsertError> {
+        if !is_valid_player(&player) {
+            return Err(RatingInsertError::Player { player });
+        }
+        if !rating.rating.is_finite() {
+            return Err(RatingInsertError::Rating {
+                value: rating.rating.to_string(),
+            });
+        }
+
+        Ok(self.ratings.insert(player, rating))
+    }
+
+    /// Every rated player and their rating ordered by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Rating)> {
+        self.ratings
+            .iter()
+            .map(|(player, rating)| (player.as_str(), rating))
+    }
+
+    /// The number of rated players.
+    pub fn len(&self) -> usize {
+        self.ratings.len()
+    }
+
+    /// Whether no player has been rated.
+    pub fn is_empty(&self) -> bool {
+        self.ratings.is_empty()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RatingTableFields> for RatingTable {
+    type Error = RatingInsertError;
+
+    /// [Inserts](RatingTable::insert) each rating, so that a name or a rating which could not
+    /// be parsed back after being written cannot be deserialized.
+    fn try_from(fields: RatingTableFields) -> Result<Self, Self::Error> {
+        let mut rating_table = RatingTable::new();
+        for (player, rating) in fields.ratings {
+            rating_table.insert(player, rating)?;
+        }
+
+        Ok(rating_table)
+    }
+}
+
+impl Display for RatingInsertError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RatingInsertError::Player { player } => write!(f, "invalid player: {player:?}"),
+            RatingInsertError::Rating { value } => write!(f, "invalid rating: {value}"),
+        }
+    }
+}
+
+impl Display for RatingTable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (player, rating) in &self.ratings {
+            writeln!(f, "{} {} {player}", rating.rating, rating.games)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for RatingTable {
+    type Err = RatingTableError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut ratings = BTreeMap::new();
+        for (line, text) in (1..).zip(s.lines()) {
+            let text = text.trim();
+            if text.is_empty() {
+                continue;
+            }
+
+            let mut split = text.splitn(3, ' ');
+            let (Some(rating), Some(games), Some(player)) =
+                (split.next(), split.next(), split.next())
+            else {
+                return Err(RatingTableError::Line { line });
+            };
+            let player = player.trim();
+            if player.is_empty() {
+                return Err(RatingTableError::Line { line });
+            }
+
+            let rating = rating
+                .parse::<f64>()
+                .ok()
+                .filter(|rating| rating.is_finite())
+                .ok_or_else(|| RatingTableError::Rating {
+                    value: rating.to_owned(),
+                    line,
+                })?;
+            let games = games.parse().map_err(|_| RatingTableError::Games {
+                value: games.to_owned(),
+                line,
+            })?;
+            if ratings
+                .insert(player.to_owned(), Rating { rating, games })
+                .is_some()
+            {
+                return Err(RatingTableError::DuplicatePlayer {
+                    player: player.to_owned(),
+                    line,
+                });
+            }
+        }
+        Ok(RatingTable { ratings })
+    }
+}
+
+impl Elo {
+    /// A new [Elo] struct.
+    ///
+    /// When any argument is [None], the default value from [DEFAULT_K_FACTOR] or
+    /// [DEFAULT_INITIAL_RATING] is used respectively.
+    ///
+    /// # Arguments
+    ///
+    /// * `k_factor`: The rating change for a single expected loss.
+    /// * `initial_rating`: The rating of a player who has not been rated.
+    pub fn new(k_factor: Option<f64>, initial_rating: Option<f64>) -> Elo {
+        Elo {
+            k_factor: k_factor.unwrap_or(DEFAULT_K_FACTOR),
+            initial_rating: initial_rating.unwrap_or(DEFAULT_INITIAL_RATING),
+        }
+    }
+
+    /// The rating change for a single expected loss.
+    pub fn k_factor(&self) -> f64 {
+        self.k_factor
+    }
+
+    /// The rating of a player who has not been rated.
+    pub fn initial_rating(&self) -> f64 {
+        self.initial_rating
+    }
+
+    /// Same as [Elo::rate_points] with the final points of the [last state](LastState).
+    ///
+    /// # Arguments
+    ///
+    /// * `table`: The ratings which are updated.
+    /// * `players`: The name of the player in each seat in the same order as the hands.
+    /// * `last_state`: The finished game.
+    ///
+    /// # Errors
+    ///
+    /// Same as [Elo::rate_points].
+    ///
+    /// # Returns
+    ///
+    /// Same as [Elo::rate_points].
+    pub fn rate_game<S: AsRef<str>>(
+        &self,
+        table: &mut RatingTable,
+        players: &[S],
+        last_state: &LastState,
+    ) -> Result<Vec<f64>, RatingError> {
+        self.rate_points(table, players, last_state.last_view().points)
+    }
+
+    /// Ranks the players by points and updates every rating at once from the ratings before
+    /// the game. Players who have not been rated start from the initial rating.
+    ///
+    /// # Arguments
+    ///
+    /// * `table`: The ratings which are updated.
+    /// * `players`: The name of the player in each seat.
+    /// * `points`: The final points of each seat.
+    ///
+    /// # Errors
+    ///
+    /// * [RatingError::PlayersLen] Attempting to rate a game with a different number of players
+    /// than points.
+    /// * [RatingError::DuplicatePlayers] Attempting to rate a game where some player has more
+    /// than one seat.
+    /// * [RatingError::InvalidPlayers] Attempting to rate a game where some name could not be
+    /// [inserted](RatingTable::insert) into the [rating table](RatingTable).
+    /// * [RatingError::InvalidRatings] Attempting to rate a game where some new rating is not
+    /// a finite number.
+    ///
+    /// # Returns
+    ///
+    /// The rating change of each seat. The [rating table](RatingTable) is left unchanged
+    /// on any error.
+    pub fn rate_points<S: AsRef<str>>(
+        &self,
+        table: &mut RatingTable,
+        players: &[S],
+        points: &[usize],
+    ) -> Result<Vec<f64>, RatingError> {
+        if players.len() != points.len() {
+            return Err(RatingError::PlayersLen {
+                expected: points.len(),
+                actual: players.len(),
+            });
+        }
+
+        let mut seen = BTreeSet::new();
+        let duplicates: BTreeSet<String> = players
+            .iter()
+            .map(AsRef::as_ref)
+            .filter(|&player| !seen.insert(player))
+            .map(str::to_owned)
+            .collect();
+        if !duplicates.is_empty() {
+            return Err(RatingError::DuplicatePlayers {
+                players: duplicates,
+            });
+        }
+
+        let invalid: BTreeSet<String> = players
+            .iter()
+            .map(AsRef::as_ref)
+            .filter(|player| !is_valid_player(player))
+            .map(str::to_owned)
+            .collect();
+        if !invalid.is_empty() {
+            return Err(RatingError::InvalidPlayers { players: invalid });
+        }
+
+        let ratings: Vec<f64> = players
+            .iter()
+            .map(|player| {
+                table
+                    .get(player.as_ref())
+                    .map_or(self.initial_rating, |rating| rating.rating)
+            })
+            .collect();
+        // a single player has no opponents, but the game still counts
+        let scale = self.k_factor / (players.len().max(2) - 1) as f64;
+        let changes: Vec<f64> = (0..players.len())
+            .map(|seat| {
+                let surprise: f64 = (0..players.len())
+                    .filter(|&other| other != seat)
+                    .map(|other| {
+                        let actual = match points[seat].cmp(&points[other]) {
+                            std::cmp::Ordering::Greater => 1.0,
+                            std::cmp::Ordering::Equal => 0.5,
+                            std::cmp::Ordering::Less => 0.0,
+                        };
+                        let expected =
+                            1.0 / (1.0 + 10f64.powf((ratings[other] - ratings[seat]) / 400.0));
+                        actual - expected
+                    })
+                    .sum();
+                scale * surprise
+            })
+            .collect();
+
+        let invalid: BTreeSet<String> = players
+            .iter()
+            .zip(&ratings)
+            .zip(&changes)
+            .filter(|((_, rating), change)| !(*rating + *change).is_finite())
+            .map(|((player, _), _)| player.as_ref().to_owned())
+            .collect();
+        if !invalid.is_empty() {
+            return Err(RatingError::InvalidRatings { players: invalid });
+        }
+
+        for ((player, rating), change) in players.iter().zip(ratings).zip(&changes) {
+            let games = table.get(player.as_ref()).map_or(0, |rating| rating.games);
+            // names and ratings have already been checked
+            table.ratings.insert(
+                player.as_ref().to_owned(),
+                Rating {
+                    rating: rating + change,
+                    games: games + 1,
+                },
+            );
+        }
+        Ok(changes)
+    }
+}
+
+/// Whether the name is written and parsed back unchanged by a [rating table](RatingTable).
+fn is_valid_player(player: &str) -> bool {
+    !player.is_empty() && player.trim() == player && !player.contains('\n')
+}
+
+impl Default for Elo {
+    fn default() -> Self {
+        Elo::new(None, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use map_macro::btree_set;
+
+    fn assert_close(expected: f64, actual: f64) {
+        assert!(
+            (expected - actual).abs() < 1e-9,
+            "expected {expected} but was {actual}"
+        );
+    }
+
+    #[test]
+    fn rate_points_two_players() {
+        let mut table = RatingTable::new();
+
+        let changes = Elo::default()
+            .rate_points(&mut table, &["alice", "bob"], &[10, 4])
+            .expect("rate_points should return Ok");
+
+        assert_eq!(vec![16.0, -16.0], changes);
+        assert_eq!(
+            Some(&Rating {
+                rating: 1516.0,
+                games: 1
+            }),
+            table.get("alice")
+        );
+        assert_eq!(
+            Some(&Rating {
+                rating: 1484.0,
+                games: 1
+            }),
+            table.get("bob")
+        );
+    }
+
+    #[test]
+    fn rate_points_ties() {
+        let mut table = RatingTable::new();
+        table
+            .insert(
+                "alice".to_owned(),
+                Rating {
+                    rating: 1600.0,
+                    games: 4,
+                },
+            )
+            .expect("insert should return Ok");
+
+        let changes = Elo::new(Some(20.0), None)
+            .rate_points(&mut table, &["alice", "bob", "carol"], &[7, 7, 3])
+            .expect("rate_points should return Ok");
+
+        let expected = |rating: f64, other: f64| 1.0 / (1.0 + 10f64.powf((other - rating) / 400.0));
+        assert_close(
+            10.0 * (0.5 - expected(1600.0, 1500.0) + 1.0 - expected(1600.0, 1500.0)),
+            changes[0],
+        );
+        assert_close(
+            10.0 * (0.5 - expected(1500.0, 1600.0) + 1.0 - expected(1500.0, 1500.0)),
+            changes[1],
+        );
+        assert_close(
+            10.0 * (0.0 - expected(1500.0, 1600.0) - expected(1500.0, 1500.0)),
+            changes[2],
+        );
+        assert_close(0.0, changes.iter().sum());
+        assert_eq!(
+            5,
+            table.get("alice").map(|rating| rating.games).unwrap_or(0)
+        );
+    }
+
+    #[test]
+    fn rate_points_errors() {
+        let mut table = RatingTable::new();
+        let elo = Elo::default();
+
+        assert_eq!(
+            Err(RatingError::PlayersLen {
+                expected: 3,
+                actual: 2,
+            }),
+            elo.rate_points(&mut table, &["alice", "bob"], &[1, 2, 3])
+        );
+        assert_eq!(
+            Err(RatingError::DuplicatePlayers {
+                players: btree_set! { "bob".to_owned() },
+            }),
+            elo.rate_points(&mut table, &["bob", "alice", "bob"], &[1, 2, 3])
+        );
+        assert_eq!(
+            Err(RatingError::InvalidPlayers {
+                players: btree_set! { "".to_owned(), " bob".to_owned() },
+            }),
+            elo.rate_points(&mut table, &["", "alice", " bob"], &[1, 2, 3])
+        );
+        assert_eq!(
+            Err(RatingError::InvalidRatings {
+                players: btree_set! { "alice".to_owned(), "bob".to_owned() },
+            }),
+            Elo::new(Some(f64::INFINITY), None).rate_points(&mut table, &["alice", "bob"], &[1, 2])
+        );
+        assert!(table.is_empty());
+    }
+
+    #[test]
+    fn insert_errors() {
+        let mut table = RatingTable::new();
+        let rating = Rating {
+            rating: 1500.0,
+            games: 0,
+        };
+
+        for player in ["", " alice", "alice ", "alice\n1500 0 bob", "\t"] {
+            assert_eq!(
+                Err(RatingInsertError::Player {
+                    player: player.to_owned(),
+                }),
+                table.insert(player.to_owned(), rating)
+            );
+        }
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(
+                Err(RatingInsertError::Rating {
+                    value: value.to_string(),
+                }),
+                table.insert(
+                    "alice".to_owned(),
+                    Rating {
+                        rating: value,
+                        games: 0,
+                    }
+                )
+            );
+        }
+        assert!(table.is_empty());
+
+        table
+            .insert("alice  the\tgreat".to_owned(), rating)
+            .expect("insert should return Ok");
+        assert_eq!(Ok(table.clone()), table.to_string().parse());
+    }
+
+    #[test]
+    fn rate_game_last_state() {
+        let mut last_state = LastState::empty_last_state();
+        last_state.mut_points().extend([3, 9]);
+        let mut table = RatingTable::new();
+
+        Elo::default()
+            .rate_game(
+                &mut table,
+                &["alice".to_owned(), "bob".to_owned()],
+                &last_state,
+            )
+            .expect("rate_game should return Ok");
+
+        assert_eq!(Some(1484.0), table.get("alice").map(|rating| rating.rating));
+        assert_eq!(Some(1516.0), table.get("bob").map(|rating| rating.rating));
+    }
+
+    #[test]
+    fn rating_table_round_trip() {
+        let mut table = RatingTable::new();
+        Elo::default()
+            .rate_points(&mut table, &["bob the builder", "alice"], &[2, 5])
+            .expect("rate_points should return Ok");
+        table
+            .insert(
+                "carol".to_owned(),
+                Rating {
+                    rating: 1432.125,
+                    games: 12,
+                },
+            )
+            .expect("insert should return Ok");
+
+        let written = table.to_string();
+
+        assert_eq!(
+            "1516 1 alice\n1484 1 bob the builder\n1432.125 12 carol\n",
+            written
+        );
+        assert_eq!(Ok(table), written.parse());
+    }
+
+    #[test]
+    fn rating_table_errors() {
+        assert_eq!(
+            Err(RatingTableError::Line { line: 2 }),
+            "1500 0 alice\n1500 0".parse::<RatingTable>()
+        );
+        assert_eq!(
+            Err(RatingTableError::Rating {
+                value: "NaN".to_owned(),
+                line: 1,
+            }),
+            "NaN 0 alice".parse::<RatingTable>()
+        );
+        assert_eq!(
+            Err(RatingTableError::Games {
+                value: "-1".to_owned(),
+                line: 1,
+            }),
+            "1500 -1 alice".parse::<RatingTable>()
+        );
+        assert_eq!(
+            Err(RatingTableError::DuplicatePlayer {
+                player: "alice".to_owned(),
+                line: 3,
+            }),
+            "1500 0 alice\n\n1510 2 alice".parse::<RatingTable>()
+        );
+    }
+}
@@ -0,0 +1,886 @@
+use crate::{
+    Action, ActionError, ConfigError, Exchanges, FirstState, Game, GameConfig, NewError, NextState,
+    Notation, NotationError, Plays, Points, ScoreBreakdown, TileExchanges, TilePlays,
+};
+use itertools::Itertools;
+use std::collections::HashSet;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// A record of a whole game which is enough to replay every turn. Written with [Display] and
+/// parsed with [FromStr] in a format like PGN, where the setup parameters and final points are
+/// tags followed by numbered [actions](Action) in [notation](Notation):
+///
+/// ```text
+/// [Players "2"]
+/// [UniqueTileCopiedCount "3"]
+/// [HandLen "6"]
+/// [Seed "42"]
+/// [FirstPlayer "1"]
+/// [TileLimit "10000"]
+/// [CoordinateLimit "10000"]
+/// [HolesLimit "100"]
+/// [FullMatchBonus "6"]
+/// [LastPlayBonus "6"]
+/// [Points "4 2"]
+///
+/// 1. play 0@0,0 3@1,0 +2
+/// 2. exchange 0 4
+/// 3. play 5@0,1 +2
+/// 4. pass
+/// ```
+///
+/// [Plays by value](Action::PlayTiles) and [exchanges by value](Action::ExchangeTiles) are
+/// written with [tiles](crate::Tile) in place of indexes, such as `play RCi@0,0 RSq@1,0 +2`
+/// and `exchange RCi BX`. [Reordering a hand](Action::ReorderHand) is written as the player
+/// followed by the permutation, such as `reorder 1 2 0 1 3 5 4`, and is numbered like any
+/// other [action](Action) even though it is not a turn.
+///
+/// The config tags are optional when parsing, where missing tags use the same defaults as
+/// [GameConfig::new].
+///
+/// # See Also
+///
+/// * [GameRecord::replay]
+/// * [FirstState::new_with_seed]
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameRecord {
+    /// The number of players in the game.
+    pub players_len: usize,
+    /// The number of copies of each [tile](crate::Tile) in the bag.
+    pub unique_tile_copied_count: usize,
+    /// The number of [tiles](crate::Tile) each player will have in their hand.
+    pub hand_len: usize,
+    /// Seeds the [game rng](crate::GameRng) carried by the game state, which is given by
+    /// [FirstState::seed].
+    pub seed: u64,
+    /// The index of the player who [plays](FirstState::first_play) first.
+    pub first_player: usize,
+    /// Limits and bonuses of the game.
+    pub config: GameConfig,
+    /// Every [action](Action) in the order they were [applied](Game::apply).
+    pub actions: Vec<RecordedAction>,
+    /// A vector of points for each player after every [action](Action).
+    pub points: Points,
+}
+
+/// An [action](Action) in a [game record](GameRecord) and the points it earned.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordedAction {
+    /// Describes how the current player advanced the game.
+    pub action: Action,
+    /// The [points](ScoreBreakdown::points) earned by [a play](Action::Play) or
+    /// [a play by value](Action::PlayTiles), or [None] for any other [action](Action).
+    pub points: Option<usize>,
+}
+
+/// Describes the reason why a [game record](GameRecord) could not be parsed. Each `line`
+/// starts from `1`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RecordError {
+    /// Attempting to parse a tag which is not a name and a quoted value in brackets.
+    Tag {
+        /// The line of the tag.
+        line: usize,
+    },
+    /// Attempting to parse a tag with an unknown name.
+    UnknownTag {
+        /// The unknown name.
+        tag: String,
+        /// The line of the tag.
+        line: usize,
+    },
+    /// Attempting to parse a tag which was already parsed.
+    DuplicateTag {
+        /// The repeated name.
+        tag: String,
+        /// The line of the tag.
+        line: usize,
+    },
+    /// Attempting to parse a record without a required tag.
+    MissingTag {
+        /// The missing name.
+        tag: String,
+    },
+    /// Attempting to parse a tag whose value is not a number or a list of numbers.
+    Value {
+        /// The name of the tag.
+        tag: String,
+        /// The bad value.
+        value: String,
+        /// The line of the tag.
+        line: usize,
+    },
+    /// Attempting to parse config tags which are rejected by [GameConfig::new].
+    Config(HashSet<ConfigError>),
+    /// Attempting to parse a turn number which does not continue from the previous turn.
+    Turn {
+        /// The bad turn number.
+        token: String,
+        /// The line of the turn.
+        line: usize,
+    },
+    /// Attempting to parse an [action](Action) which is not `play`, `exchange`, `pass`, or
+    /// `reorder` followed by a player.
+    Action {
+        /// The bad [action](Action).
+        token: String,
+        /// The line of the turn.
+        line: usize,
+    },
+    /// Attempting to parse [a play](Action::Play) without points such as `+2` at the end.
+    Points {
+        /// The bad points.
+        token: String,
+        /// The line of the turn.
+        line: usize,
+    },
+    /// Attempting to parse [plays](Plays), [exchanges](Exchanges),
+    /// [tile plays](TilePlays) or [tile exchanges](TileExchanges) in bad
+    /// [notation](Notation). The position in `error` is relative to the first token after
+    /// the [action](Action).
+    Notation {
+        /// The reason the [notation](Notation) could not be parsed.
+        error: NotationError,
+        /// The line of the turn.
+        line: usize,
+    },
+}
+
+/// Describes the reason why a [game record](GameRecord) could not be
+/// [replayed](GameRecord::replay). Each `turn` starts from `1`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReplayError {
+    /// Attempting to replay a [game record](GameRecord) whose setup parameters are rejected by
+    /// [FirstState::new_with_seed].
+    New(HashSet<NewError>),
+    /// Attempting to replay an [action](Action) which could not be [applied](Game::apply).
+    Action {
+        /// The turn of the [action](Action).
+        turn: usize,
+        /// The reason the [action](Action) could not be [applied](Game::apply).
+        error: ActionError,
+    },
+    /// Attempting to replay an [action](Action) which earned different points than recorded.
+    Points {
+        /// The turn of the [action](Action).
+        turn: usize,
+        /// The recorded points.
+        recorded: Option<usize>,
+        /// The points earned while replaying.
+        actual: Option<usize>,
+    },
+    /// Attempting to replay a [game record](GameRecord) which ends with different points than
+    /// recorded.
+    FinalPoints {
+        /// The recorded points for each player.
+        recorded: Points,
+        /// The points for each player after replaying.
+        actual: Points,
+    },
+}
+
+const PLAYERS: &str = "Players";
+const UNIQUE_TILE_COPIED_COUNT: &str = "UniqueTileCopiedCount";
+const HAND_LEN: &str = "HandLen";
+const SEED: &str = "Seed";
+const FIRST_PLAYER: &str = "FirstPlayer";
+const TILE_LIMIT: &str = "TileLimit";
+const COORDINATE_LIMIT: &str = "CoordinateLimit";
+const HOLES_LIMIT: &str = "HolesLimit";
+const FULL_MATCH_BONUS: &str = "FullMatchBonus";
+const LAST_PLAY_BONUS: &str = "LastPlayBonus";
+const POINTS: &str = "Points";
+
+impl GameRecord {
+    /// Creates the [first state](FirstState) from the setup parameters, and then
+    /// [applies](Game::apply) every [action](Action) while checking that each [action](Action)
+    /// and the whole game earned the recorded points.
+    ///
+    /// # Errors
+    ///
+    /// * [ReplayError::New] Attempting to replay a [game record](GameRecord) whose setup
+    /// parameters are rejected by [FirstState::new_with_seed].
+    /// * [ReplayError::Action] Attempting to replay an [action](Action) which could not be
+    /// [applied](Game::apply).
+    /// * [ReplayError::Points] Attempting to replay an [action](Action) which earned different
+    /// points than recorded.
+    /// * [ReplayError::FinalPoints] Attempting to replay a [game record](GameRecord) which ends
+    /// with different points than recorded.
+    ///
+    /// # Returns
+    ///
+    /// Every intermediate [NextState] in the order they were reached and the [game](Game)
+    /// after the last [action](Action).
+    pub fn replay(&self) -> Result<(Vec<NextState>, Game), ReplayError> {
+        let first_state = FirstState::new_with_seed(
+            self.players_len,
+            Some(self.unique_tile_copied_count),
+            Some(self.hand_len),
+            Some(self.config),
+            |_| self.first_player,
+            self.seed,
+        )
+        .map_err(ReplayError::New)?;
+
+        let mut game = Game::from(first_state);
+        let mut next_states = Vec::with_capacity(self.actions.len());
+        for (turn, recorded_action) in (1..).zip(&self.actions) {
+            let (next_game, score_breakdown) = game
+                .apply(&recorded_action.action)
+                .map_err(|(_, error)| ReplayError::Action { turn, error })?;
+
+            let actual = score_breakdown.as_ref().map(ScoreBreakdown::points);
+            if actual != recorded_action.points {
+                return Err(ReplayError::Points {
+                    turn,
+                    recorded: recorded_action.points,
+                    actual,
+                });
+            }
+
+            if let Game::Next(next_state) = &next_game {
+                next_states.push(next_state.clone());
+            }
+            game = next_game;
+        }
+
+        let actual = match &game {
+            Game::First(_) => (0..self.players_len).map(|_| 0).collect(),
+            Game::Next(next_state) => next_state.next_view().points.clone(),
+            Game::Last(last_state) => last_state.last_view().points.clone(),
+        };
+        if actual != self.points {
+            return Err(ReplayError::FinalPoints {
+                recorded: self.points.clone(),
+                actual,
+            });
+        }
+
+        Ok((next_states, game))
+    }
+}
+
+impl Display for GameRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "[{PLAYERS} \"{}\"]", self.players_len)?;
+        writeln!(
+            f,
+            "[{UNIQUE_TILE_COPIED_COUNT} \"{}\"]",
+            self.unique_tile_copied_count
+        )?;
+        writeln!(f, "[{HAND_LEN} \"{}\"]", self.hand_len)?;
+        writeln!(f, "[{SEED} \"{}\"]", self.seed)?;
+        writeln!(f, "[{FIRST_PLAYER} \"{}\"]", self.first_player)?;
+        writeln!(f, "[{TILE_LIMIT} \"{}\"]", self.config.tile_limit())?;
+        writeln!(
+            f,
+            "[{COORDINATE_LIMIT} \"{}\"]",
+            self.config.coordinate_limit()
+        )?;
+        writeln!(f, "[{HOLES_LIMIT} \"{}\"]", self.config.holes_limit())?;
+        writeln!(
+            f,
+            "[{FULL_MATCH_BONUS} \"{}\"]",
+            self.config.full_match_bonus()
+        )?;
+        writeln!(
+            f,
+            "[{LAST_PLAY_BONUS} \"{}\"]",
+            self.config.last_play_bonus()
+        )?;
+        writeln!(f, "[{POINTS} \"{}\"]", self.points.iter().join(" "))?;
+
+        for (turn, recorded_action) in (1..).zip(&self.actions) {
+            writeln!(f)?;
+            write!(f, "{turn}. ")?;
+            match &recorded_action.action {
+                Action::Play(plays) => write!(f, "play {}", Notation(plays.clone()))?,
+                Action::Exchange(exchanges) => {
+                    write!(f, "exchange {}", Notation(exchanges.clone()))?
+                }
+                Action::PlayTiles(tile_plays) => {
+                    write!(f, "play {}", Notation(tile_plays.clone()))?
+                }
+                Action::ExchangeTiles(tile_exchanges) => {
+                    write!(f, "exchange {}", Notation(tile_exchanges.clone()))?
+                }
+                Action::Pass => write!(f, "pass")?,
+                Action::ReorderHand {
+                    player,
+                    permutation,
+                } => write!(f, "reorder {player} {}", Notation(permutation.clone()))?,
+            }
+            if let Some(points) = recorded_action.points {
+                write!(f, " +{points}")?;
+            }
+        }
+        writeln!(f)
+    }
+}
+
+impl FromStr for GameRecord {
+    type Err = RecordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tags = Vec::<(&str, &str, usize)>::new();
+        let mut actions = Vec::new();
+
+        for (line, text) in (1..).zip(s.lines()) {
+            let text = text.trim();
+            if text.is_empty() {
+                continue;
+            }
+
+            if text.starts_with('[') {
+                let (tag, value) = text
+                    .strip_prefix('[')
+                    .and_then(|text| text.strip_suffix(']'))
+                    .and_then(|text| text.split_once(' '))
+                    .and_then(|(tag, value)| {
+                        Some((tag, value.strip_prefix('"')?.strip_suffix('"')?))
+                    })
+                    .ok_or(RecordError::Tag { line })?;
+                if tags.iter().any(|&(other, _, _)| other == tag) {
+                    return Err(RecordError::DuplicateTag {
+                        tag: tag.to_owned(),
+                        line,
+                    });
+                }
+                tags.push((tag, value, line));
+                continue;
+            }
+
+            actions.push(parse_recorded_action(text, line, actions.len() + 1)?);
+        }
+
+        for &(tag, _, line) in &tags {
+            if ![
+                PLAYERS,
+                UNIQUE_TILE_COPIED_COUNT,
+                HAND_LEN,
+                SEED,
+                FIRST_PLAYER,
+                TILE_LIMIT,
+                COORDINATE_LIMIT,
+                HOLES_LIMIT,
+                FULL_MATCH_BONUS,
+                LAST_PLAY_BONUS,
+                POINTS,
+            ]
+            .contains(&tag)
+            {
+                return Err(RecordError::UnknownTag {
+                    tag: tag.to_owned(),
+                    line,
+                });
+            }
+        }
+
+        fn parse_tag<T: FromStr>(
+            tags: &[(&str, &str, usize)],
+            tag: &str,
+        ) -> Result<Option<T>, RecordError> {
+            let Some(&(_, value, line)) = tags.iter().find(|&&(other, _, _)| other == tag) else {
+                return Ok(None);
+            };
+            value.parse().map(Some).map_err(|_| RecordError::Value {
+                tag: tag.to_owned(),
+                value: value.to_owned(),
+                line,
+            })
+        }
+
+        fn require<T>(value: Option<T>, tag: &str) -> Result<T, RecordError> {
+            value.ok_or_else(|| RecordError::MissingTag {
+                tag: tag.to_owned(),
+            })
+        }
+
+        let config = GameConfig::new(
+            parse_tag(&tags, TILE_LIMIT)?,
+            parse_tag(&tags, COORDINATE_LIMIT)?,
+            parse_tag(&tags, HOLES_LIMIT)?,
+            parse_tag(&tags, FULL_MATCH_BONUS)?,
+            parse_tag(&tags, LAST_PLAY_BONUS)?,
+        )
+        .map_err(RecordError::Config)?;
+
+        let points = require(parse_tag::<String>(&tags, POINTS)?, POINTS)?;
+        let points = points
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Points, _>>()
+            .map_err(|_| {
+                let line = tags
+                    .iter()
+                    .find(|&&(tag, _, _)| tag == POINTS)
+                    .map_or(0, |&(_, _, line)| line);
+                RecordError::Value {
+                    tag: POINTS.to_owned(),
+                    value: points.clone(),
+                    line,
+                }
+            })?;
+
+        Ok(GameRecord {
+            players_len: require(parse_tag(&tags, PLAYERS)?, PLAYERS)?,
+            unique_tile_copied_count: require(
+                parse_tag(&tags, UNIQUE_TILE_COPIED_COUNT)?,
+                UNIQUE_TILE_COPIED_COUNT,
+            )?,
+            hand_len: require(parse_tag(&tags, HAND_LEN)?, HAND_LEN)?,
+            seed: require(parse_tag(&tags, SEED)?, SEED)?,
+            first_player: require(parse_tag(&tags, FIRST_PLAYER)?, FIRST_PLAYER)?,
+            config,
+            actions,
+            points,
+        })
+    }
+}
+
+/// Parses a single turn such as `3. play 5@0,1 +2`.
+///
+/// # Arguments
+///
+/// * `text`: The turn without surrounding whitespace.
+/// * `line`: The line of the turn.
+/// * `turn`: The expected turn number.
+fn parse_recorded_action(
+    text: &str,
+    line: usize,
+    turn: usize,
+) -> Result<RecordedAction, RecordError> {
+    let (turn_token, text) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+    if turn_token != format!("{turn}.") {
+        return Err(RecordError::Turn {
+            token: turn_token.to_owned(),
+            line,
+        });
+    }
+
+    let text = text.trim_start();
+    let (action_token, text) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+    let text = text.trim();
+    let notation_error = |error| RecordError::Notation { error, line };
+    match action_token {
+        "play" => {
+            let (plays, points_token) = text.rsplit_once(char::is_whitespace).unwrap_or(("", text));
+            let points = points_token
+                .strip_prefix('+')
+                .and_then(|points| points.parse().ok())
+                .ok_or_else(|| RecordError::Points {
+                    token: points_token.to_owned(),
+                    line,
+                })?;
+            let action = if by_value(plays) {
+                let Notation(tile_plays) = plays
+                    .parse::<Notation<TilePlays>>()
+                    .map_err(notation_error)?;
+                Action::PlayTiles(tile_plays)
+            } else {
+                let Notation(plays) = plays.parse::<Notation<Plays>>().map_err(notation_error)?;
+                Action::Play(plays)
+            };
+            Ok(RecordedAction {
+                action,
+                points: Some(points),
+            })
+        }
+        "exchange" => {
+            let action = if by_value(text) {
+                let Notation(tile_exchanges) = text
+                    .parse::<Notation<TileExchanges>>()
+                    .map_err(notation_error)?;
+                Action::ExchangeTiles(tile_exchanges)
+            } else {
+                let Notation(exchanges) = text
+                    .parse::<Notation<Exchanges>>()
+                    .map_err(notation_error)?;
+                Action::Exchange(exchanges)
+            };
+            Ok(RecordedAction {
+                action,
+                points: None,
+            })
+        }
+        "pass" if text.is_empty() => Ok(RecordedAction {
+            action: Action::Pass,
+            points: None,
+        }),
+        "reorder" if !text.is_empty() => {
+            let Notation(mut permutation) = text
+                .parse::<Notation<Vec<usize>>>()
+                .map_err(notation_error)?;
+            let player = permutation.remove(0);
+            Ok(RecordedAction {
+                action: Action::ReorderHand {
+                    player,
+                    permutation,
+                },
+                points: None,
+            })
+        }
+        _ => Err(RecordError::Action {
+            token: action_token.to_owned(),
+            line,
+        }),
+    }
+}
+
+/// Whether [plays](Plays) or [exchanges](Exchanges) are given by [tile](crate::Tile) instead of
+/// by index, since every [color](crate::Color) code is a letter and every index is a digit.
+fn by_value(text: &str) -> bool {
+    text.starts_with(|c: char| c.is_ascii_alphabetic())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use map_macro::btree_set;
+    use smallvec::smallvec;
+
+    /// Plays a whole game from `seed` where every player makes the first
+    /// [legal play](NextState::legal_plays) with the most points, or else exchanges their first
+    /// [tile](crate::Tile), or else passes. Every player exchanges their first [tile](crate::Tile)
+    /// instead of playing for the first `exchanges` turns after the first turn. When `reorders`
+    /// is true, every player also reverses their hand before each turn after the first turn.
+    fn record_game(seed: u64, exchanges: usize, reorders: bool) -> GameRecord {
+        let first_state =
+            FirstState::new_random_first_player_with_seed(2, Some(1), None, None, seed)
+                .expect("new_random_first_player_with_seed should return Ok");
+        let mut record = GameRecord {
+            players_len: 2,
+            unique_tile_copied_count: 1,
+            hand_len: crate::DEFAULT_HAND_LEN,
+            seed: first_state.seed(),
+            first_player: first_state.current_player(),
+            config: GameConfig::default(),
+            actions: Vec::new(),
+            points: Points::new(),
+        };
+
+        let mut game = Game::from(first_state);
+        while !game.has_ended() {
+            let action = match &game {
+                Game::First(first_state) => {
+                    let first_view = first_state.first_view();
+                    let max_match = first_view.max_matches[first_view.current_player];
+                    (0..first_view.hand_lens[first_view.current_player])
+                        .combinations(max_match)
+                        .map(|indexes| (0..).zip(indexes).map(|(x, index)| (index, (x, 0))))
+                        .map(|plays| plays.collect())
+                        .find(|plays| first_state.preview_play(plays).is_ok())
+                        .map(Action::Play)
+                        .expect("some first play should be legal")
+                }
+                Game::Next(next_state)
+                    if reorders
+                        && !matches!(
+                            record
+                                .actions
+                                .last()
+                                .map(|recorded_action| &recorded_action.action),
+                            Some(Action::ReorderHand { .. })
+                        ) =>
+                {
+                    let player = next_state.current_player();
+                    Action::ReorderHand {
+                        player,
+                        permutation: (0..next_state.next_view().hand_lens[player])
+                            .rev()
+                            .collect(),
+                    }
+                }
+                Game::Next(next_state)
+                    if record.actions.len() <= exchanges && next_state.next_view().bag_len > 0 =>
+                {
+                    Action::Exchange(btree_set! { 0 })
+                }
+                Game::Next(next_state) => next_state
+                    .legal_plays()
+                    .into_iter()
+                    .max_by_key(|(_, score_breakdown)| score_breakdown.points())
+                    .map(|(plays, _)| Action::Play(plays))
+                    .unwrap_or_else(|| {
+                        if next_state.next_view().bag_len > 0 {
+                            Action::Exchange(btree_set! { 0 })
+                        } else {
+                            Action::Pass
+                        }
+                    }),
+                Game::Last(_) => unreachable!("game should not have ended"),
+            };
+
+            let (next_game, score_breakdown) = game.apply(&action).expect("apply should return Ok");
+            record.actions.push(RecordedAction {
+                action,
+                points: score_breakdown.as_ref().map(ScoreBreakdown::points),
+            });
+            game = next_game;
+        }
+
+        let Game::Last(last_state) = &game else {
+            unreachable!("game should have ended");
+        };
+        record.points = last_state.last_view().points.clone();
+        record
+    }
+
+    #[test]
+    fn write_and_parse() {
+        let record = record_game(7, 0, false);
+
+        let text = record.to_string();
+
+        assert!(text.starts_with("[Players \"2\"]\n"));
+        assert!(text.contains("\n1. play "));
+        assert_eq!(Ok(record), text.parse());
+    }
+
+    #[test]
+    fn replay() {
+        let record = record_game(11, 0, false);
+
+        let (next_states, game) = record.replay().expect("replay should return Ok");
+
+        assert!(game.has_ended());
+        assert!(!next_states.is_empty());
+        assert!(next_states.len() <= record.actions.len());
+        assert_eq!(record.first_player, 1 - next_states[0].current_player());
+    }
+
+    #[test]
+    fn replay_exchanges() {
+        for seed in 0..8 {
+            let record = record_game(seed, 6, false);
+
+            assert!(record.actions[1..=6]
+                .iter()
+                .all(|recorded_action| matches!(recorded_action.action, Action::Exchange(_))));
+            assert!(record.replay().is_ok());
+        }
+    }
+
+    #[test]
+    fn write_and_parse_by_value() {
+        let mut record = record_game(23, 2, false);
+        let mut game = Game::from(
+            FirstState::new_with_seed(2, Some(1), None, None, |_| record.first_player, record.seed)
+                .expect("new_with_seed should return Ok"),
+        );
+        for recorded_action in &mut record.actions {
+            let hand = game
+                .current_player()
+                .and_then(|player| game.get_hand(player))
+                .expect("game should not have ended");
+            let action = match &recorded_action.action {
+                Action::Play(plays) => Action::PlayTiles(
+                    plays
+                        .iter()
+                        .map(|(&index, &coordinate)| (coordinate, hand[index]))
+                        .collect(),
+                ),
+                Action::Exchange(exchanges) => {
+                    Action::ExchangeTiles(exchanges.iter().map(|&index| hand[index]).collect())
+                }
+                action => action.clone(),
+            };
+            game = game.apply(&action).expect("apply should return Ok").0;
+            recorded_action.action = action;
+        }
+
+        let text = record.to_string();
+
+        assert!(matches!(record.actions[0].action, Action::PlayTiles(_)));
+        assert!(matches!(record.actions[1].action, Action::ExchangeTiles(_)));
+        assert_eq!(Ok(record.clone()), text.parse());
+        assert!(record.replay().is_ok());
+    }
+
+    #[test]
+    fn write_and_parse_reorders() {
+        let record = record_game(29, 0, true);
+
+        let text = record.to_string();
+
+        assert!(text.contains("\n2. reorder "));
+        assert_eq!(Ok(record.clone()), text.parse());
+        let (_, game) = record.replay().expect("replay should return Ok");
+        assert!(game.has_ended());
+    }
+
+    #[test]
+    fn replay_points() {
+        let mut record = record_game(13, 0, false);
+        let actual = record.actions[0].points;
+        record.actions[0].points = actual.map(|points| points + 1);
+
+        assert_eq!(
+            Err(ReplayError::Points {
+                turn: 1,
+                recorded: record.actions[0].points,
+                actual,
+            }),
+            record.replay().map(|_| ())
+        );
+    }
+
+    #[test]
+    fn replay_final_points() {
+        let mut record = record_game(17, 0, false);
+        let actual = record.points.clone();
+        record.points[0] += 1;
+
+        assert_eq!(
+            Err(ReplayError::FinalPoints {
+                recorded: record.points.clone(),
+                actual,
+            }),
+            record.replay().map(|_| ())
+        );
+    }
+
+    #[test]
+    fn replay_action() {
+        let mut record = record_game(19, 0, false);
+        record.actions.insert(
+            0,
+            RecordedAction {
+                action: Action::Pass,
+                points: None,
+            },
+        );
+
+        assert_eq!(
+            Err(ReplayError::Action {
+                turn: 1,
+                error: ActionError::FirstPass,
+            }),
+            record.replay().map(|_| ())
+        );
+    }
+
+    #[test]
+    fn replay_new() {
+        let record = GameRecord {
+            players_len: 0,
+            unique_tile_copied_count: 1,
+            hand_len: 1,
+            seed: 0,
+            first_player: 0,
+            config: GameConfig::default(),
+            actions: Vec::new(),
+            points: smallvec![],
+        };
+
+        assert!(matches!(record.replay(), Err(ReplayError::New(_))));
+    }
+
+    #[test]
+    fn parse_defaults() {
+        let text = "[Players \"2\"]\n[UniqueTileCopiedCount \"3\"]\n[HandLen \"6\"]\n\
+            [Seed \"5\"]\n[FirstPlayer \"0\"]\n[Points \"0 0\"]\n\n1. pass\n";
+
+        let record: GameRecord = text.parse().expect("parse should return Ok");
+
+        assert_eq!(GameConfig::default(), record.config);
+        assert_eq!(
+            vec![RecordedAction {
+                action: Action::Pass,
+                points: None,
+            }],
+            record.actions
+        );
+        assert_eq!(Points::from_slice(&[0, 0]), record.points);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let tags = "[Players \"2\"]\n[UniqueTileCopiedCount \"3\"]\n[HandLen \"6\"]\n\
+            [Seed \"5\"]\n[FirstPlayer \"0\"]\n[Points \"0 0\"]\n";
+        let parse = |turns: &str| format!("{tags}{turns}").parse::<GameRecord>();
+
+        assert_eq!(
+            Err(RecordError::MissingTag {
+                tag: POINTS.to_owned(),
+            }),
+            tags.replace("[Points \"0 0\"]\n", "").parse::<GameRecord>()
+        );
+        assert_eq!(Err(RecordError::Tag { line: 7 }), parse("[Event]\n"));
+        assert_eq!(
+            Err(RecordError::UnknownTag {
+                tag: "Event".to_owned(),
+                line: 7,
+            }),
+            parse("[Event \"casual\"]\n")
+        );
+        assert_eq!(
+            Err(RecordError::DuplicateTag {
+                tag: SEED.to_owned(),
+                line: 7,
+            }),
+            parse("[Seed \"6\"]\n")
+        );
+        assert_eq!(
+            Err(RecordError::Value {
+                tag: SEED.to_owned(),
+                value: "-1".to_owned(),
+                line: 4,
+            }),
+            tags.replace("\"5\"", "\"-1\"").parse::<GameRecord>()
+        );
+        assert_eq!(
+            Err(RecordError::Turn {
+                token: "2.".to_owned(),
+                line: 8,
+            }),
+            parse("\n2. pass\n")
+        );
+        assert_eq!(
+            Err(RecordError::Action {
+                token: "resign".to_owned(),
+                line: 7,
+            }),
+            parse("1. resign\n")
+        );
+        assert_eq!(
+            Err(RecordError::Points {
+                token: "0@0,0".to_owned(),
+                line: 7,
+            }),
+            parse("1. play 0@0,0\n")
+        );
+        assert_eq!(
+            Err(RecordError::Notation {
+                error: NotationError::Separator {
+                    token: "1".to_owned(),
+                    position: 6,
+                },
+                line: 7,
+            }),
+            parse("1. play 0@0,0 1 +2\n")
+        );
+        assert_eq!(
+            Err(RecordError::Action {
+                token: "reorder".to_owned(),
+                line: 7,
+            }),
+            parse("1. reorder\n")
+        );
+        assert_eq!(
+            Err(RecordError::Notation {
+                error: NotationError::Index {
+                    token: "-1".to_owned(),
+                    position: 4,
+                },
+                line: 7,
+            }),
+            parse("1. reorder 0 1 -1\n")
+        );
+    }
+}
@@ -0,0 +1,342 @@
+use crate::{
+    find_component_minimums_and_maximums, Board, Color, Coordinate, GameConfig, Notation, Plays,
+    Shape, Tile,
+};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt::Write;
+
+/// How [tiles](Tile) are drawn by [render_board].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum RenderStyle {
+    /// Draws each [shape](Shape) as a unicode glyph in its [color](Color) with ANSI escape codes.
+    Ansi,
+    /// Draws each [tile](Tile) in [notation](Notation) such as `RCi` without escape codes.
+    Ascii,
+}
+
+/// Describes the reason why the board could not be [rendered](render_board).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RenderError {
+    /// Attempting [to render](render_board) pending [plays](Plays) at already occupied
+    /// [coordinates](Coordinate) on the board.
+    CoordinatesOccupied {
+        /// [Plays](Plays) where board already contains a [tile](Tile)
+        /// at the [coordinate](Coordinate).
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::plays"))]
+        coordinates_occupied: Plays,
+    },
+    /// Attempting [to render](render_board) [tiles](Tile) too far away from the center of
+    /// the board.
+    CoordinatesOutOfBounds {
+        /// [Coordinates](Coordinate) where the absolute value of a component is greater than
+        /// or equal to the [coordinate limit](GameConfig::coordinate_limit).
+        coordinates_out_of_bounds: BTreeSet<Coordinate>,
+    },
+    /// Attempting [to render](render_board) a grid with more cells than
+    /// the [tile limit](GameConfig::tile_limit).
+    TooLarge {
+        /// The number of columns in the grid.
+        width: usize,
+        /// The number of rows in the grid.
+        height: usize,
+    },
+}
+
+/// Draws the board as a grid with the x axis labelled above and the y axis labelled on
+/// the left where y increases downward. The grid covers every [coordinate](Coordinate)
+/// between the minimum and maximum components of the board and `pending` [plays](Plays)
+/// including negative components. An empty board is drawn as the origin.
+///
+/// # Arguments
+///
+/// * `board`: A map of [coordinates](Coordinate) to [tiles](Tile) that have
+/// been played.
+/// * `pending`: Some [plays](Plays) and the hand they index, which are drawn in brackets and
+/// listed below the grid, or [None].
+/// * `style`: Whether to draw [tiles](Tile) with ANSI escape codes or plain ASCII.
+/// * `config`: Limits of the game, which also limit the size of the grid.
+///
+/// # Errors
+///
+/// * [RenderError::CoordinatesOccupied] Attempting [to render](render_board) pending
+/// [plays](Plays) at already occupied [coordinates](Coordinate) on the board.
+/// * [RenderError::CoordinatesOutOfBounds] Attempting [to render](render_board)
+/// [tiles](Tile) too far away from the center of the board.
+/// * [RenderError::TooLarge] Attempting [to render](render_board) a grid with more cells
+/// than the [tile limit](GameConfig::tile_limit).
+///
+/// # Returns
+///
+/// The grid where each line ends with `\n`. If some pending index is out of bounds of
+/// the hand, that [play](Plays) is skipped.
+///
+/// # See Also
+///
+/// * [RenderStyle]
+/// * [Notation]
+pub fn render_board(
+    board: &Board,
+    pending: Option<(&Plays, &[Tile])>,
+    style: RenderStyle,
+    config: &GameConfig,
+) -> Result<String, HashSet<RenderError>> {
+    let mut errors = HashSet::with_capacity(3);
+    let coordinates_occupied: Plays = pending
+        .into_iter()
+        .flat_map(|(plays, _)| plays.iter())
+        .filter(|(_, coordinate)| board.contains_key(coordinate))
+        .map(|(&index, &coordinate)| (index, coordinate))
+        .collect();
+    if !coordinates_occupied.is_empty() {
+        errors.insert(RenderError::CoordinatesOccupied {
+            coordinates_occupied,
+        });
+    }
+
+    let pending_board: HashMap<_, _> = pending
+        .into_iter()
+        .flat_map(|(plays, hand)| {
+            plays
+                .iter()
+                .filter_map(|(&index, &coordinate)| Some((coordinate, *hand.get(index)?)))
+        })
+        .collect();
+
+    let coordinate_limit = config.coordinate_limit();
+    let coordinates_out_of_bounds: BTreeSet<Coordinate> = board
+        .keys()
+        .chain(pending_board.keys())
+        .copied()
+        .filter(|&(x, y)| {
+            x <= -coordinate_limit
+                || coordinate_limit <= x
+                || y <= -coordinate_limit
+                || coordinate_limit <= y
+        })
+        .collect();
+    if !coordinates_out_of_bounds.is_empty() {
+        errors.insert(RenderError::CoordinatesOutOfBounds {
+            coordinates_out_of_bounds,
+        });
+        return Err(errors);
+    }
+
+    let (min_x, min_y, max_x, max_y) =
+        find_component_minimums_and_maximums(board.keys().chain(pending_board.keys()).copied())
+            .unwrap_or((0, 0, 0, 0));
+    let width = max_x.abs_diff(min_x) + 1;
+    let height = max_y.abs_diff(min_y) + 1;
+    if width.saturating_mul(height) > config.tile_limit() {
+        errors.insert(RenderError::TooLarge { width, height });
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let label_width = |min: isize, max: isize| min.to_string().len().max(max.to_string().len());
+    let y_width = label_width(min_y, max_y);
+    let content_width = match style {
+        RenderStyle::Ansi => 3,
+        RenderStyle::Ascii => 5,
+    };
+    let cell_width = content_width.max(label_width(min_x, max_x) + 1);
+
+    let mut rendered = String::new();
+    let mut line = format!("{:y_width$} ", "");
+    for x in min_x..=max_x {
+        write!(line, "{x:^cell_width$}").expect("write to String should succeed");
+    }
+    push_line(&mut rendered, &line);
+
+    for y in min_y..=max_y {
+        let mut line = format!("{y:>y_width$} ");
+        for x in min_x..=max_x {
+            let cell = match (pending_board.get(&(x, y)), board.get(&(x, y))) {
+                (Some(&tile), _) => format!("[{}]", render_tile(tile, style)),
+                (None, Some(&tile)) => format!(" {} ", render_tile(tile, style)),
+                (None, None) => format!(" {:^1$} ", ".", content_width - 2),
+            };
+            // padding is computed from the visible width since escape codes take no space
+            let padding = cell_width - content_width;
+            let left = padding / 2;
+            write!(line, "{:left$}{cell}{:2$}", "", "", padding - left)
+                .expect("write to String should succeed");
+        }
+        push_line(&mut rendered, &line);
+    }
+
+    if let Some((plays, hand)) = pending {
+        let mut line = "hand:".to_owned();
+        for (index, &tile) in hand.iter().enumerate() {
+            let tile = render_tile(tile, style);
+            if plays.contains_left(&index) {
+                write!(line, " {index}:[{tile}]")
+            } else {
+                write!(line, " {index}:{tile}")
+            }
+            .expect("write to String should succeed");
+        }
+        push_line(&mut rendered, &line);
+    }
+
+    Ok(rendered)
+}
+
+/// Appends `line` without trailing spaces and a newline.
+fn push_line(rendered: &mut String, line: &str) {
+    rendered.push_str(line.trim_end());
+    rendered.push('\n');
+}
+
+/// Draws a single [tile](Tile) which is one character wide in ANSI and three characters wide
+/// in ASCII.
+fn render_tile(tile: Tile, style: RenderStyle) -> String {
+    let (color, shape) = tile;
+    match style {
+        RenderStyle::Ansi => {
+            let code = match color {
+                Color::Red => "31",
+                Color::Orange => "38;5;208",
+                Color::Yellow => "33",
+                Color::Green => "32",
+                Color::Blue => "34",
+                Color::Purple => "35",
+            };
+            let glyph = match shape {
+                Shape::Circle => '●',
+                Shape::Clover => '♣',
+                Shape::Diamond => '◆',
+                Shape::Square => '■',
+                Shape::Starburst => '✸',
+                Shape::X => '✖',
+            };
+            format!("\x1b[{code}m{glyph}\x1b[0m")
+        }
+        RenderStyle::Ascii => format!("{:<3}", Notation(tile).to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use map_macro::{hash_map, hash_set};
+
+    #[test]
+    fn render_board_empty_board() {
+        assert_eq!(
+            "    0\n0   .\n",
+            render_board(
+                &Board::new(),
+                None,
+                RenderStyle::Ascii,
+                &GameConfig::default()
+            )
+            .expect("render_board should return Ok")
+        );
+    }
+
+    #[test]
+    fn render_board_negative_coordinates() {
+        let board = hash_map! {
+            (-1, 0) => (Color::Red, Shape::Circle),
+            (0, 0) => (Color::Red, Shape::X),
+            (0, -1) => (Color::Blue, Shape::X),
+        };
+
+        let expected = ["    -1    0", "-1   .   BX", " 0  RCi  RX", ""];
+
+        assert_eq!(
+            expected.join("\n"),
+            render_board(&board, None, RenderStyle::Ascii, &GameConfig::default())
+                .expect("render_board should return Ok")
+        );
+    }
+
+    #[test]
+    fn render_board_pending_plays() {
+        let board = hash_map! { (0, 0) => (Color::Red, Shape::Circle) };
+        let plays = [(1, (1, 0)), (5, (2, 0))].into_iter().collect();
+        let hand = [(Color::Green, Shape::Circle), (Color::Red, Shape::Square)];
+
+        let expected = ["    0    1", "0  RCi [RSq]", "hand: 0:GCi 1:[RSq]", ""];
+
+        assert_eq!(
+            expected.join("\n"),
+            render_board(
+                &board,
+                Some((&plays, &hand)),
+                RenderStyle::Ascii,
+                &GameConfig::default()
+            )
+            .expect("render_board should return Ok")
+        );
+    }
+
+    #[test]
+    fn render_board_ansi() {
+        let board = hash_map! { (0, 0) => (Color::Orange, Shape::Diamond) };
+        let plays = [(0, (0, 1))].into_iter().collect();
+        let hand = [(Color::Purple, Shape::Starburst)];
+
+        let expected = [
+            "   0",
+            "0  \x1b[38;5;208m◆\x1b[0m",
+            "1 [\x1b[35m✸\x1b[0m]",
+            "hand: 0:[\x1b[35m✸\x1b[0m]",
+            "",
+        ];
+
+        assert_eq!(
+            expected.join("\n"),
+            render_board(
+                &board,
+                Some((&plays, &hand)),
+                RenderStyle::Ansi,
+                &GameConfig::default()
+            )
+            .expect("render_board should return Ok")
+        );
+    }
+
+    #[test]
+    fn render_board_errors() {
+        let board = hash_map! {
+            (0, 0) => (Color::Red, Shape::Circle),
+            (0, 1) => (Color::Red, Shape::Square),
+        };
+        let plays = [(0, (0, 1)), (1, (1, 0))].into_iter().collect();
+        let hand = [(Color::Green, Shape::Circle), (Color::Red, Shape::X)];
+        let config =
+            GameConfig::new(Some(108), Some(200), None, None, None).expect("new should return Ok");
+
+        assert_eq!(
+            Err(hash_set! { RenderError::CoordinatesOccupied {
+                coordinates_occupied: [(0, (0, 1))].into_iter().collect(),
+            }}),
+            render_board(&board, Some((&plays, &hand)), RenderStyle::Ascii, &config)
+        );
+
+        let far_board = hash_map! {
+            (0, 0) => (Color::Red, Shape::Circle),
+            (0, 199) => (Color::Red, Shape::Square),
+            (200, 0) => (Color::Red, Shape::X),
+        };
+        assert_eq!(
+            Err(hash_set! { RenderError::CoordinatesOutOfBounds {
+                coordinates_out_of_bounds: [(200, 0)].into_iter().collect(),
+            }}),
+            render_board(&far_board, None, RenderStyle::Ascii, &config)
+        );
+
+        let wide_board = hash_map! {
+            (0, 0) => (Color::Red, Shape::Circle),
+            (-50, 10) => (Color::Red, Shape::Square),
+        };
+        assert_eq!(
+            Err(hash_set! { RenderError::TooLarge { width: 51, height: 11 } }),
+            render_board(&wide_board, None, RenderStyle::Ascii, &config)
+        );
+    }
+}
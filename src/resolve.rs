@@ -0,0 +1,179 @@
+use crate::{Exchanges, Plays, Tile, TileExchanges, TilePlays};
+use itertools::Itertools;
+
+/// Resolves [tile plays](TilePlays) into [plays](Plays) against the current order of `hand`.
+/// Each [tile](Tile) is matched to the copy with the lowest index in `hand` which has not been
+/// matched yet, so playing two copies of a [tile](Tile) requires holding two copies.
+///
+/// # Arguments
+///
+/// * `hand`: A vector of [tiles](Tile) for one player.
+/// * `tile_plays`: A map of [coordinates](crate::Coordinate) on the board to [tiles](Tile)
+/// to be played.
+///
+/// # Errors
+///
+/// If some [tiles](Tile) are not held in `hand`, the [tile plays](TilePlays) of those
+/// [tiles](Tile) are returned.
+///
+/// # See Also
+///
+/// * [FirstState::first_play_tiles](crate::FirstState::first_play_tiles)
+/// * [NextState::next_play_tiles](crate::NextState::next_play_tiles)
+///
+/// # Returns
+///
+/// A bimap of indexes of [tiles](Tile) in `hand` to [coordinates](crate::Coordinate)
+/// on the board.
+pub fn resolve_plays(hand: &[Tile], tile_plays: &TilePlays) -> Result<Plays, TilePlays> {
+    let mut unmatched = hand.iter().copied().enumerate().collect_vec();
+    let mut plays = Plays::new();
+    let mut tiles_not_held = TilePlays::new();
+    for (&coordinate, &tile) in tile_plays {
+        match unmatched.iter().position(|&(_, held)| held == tile) {
+            Some(position) => {
+                let (index, _) = unmatched.remove(position);
+                plays.insert(index, coordinate);
+            }
+            None => {
+                tiles_not_held.insert(coordinate, tile);
+            }
+        }
+    }
+
+    if !tiles_not_held.is_empty() {
+        return Err(tiles_not_held);
+    }
+
+    Ok(plays)
+}
+
+/// Resolves [tile exchanges](TileExchanges) into [exchanges](Exchanges) against the current
+/// order of `hand`. Each [tile](Tile) is matched to the copy with the lowest index in `hand`
+/// which has not been matched yet, so exchanging two copies of a [tile](Tile) requires holding
+/// two copies.
+///
+/// # Arguments
+///
+/// * `hand`: A vector of [tiles](Tile) for one player.
+/// * `tile_exchanges`: A vector of [tiles](Tile) to be exchanged.
+///
+/// # Errors
+///
+/// If some [tiles](Tile) are not held in `hand`, each copy of those [tiles](Tile) which could
+/// not be matched is returned in the order given.
+///
+/// # See Also
+///
+/// * [NextState::next_exchange_tiles](crate::NextState::next_exchange_tiles)
+///
+/// # Returns
+///
+/// An ordered set of indexes of [tiles](Tile) in `hand` to be exchanged.
+pub fn resolve_exchanges(
+    hand: &[Tile],
+    tile_exchanges: &[Tile],
+) -> Result<Exchanges, TileExchanges> {
+    let mut unmatched = hand.iter().copied().enumerate().collect_vec();
+    let mut exchanges = Exchanges::new();
+    let mut tiles_not_held = TileExchanges::new();
+    for &tile in tile_exchanges {
+        match unmatched.iter().position(|&(_, held)| held == tile) {
+            Some(position) => {
+                let (index, _) = unmatched.remove(position);
+                exchanges.insert(index);
+            }
+            None => tiles_not_held.push(tile),
+        }
+    }
+
+    if !tiles_not_held.is_empty() {
+        return Err(tiles_not_held);
+    }
+
+    Ok(exchanges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color, Shape};
+    use map_macro::{btree_map, btree_set};
+
+    const RED_CIRCLE: Tile = (Color::Red, Shape::Circle);
+    const RED_SQUARE: Tile = (Color::Red, Shape::Square);
+    const BLUE_X: Tile = (Color::Blue, Shape::X);
+
+    #[test]
+    fn resolve_plays_by_tile() {
+        let hand = [RED_SQUARE, BLUE_X, RED_CIRCLE];
+        let tile_plays = btree_map! {
+            (0, 0) => RED_CIRCLE,
+            (1, 0) => RED_SQUARE,
+        };
+
+        let plays = resolve_plays(&hand, &tile_plays).expect("resolve_plays should return Ok");
+
+        assert_eq!(
+            [(0, (1, 0)), (2, (0, 0))].into_iter().collect::<Plays>(),
+            plays
+        );
+    }
+
+    #[test]
+    fn resolve_plays_copies() {
+        let hand = [RED_CIRCLE, BLUE_X, RED_CIRCLE];
+        let tile_plays = btree_map! {
+            (0, 0) => RED_CIRCLE,
+            (0, 1) => RED_CIRCLE,
+        };
+
+        let plays = resolve_plays(&hand, &tile_plays).expect("resolve_plays should return Ok");
+
+        assert_eq!(
+            [(0, (0, 0)), (2, (0, 1))].into_iter().collect::<Plays>(),
+            plays
+        );
+    }
+
+    #[test]
+    fn resolve_plays_tiles_not_held() {
+        let hand = [RED_CIRCLE, BLUE_X];
+        let tile_plays = btree_map! {
+            (0, 0) => RED_CIRCLE,
+            (0, 1) => RED_CIRCLE,
+            (0, 2) => RED_SQUARE,
+        };
+
+        let tiles_not_held =
+            resolve_plays(&hand, &tile_plays).expect_err("resolve_plays should return Err");
+
+        assert_eq!(
+            btree_map! {
+                (0, 1) => RED_CIRCLE,
+                (0, 2) => RED_SQUARE,
+            },
+            tiles_not_held
+        );
+    }
+
+    #[test]
+    fn resolve_exchanges_by_tile() {
+        let hand = [RED_CIRCLE, BLUE_X, RED_CIRCLE, RED_SQUARE];
+
+        let exchanges = resolve_exchanges(&hand, &[RED_SQUARE, RED_CIRCLE, RED_CIRCLE])
+            .expect("resolve_exchanges should return Ok");
+
+        assert_eq!(btree_set! { 0, 2, 3 }, exchanges);
+    }
+
+    #[test]
+    fn resolve_exchanges_tiles_not_held() {
+        let hand = [RED_CIRCLE, BLUE_X];
+
+        let tiles_not_held = resolve_exchanges(&hand, &[RED_CIRCLE, RED_SQUARE, RED_CIRCLE])
+            .expect_err("resolve_exchanges should return Err");
+
+        assert_eq!(vec![RED_SQUARE, RED_CIRCLE], tiles_not_held);
+    }
+}
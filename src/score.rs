@@ -0,0 +1,93 @@
+use crate::Coordinate;
+use std::collections::BTreeSet;
+
+/// The points scored from a single line of [tiles](crate::Tile) containing played
+/// [tiles](crate::Tile).
+///
+/// # See Also
+///
+/// * [check_line](crate::check_line)
+/// * [ScoreBreakdown]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LineScore {
+    /// The [coordinates](Coordinate) of every [tile](crate::Tile) in the line.
+    pub coordinates: BTreeSet<Coordinate>,
+    /// The number of [tiles](crate::Tile) in the line, which is the base number of points.
+    pub len: usize,
+    /// Whether the line contains either [every color](crate::Color::colors) or
+    /// [every shape](crate::Shape::shapes).
+    pub full_match: bool,
+    /// The extra points earned by a full match, which is `0` when the line is not a full match.
+    pub full_match_bonus: usize,
+}
+
+/// The points earned by a [play](crate::Plays) itemised by each scored line and
+/// the [last play bonus](crate::GameConfig::last_play_bonus).
+///
+/// # See Also
+///
+/// * [FirstState::first_play](crate::FirstState::first_play)
+/// * [NextState::next_play](crate::NextState::next_play)
+/// * [LineScore]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScoreBreakdown {
+    /// Every scored line in the order they were checked.
+    pub lines: Vec<LineScore>,
+    /// Whether the [play](crate::Plays) ended the game.
+    pub last_play: bool,
+    /// The extra points earned by ending the game, which is `0` when the game has not ended.
+    pub last_play_bonus: usize,
+}
+
+impl LineScore {
+    /// The total points scored from the line.
+    pub fn points(&self) -> usize {
+        self.len + self.full_match_bonus
+    }
+}
+
+impl ScoreBreakdown {
+    /// The total points earned by the [play](crate::Plays).
+    pub fn points(&self) -> usize {
+        self.lines.iter().map(LineScore::points).sum::<usize>() + self.last_play_bonus
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use map_macro::btree_set;
+
+    #[test]
+    fn points_empty() {
+        assert_eq!(0, ScoreBreakdown::default().points());
+    }
+
+    #[test]
+    fn points_some_lines() {
+        let score_breakdown = ScoreBreakdown {
+            lines: vec![
+                LineScore {
+                    coordinates: btree_set! { (0, 0), (1, 0) },
+                    len: 2,
+                    full_match: false,
+                    full_match_bonus: 0,
+                },
+                LineScore {
+                    coordinates: (0..6).map(|y| (1, y)).collect(),
+                    len: 6,
+                    full_match: true,
+                    full_match_bonus: 6,
+                },
+            ],
+            last_play: true,
+            last_play_bonus: 6,
+        };
+
+        assert_eq!(2, score_breakdown.lines[0].points());
+        assert_eq!(12, score_breakdown.lines[1].points());
+        assert_eq!(20, score_breakdown.points());
+    }
+}
@@ -0,0 +1,553 @@
+//! Custom serde representations for types which do not map to JSON on their own, and checks
+//! which reject deserialized states that could not have been reached by playing the game.
+
+use crate::{Board, Coordinate, GameConfig, Hands, TileIds};
+use std::collections::{BTreeSet, HashSet};
+use std::fmt::{self, Display, Formatter};
+
+/// Serializes a [Board](crate::Board) or the board of [tile ids](crate::TileIds) as a sequence of
+/// [coordinates](crate::Coordinate) and values ordered by [coordinate](crate::Coordinate) since
+/// JSON object keys cannot be tuples.
+pub(crate) mod board {
+    use crate::Coordinate;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::{BTreeMap, HashMap};
+
+    pub(crate) fn serialize<S: Serializer, T: Serialize>(
+        board: &HashMap<Coordinate, T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(board.iter().collect::<BTreeMap<_, _>>())
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>, T: Deserialize<'de>>(
+        deserializer: D,
+    ) -> Result<HashMap<Coordinate, T>, D::Error> {
+        let entries = Vec::<(Coordinate, T)>::deserialize(deserializer)?;
+        let len = entries.len();
+        let board: HashMap<Coordinate, T> = entries.into_iter().collect();
+        if board.len() != len {
+            return Err(D::Error::custom("duplicate coordinates in board"));
+        }
+
+        Ok(board)
+    }
+}
+
+/// Serializes [Plays](crate::Plays) as a sequence of indexes and
+/// [coordinates](crate::Coordinate) ordered by index so that both sides of the bimap stay unique
+/// after deserializing.
+pub(crate) mod plays {
+    use crate::{Coordinate, Plays};
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        plays: &Plays,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(plays.iter())
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Plays, D::Error> {
+        let mut plays = Plays::new();
+        for (index, coordinate) in Vec::<(usize, Coordinate)>::deserialize(deserializer)? {
+            plays
+                .insert_no_overwrite(index, coordinate)
+                .map_err(|_| D::Error::custom("duplicate indexes or coordinates in plays"))?;
+        }
+
+        Ok(plays)
+    }
+}
+
+/// Serializes [TilePlays](crate::TilePlays) as a sequence of [coordinates](crate::Coordinate)
+/// and [tiles](crate::Tile) ordered by [coordinate](crate::Coordinate) since JSON object keys
+/// cannot be tuples.
+pub(crate) mod tile_plays {
+    use crate::{Coordinate, Tile, TilePlays};
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        tile_plays: &TilePlays,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(tile_plays.iter())
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<TilePlays, D::Error> {
+        let entries = Vec::<(Coordinate, Tile)>::deserialize(deserializer)?;
+        let len = entries.len();
+        let tile_plays: TilePlays = entries.into_iter().collect();
+        if tile_plays.len() != len {
+            return Err(D::Error::custom("duplicate coordinates in tile plays"));
+        }
+
+        Ok(tile_plays)
+    }
+}
+
+/// Describes the reason why a deserialized state could not have been reached by playing
+/// the game, so that it is rejected instead of panicking later on.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum StateError {
+    /// Deserializing a state with no players.
+    EmptyPlayers,
+    /// Deserializing a state where the current player is not in the game.
+    CurrentPlayerOutOfBounds {
+        /// The index of the player whose turn it is.
+        current_player: usize,
+        /// The number of players in the game.
+        players_len: usize,
+    },
+    /// Deserializing a state with a different number of points than players.
+    WrongPointsLength {
+        /// The number of points.
+        points_len: usize,
+        /// The number of players in the game.
+        players_len: usize,
+    },
+    /// Deserializing a state with a different number of maximum matches than players.
+    WrongMaxMatchesLength {
+        /// The number of maximum matches.
+        max_matches_len: usize,
+        /// The number of players in the game.
+        players_len: usize,
+    },
+    /// Deserializing a state with [tiles](crate::Tile) on the board too far away from
+    /// the center of the board.
+    CoordinatesOutOfBounds {
+        /// [Coordinates](Coordinate) where the absolute value of a component is greater than
+        /// or equal to the [coordinate limit](GameConfig::coordinate_limit).
+        coordinates_out_of_bounds: BTreeSet<Coordinate>,
+    },
+    /// Deserializing a state with more [tiles](crate::Tile) than
+    /// the [tile limit](GameConfig::tile_limit).
+    TooManyTiles {
+        /// The number of [tiles](crate::Tile) in the bag, every hand, and the board.
+        tiles_len: usize,
+    },
+    /// Deserializing a state whose [tile ids](TileIds) are not unique or are not exactly one
+    /// for each [tile](crate::Tile) in the bag, each hand, and the board.
+    MismatchedTileIds,
+}
+
+/// Every [state error](StateError) which rejected a deserialized state. Implements `Display`
+/// so that serde can report it.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct StateErrors(pub HashSet<StateError>);
+
+impl Display for StateErrors {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid state: {:?}", self.0)
+    }
+}
+
+/// Checks that every [coordinate](Coordinate) on the board is within
+/// the [coordinate limit](GameConfig::coordinate_limit) and that the number of
+/// [tiles](crate::Tile) is within the [tile limit](GameConfig::tile_limit), which together
+/// prevent overflow while checking [plays](crate::Plays).
+///
+/// # Arguments
+///
+/// * `bag_len`: The number of [tiles](crate::Tile) in the bag.
+/// * `board`: This is a map of [coordinates](Coordinate) to [tiles](crate::Tile) that
+/// have been played.
+/// * `hands`: A vector of hands for each player, where each hand is
+/// a vector of [tiles](crate::Tile).
+/// * `config`: Limits and bonuses of the game.
+/// * `errors`: Every [state error](StateError) found so far.
+pub(crate) fn check_tiles(
+    bag_len: usize,
+    board: &Board,
+    hands: &Hands,
+    config: &GameConfig,
+    errors: &mut HashSet<StateError>,
+) {
+    let coordinate_limit = config.coordinate_limit();
+    let coordinates_out_of_bounds: BTreeSet<Coordinate> = board
+        .keys()
+        .copied()
+        .filter(|&(x, y)| {
+            x <= -coordinate_limit
+                || coordinate_limit <= x
+                || y <= -coordinate_limit
+                || coordinate_limit <= y
+        })
+        .collect();
+    if !coordinates_out_of_bounds.is_empty() {
+        errors.insert(StateError::CoordinatesOutOfBounds {
+            coordinates_out_of_bounds,
+        });
+    }
+
+    let tiles_len = bag_len + board.len() + hands.iter().map(|hand| hand.len()).sum::<usize>();
+    if tiles_len > config.tile_limit() {
+        errors.insert(StateError::TooManyTiles { tiles_len });
+    }
+}
+
+/// Checks that the [tile ids](TileIds), if they are tracked, are unique and line up with
+/// the [tiles](crate::Tile) in the bag, each hand, and the board, so that moving them along
+/// with the [tiles](crate::Tile) cannot panic.
+///
+/// # Arguments
+///
+/// * `tile_ids`: The [identities](crate::TileId) of every [tile](crate::Tile) or [None] if
+/// they are not tracked.
+/// * `bag_len`: The number of [tiles](crate::Tile) in the bag or [None] if the bag is
+/// not kept, such as after the game has ended.
+/// * `board`: This is a map of [coordinates](Coordinate) to [tiles](crate::Tile) that
+/// have been played.
+/// * `hands`: A vector of hands for each player, where each hand is
+/// a vector of [tiles](crate::Tile).
+/// * `errors`: Every [state error](StateError) found so far.
+pub(crate) fn check_tile_ids(
+    tile_ids: Option<&TileIds>,
+    bag_len: Option<usize>,
+    board: &Board,
+    hands: &Hands,
+    errors: &mut HashSet<StateError>,
+) {
+    if tile_ids
+        .filter(|tile_ids| !tile_ids.lines_up(bag_len, hands, board))
+        .is_some()
+    {
+        errors.insert(StateError::MismatchedTileIds);
+    }
+}
+
+/// Checks that the game has some players, that the current player is one of them, and that
+/// the lengths of properties for each player match the number of players.
+///
+/// # Arguments
+///
+/// * `hands`: A vector of hands for each player, where each hand is
+/// a vector of [tiles](crate::Tile).
+/// * `current_player`: The index of the player whose turn it is or [None] if
+/// the game has ended.
+/// * `points_len`: The number of points or [None] if the state has no points.
+/// * `max_matches_len`: The number of maximum matches or [None] if the state has no
+/// maximum matches.
+/// * `errors`: Every [state error](StateError) found so far.
+pub(crate) fn check_players(
+    hands: &Hands,
+    current_player: Option<usize>,
+    points_len: Option<usize>,
+    max_matches_len: Option<usize>,
+    errors: &mut HashSet<StateError>,
+) {
+    let players_len = hands.len();
+    if players_len == 0 {
+        errors.insert(StateError::EmptyPlayers);
+    }
+    if let Some(current_player) = current_player.filter(|&player| player >= players_len) {
+        errors.insert(StateError::CurrentPlayerOutOfBounds {
+            current_player,
+            players_len,
+        });
+    }
+    if let Some(points_len) = points_len.filter(|&len| len != players_len) {
+        errors.insert(StateError::WrongPointsLength {
+            points_len,
+            players_len,
+        });
+    }
+    if let Some(max_matches_len) = max_matches_len.filter(|&len| len != players_len) {
+        errors.insert(StateError::WrongMaxMatchesLength {
+            max_matches_len,
+            players_len,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::first_state::FirstStateFields;
+    use crate::last_state::LastStateFields;
+    use crate::{
+        Action, Board, Color, FirstState, Game, GameConfig, GreedyPlayer, LastState, NextState,
+        PlayerView, Plays, RatingTable, Shape, StateError, StateErrors,
+    };
+    use map_macro::{hash_map, hash_set};
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct BoardWrapper(#[serde(with = "super::board")] Board);
+
+    #[test]
+    fn board_round_trip() {
+        let board = BoardWrapper(hash_map! {
+            (0, 0) => (Color::Red, Shape::Circle),
+            (-1, 0) => (Color::Red, Shape::Square),
+        });
+
+        let json = serde_json::to_string(&board).expect("to_string should return Ok");
+
+        assert_eq!(
+            r#"[[[-1,0],["Red","Square"]],[[0,0],["Red","Circle"]]]"#,
+            json
+        );
+        assert_eq!(
+            board,
+            serde_json::from_str(&json).expect("from_str should return Ok")
+        );
+    }
+
+    #[test]
+    fn board_duplicate_coordinates() {
+        let json = r#"[[[0,0],["Red","Circle"]],[[0,0],["Red","Square"]]]"#;
+
+        assert!(serde_json::from_str::<BoardWrapper>(json).is_err());
+    }
+
+    #[test]
+    fn action_round_trip() {
+        let action = Action::Play([(0, (1, 0)), (2, (2, 0))].into_iter().collect());
+
+        let json = serde_json::to_string(&action).expect("to_string should return Ok");
+
+        assert_eq!(r#"{"Play":[[0,[1,0]],[2,[2,0]]]}"#, json);
+        assert_eq!(
+            action,
+            serde_json::from_str(&json).expect("from_str should return Ok")
+        );
+    }
+
+    #[test]
+    fn plays_duplicate_coordinates() {
+        let json = r#"{"Play":[[0,[1,0]],[2,[1,0]]]}"#;
+
+        assert!(serde_json::from_str::<Action>(json).is_err());
+        assert_eq!(
+            Action::Play(Plays::new()),
+            serde_json::from_str(r#"{"Play":[]}"#).expect("from_str should return Ok")
+        );
+    }
+
+    #[test]
+    fn config_invalid() {
+        let json = serde_json::json!({
+            "tile_limit": 0,
+            "coordinate_limit": 1,
+            "holes_limit": 0,
+            "full_match_bonus": 0,
+            "last_play_bonus": 0,
+        });
+
+        let error =
+            serde_json::from_value::<GameConfig>(json).expect_err("from_value should return Err");
+
+        assert!(error.to_string().contains("NotEnoughTileLimit"));
+    }
+
+    #[test]
+    fn rating_table_invalid() {
+        let json = serde_json::json!({
+            "ratings": { "alice": { "rating": 1500.0, "games": 0 } },
+        });
+        let mut padded_player = json.clone();
+        padded_player["ratings"] = serde_json::json!({
+            " alice": { "rating": 1500.0, "games": 0 },
+        });
+        let mut multiline_player = json.clone();
+        multiline_player["ratings"] = serde_json::json!({
+            "alice\nbob": { "rating": 1500.0, "games": 0 },
+        });
+
+        assert!(serde_json::from_value::<RatingTable>(json).is_ok());
+        assert!(serde_json::from_value::<RatingTable>(padded_player).is_err());
+        assert!(serde_json::from_value::<RatingTable>(multiline_player).is_err());
+    }
+
+    #[test]
+    fn config_round_trip() {
+        let config =
+            GameConfig::new(None, Some(100), None, Some(3), None).expect("new should return Ok");
+
+        let json = serde_json::to_string(&config).expect("to_string should return Ok");
+
+        assert_eq!(
+            config,
+            serde_json::from_str(&json).expect("from_str should return Ok")
+        );
+    }
+
+    #[test]
+    fn next_view_serialize() {
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rand::thread_rng());
+        next_state
+            .mut_board()
+            .insert((0, 0), (Color::Red, Shape::Circle));
+
+        let json = serde_json::to_value(next_state.next_view()).expect("to_value should return Ok");
+
+        assert_eq!(serde_json::json!(0), json["bag_len"]);
+        assert_eq!(
+            serde_json::json!([[[0, 0], ["Red", "Circle"]]]),
+            json["board"]
+        );
+    }
+
+    #[test]
+    fn tile_ids_round_trip() {
+        let first_state = FirstState::new_random_first_player(2, None, None, None)
+            .expect("new_random_first_player should return Ok")
+            .track_tile_ids();
+        let hand = first_state.get_hand(first_state.current_player()).cloned();
+        let action = GreedyPlayer::new().choose(
+            PlayerView::First(first_state.first_view()),
+            &hand.unwrap_or_default(),
+        );
+        let (game, _) = Game::from(first_state)
+            .apply(&action)
+            .expect("apply should return Ok");
+
+        let json = serde_json::to_string(&game).expect("to_string should return Ok");
+        let actual_game: Game = serde_json::from_str(&json).expect("from_str should return Ok");
+
+        let tile_ids = game.tile_ids().expect("tile_ids should return Some");
+        assert!(!tile_ids.board().is_empty());
+        assert_eq!(Some(tile_ids), actual_game.tile_ids());
+    }
+
+    #[test]
+    fn tile_ids_mismatched() {
+        let first_state = FirstState::new_random_first_player(2, None, None, None)
+            .expect("new_random_first_player should return Ok")
+            .track_tile_ids();
+        let json = serde_json::to_value(&first_state).expect("to_value should return Ok");
+        let mut missing_hand = json.clone();
+        missing_hand["tile_ids"]["hands"][1] = serde_json::json!([]);
+
+        assert!(serde_json::from_value::<FirstState>(json).is_ok());
+        assert!(serde_json::from_value::<FirstState>(missing_hand).is_err());
+
+        let hand = first_state.get_hand(first_state.current_player()).cloned();
+        let action = GreedyPlayer::new().choose(
+            PlayerView::First(first_state.first_view()),
+            &hand.unwrap_or_default(),
+        );
+        let Ok((Game::Next(next_state), _)) = Game::from(first_state).apply(&action) else {
+            panic!("apply should return Ok with next_state");
+        };
+        let json = serde_json::to_value(&next_state).expect("to_value should return Ok");
+        let mut empty_bag = json.clone();
+        empty_bag["tile_ids"]["bag"] = serde_json::json!([]);
+        let mut duplicate_ids = json.clone();
+        duplicate_ids["tile_ids"]["hands"][0][0] = json["tile_ids"]["hands"][1][0].clone();
+        let mut moved_board = json.clone();
+        moved_board["tile_ids"]["board"][0][0] = serde_json::json!([1000, 1000]);
+
+        assert!(serde_json::from_value::<NextState>(json).is_ok());
+        assert!(serde_json::from_value::<NextState>(empty_bag).is_err());
+        assert!(serde_json::from_value::<NextState>(duplicate_ids).is_err());
+        assert!(serde_json::from_value::<NextState>(moved_board).is_err());
+    }
+
+    #[test]
+    fn game_round_trip() {
+        let mut rng = rand::thread_rng();
+        let mut next_state = NextState::empty_next_state();
+        next_state.random_players(&mut rng);
+        next_state.random_bag(&mut rng);
+        next_state.random_board(&mut rng);
+        next_state.random_hands(&mut rng);
+        let game = Game::from(next_state);
+
+        let json = serde_json::to_string(&game).expect("to_string should return Ok");
+        let actual_game: Game = serde_json::from_str(&json).expect("from_str should return Ok");
+
+        assert_eq!(
+            json,
+            serde_json::to_string(&actual_game).expect("to_string should return Ok")
+        );
+    }
+
+    #[test]
+    fn first_state_invalid() {
+        let first_state = FirstState::new_random_first_player(2, None, None, None)
+            .expect("new_random_first_player should return Ok");
+        let json = serde_json::to_value(&first_state).expect("to_value should return Ok");
+
+        let mut current_player_out_of_bounds = json.clone();
+        current_player_out_of_bounds["current_player"] = serde_json::json!(2);
+        let mut wrong_max_matches_len = json.clone();
+        wrong_max_matches_len["max_matches"] = serde_json::json!([1]);
+
+        assert!(serde_json::from_value::<FirstState>(json).is_ok());
+        assert!(serde_json::from_value::<FirstState>(current_player_out_of_bounds).is_err());
+        assert!(serde_json::from_value::<FirstState>(wrong_max_matches_len.clone()).is_err());
+
+        let fields: FirstStateFields =
+            serde_json::from_value(wrong_max_matches_len).expect("from_value should return Ok");
+        assert_eq!(
+            Some(StateErrors(hash_set! {
+                StateError::WrongMaxMatchesLength {
+                    max_matches_len: 1,
+                    players_len: 2,
+                },
+            })),
+            FirstState::try_from(fields).err()
+        );
+    }
+
+    #[test]
+    fn next_state_invalid() {
+        let mut rng = rand::thread_rng();
+        let mut next_state = NextState::empty_next_state();
+        let players_len = next_state.random_players(&mut rng);
+        next_state.random_board(&mut rng);
+        next_state.random_hands(&mut rng);
+        let json = serde_json::to_value(&next_state).expect("to_value should return Ok");
+
+        let mut current_player_out_of_bounds = json.clone();
+        current_player_out_of_bounds["current_player"] = serde_json::json!(players_len);
+        let mut wrong_points_len = json.clone();
+        wrong_points_len["points"] = serde_json::json!([]);
+        let mut no_players = json.clone();
+        no_players["hands"] = serde_json::json!([]);
+        no_players["points"] = serde_json::json!([]);
+        let mut coordinates_out_of_bounds = json.clone();
+        let coordinate_limit = json["config"]["coordinate_limit"].clone();
+        coordinates_out_of_bounds["board"] =
+            serde_json::json!([[[0, coordinate_limit], ["Red", "Circle"]]]);
+
+        assert!(serde_json::from_value::<NextState>(json).is_ok());
+        assert!(serde_json::from_value::<NextState>(current_player_out_of_bounds).is_err());
+        assert!(serde_json::from_value::<NextState>(wrong_points_len).is_err());
+        assert!(serde_json::from_value::<NextState>(no_players).is_err());
+        assert!(serde_json::from_value::<NextState>(coordinates_out_of_bounds).is_err());
+    }
+
+    #[test]
+    fn last_state_invalid() {
+        let last_state = LastState::random_last_state(&mut rand::thread_rng());
+        let json = serde_json::to_value(&last_state).expect("to_value should return Ok");
+
+        let players_len = json["hands"].as_array().map_or(0, Vec::len);
+        let mut wrong_points_len = json.clone();
+        wrong_points_len["points"] = serde_json::json!([]);
+
+        assert!(serde_json::from_value::<LastState>(json).is_ok());
+        assert!(serde_json::from_value::<LastState>(wrong_points_len.clone()).is_err());
+
+        let fields: LastStateFields =
+            serde_json::from_value(wrong_points_len).expect("from_value should return Ok");
+        assert_eq!(
+            Some(StateErrors(hash_set! {
+                StateError::WrongPointsLength {
+                    points_len: 0,
+                    players_len,
+                },
+            })),
+            LastState::try_from(fields).err()
+        );
+    }
+}
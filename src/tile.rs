@@ -73,6 +73,7 @@ pub fn tiles() -> [Tile; TILES_LEN] {
 
 /// Describes the color on a [tile](Tile).
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Color {
     /// `0`.
     Red = 0,
@@ -133,6 +134,7 @@ impl Distribution<Color> for Standard {
 
 /// Describes the shape on a [tile](Tile).
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Shape {
     /// `0`.
     Circle = 0,
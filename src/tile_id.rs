@@ -0,0 +1,379 @@
+use crate::{Bag, Coordinate, Exchanges, Hands, Plays, HAND_CAPACITY, PLAYER_CAPACITY};
+use itertools::Itertools;
+use smallvec::SmallVec;
+use std::collections::HashMap;
+
+/// A stable identity of one physical [tile](crate::Tile) which is kept from the bag, through
+/// a hand, to the board. Copies of the same [tile](crate::Tile) have different identities.
+///
+/// # See Also
+///
+/// * [TileIds]
+/// * [FirstState::track_tile_ids](crate::FirstState::track_tile_ids)
+pub type TileId = usize;
+
+/// The [identities](TileId) of every [tile](crate::Tile) in the bag, each hand, and the board,
+/// which are moved along with their [tiles](crate::Tile) by every play and exchange.
+///
+/// Identities are the positions of [tiles](crate::Tile) in the shuffled bag the game started
+/// with, so the same seed always gives the same identities.
+///
+/// # See Also
+///
+/// * [FirstState::track_tile_ids](crate::FirstState::track_tile_ids)
+/// * [FirstState::tile_ids](crate::FirstState::tile_ids)
+/// * [NextState::tile_ids](crate::NextState::tile_ids)
+/// * [LastState::tile_ids](crate::LastState::tile_ids)
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TileIds {
+    /// The [identities](TileId) of the bag in the same order as the bag.
+    bag: Vec<TileId>,
+    /// The [identities](TileId) of each hand in the same order as each hand.
+    hands: SmallVec<[SmallVec<[TileId; HAND_CAPACITY]>; PLAYER_CAPACITY]>,
+    /// A map of [coordinates](Coordinate) to [identities](TileId) of [tiles](crate::Tile) that
+    /// have been played.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::board"))]
+    board: HashMap<Coordinate, TileId>,
+}
+
+impl TileIds {
+    /// Gives each [tile](crate::Tile) in the bag its position as its [identity](TileId), and
+    /// then gives each [tile](crate::Tile) in each hand the positions after the bag as if
+    /// the hands were still on top of the bag.
+    ///
+    /// # Arguments
+    ///
+    /// * `bag`: This is a bag of all the [tiles](crate::Tile) that haven't been removed yet.
+    /// * `hands`: A vector of hands for each player, where each hand is
+    /// a vector of [tiles](crate::Tile).
+    pub(crate) fn new(bag: &Bag, hands: &Hands) -> TileIds {
+        let mut next_id = bag.len();
+        let hands = hands
+            .iter()
+            .map(|hand| {
+                let ids = (next_id..next_id + hand.len()).collect();
+                next_id += hand.len();
+                ids
+            })
+            .collect();
+        TileIds {
+            bag: (0..bag.len()).collect(),
+            hands,
+            board: HashMap::new(),
+        }
+    }
+
+    /// The [identities](TileId) of the requesting player's hand in the same order as the hand
+    /// or [None] if out of bounds.
+    pub fn hand(&self, player: usize) -> Option<&[TileId]> {
+        self.hands.get(player).map(|hand| hand.as_slice())
+    }
+
+    /// A map of [coordinates](Coordinate) to [identities](TileId) of [tiles](crate::Tile) that
+    /// have been played.
+    pub fn board(&self) -> &HashMap<Coordinate, TileId> {
+        &self.board
+    }
+
+    /// Moves the [identities](TileId) of [plays](Plays) from the player's hand to the board,
+    /// and then moves `drawn_len` [identities](TileId) from the end of the bag to the end of
+    /// the hand, the same as the [tiles](crate::Tile) are moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `player`: The index of the player who played.
+    /// * `plays`: A bimap of indexes of [tiles](crate::Tile) to be played to
+    /// [coordinates](Coordinate) on the board.
+    /// * `drawn_len`: The number of [tiles](crate::Tile) drawn from the bag.
+    ///
+    /// # Returns
+    ///
+    /// The played [identities](TileId) ordered by index in the hand and the drawn
+    /// [identities](TileId) in the order they were added.
+    pub(crate) fn play(
+        &mut self,
+        player: usize,
+        plays: &Plays,
+        drawn_len: usize,
+    ) -> (Vec<TileId>, Vec<TileId>) {
+        let hand = &mut self.hands[player];
+        let mut played = plays
+            .iter()
+            .rev()
+            .map(|(&index, &coordinate)| {
+                let id = hand.remove(index);
+                self.board.insert(coordinate, id);
+                id
+            })
+            .collect_vec();
+        played.reverse();
+        let drawn = self.bag.drain(self.bag.len() - drawn_len..).collect_vec();
+        hand.extend(drawn.iter().copied());
+        (played, drawn)
+    }
+
+    /// Moves the [identities](TileId) of [exchanges](Exchanges) from the player's hand to
+    /// the end of the bag after moving as many [identities](TileId) from the end of the bag to
+    /// the end of the hand, the same as the [tiles](crate::Tile) are moved before shuffling.
+    ///
+    /// # Arguments
+    ///
+    /// * `player`: The index of the player who exchanged.
+    /// * `exchanges`: An ordered set of indexes of [tiles](crate::Tile) to be exchanged.
+    ///
+    /// # Returns
+    ///
+    /// The exchanged [identities](TileId) ordered by index in the hand and the drawn
+    /// [identities](TileId) in the order they were added.
+    pub(crate) fn exchange(
+        &mut self,
+        player: usize,
+        exchanges: &Exchanges,
+    ) -> (Vec<TileId>, Vec<TileId>) {
+        let hand = &mut self.hands[player];
+        let ids_from_hand = exchanges
+            .iter()
+            .rev()
+            .map(|&index| hand.remove(index))
+            .collect_vec();
+        let drawn = self
+            .bag
+            .drain(self.bag.len() - exchanges.len()..)
+            .collect_vec();
+        hand.extend(drawn.iter().copied());
+        let exchanged = ids_from_hand.iter().rev().copied().collect();
+        self.bag.extend(ids_from_hand);
+        (exchanged, drawn)
+    }
+
+    /// Moves the [identity](TileId) at index `permutation[i]` of the player's hand to index `i`
+    /// the same as the [tiles](crate::Tile) are moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `player`: The index of the player whose hand is reordered.
+    /// * `permutation`: The index in the hand before reordering of each [tile](crate::Tile) in
+    /// the new order.
+    pub(crate) fn reorder_hand(&mut self, player: usize, permutation: &[usize]) {
+        let hand = &mut self.hands[player];
+        *hand = permutation.iter().map(|&index| hand[index]).collect();
+    }
+
+    /// Swaps two [identities](TileId) in the bag the same as two [tiles](crate::Tile) are
+    /// swapped while shuffling.
+    pub(crate) fn swap_bag(&mut self, first: usize, second: usize) {
+        self.bag.swap(first, second);
+    }
+
+    /// Whether every [identity](TileId) is unique and there is exactly one for each
+    /// [tile](crate::Tile) in the bag, each hand, and the board, which every move of
+    /// the [identities](TileId) relies on.
+    ///
+    /// # Arguments
+    ///
+    /// * `bag_len`: The number of [tiles](crate::Tile) in the bag or [None] if the bag is
+    /// not kept, such as after the game has ended.
+    /// * `hands`: A vector of hands for each player, where each hand is
+    /// a vector of [tiles](crate::Tile).
+    /// * `board`: This is a map of [coordinates](Coordinate) to [tiles](crate::Tile) that
+    /// have been played.
+    #[cfg(feature = "serde")]
+    pub(crate) fn lines_up(
+        &self,
+        bag_len: Option<usize>,
+        hands: &Hands,
+        board: &crate::Board,
+    ) -> bool {
+        bag_len.map_or(true, |bag_len| self.bag.len() == bag_len)
+            && self.hands.len() == hands.len()
+            && self
+                .hands
+                .iter()
+                .zip(hands)
+                .all(|(ids, hand)| ids.len() == hand.len())
+            && self.board.len() == board.len()
+            && board
+                .keys()
+                .all(|coordinate| self.board.contains_key(coordinate))
+            && self
+                .bag
+                .iter()
+                .chain(self.hands.iter().flatten())
+                .chain(self.board.values())
+                .all_unique()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Board, Event, FirstState, Game, GameRng, GreedyPlayer, HandSort, PlayerView, Tile,
+        TILES_LEN,
+    };
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn new_positions() {
+        let mut rng = GameRng::from_entropy();
+        let bag: Bag = (0..5).map(|_| rng.gen()).collect();
+        let hands: Hands = (0..2)
+            .map(|_| (0..3).map(|_| rng.gen()).collect())
+            .collect();
+
+        let tile_ids = TileIds::new(&bag, &hands);
+
+        assert_eq!((0..5).collect_vec(), tile_ids.bag);
+        assert_eq!(Some([5, 6, 7].as_slice()), tile_ids.hand(0));
+        assert_eq!(Some([8, 9, 10].as_slice()), tile_ids.hand(1));
+        assert_eq!(None, tile_ids.hand(2));
+        assert!(tile_ids.board().is_empty());
+    }
+
+    #[test]
+    fn reorder_hand() {
+        let mut rng = GameRng::from_entropy();
+        let bag: Bag = (0..5).map(|_| rng.gen()).collect();
+        let hands: Hands = (0..2)
+            .map(|_| (0..3).map(|_| rng.gen()).collect())
+            .collect();
+        let mut tile_ids = TileIds::new(&bag, &hands);
+
+        tile_ids.reorder_hand(1, &[2, 0, 1]);
+
+        assert_eq!(Some([5, 6, 7].as_slice()), tile_ids.hand(0));
+        assert_eq!(Some([10, 8, 9].as_slice()), tile_ids.hand(1));
+    }
+
+    #[test]
+    fn tile_ids_follow_tiles() {
+        let seed = rand::thread_rng().gen();
+        let first_state = FirstState::new_random_first_player_with_seed(3, None, None, None, seed)
+            .expect("new_random_first_player_with_seed should return Ok")
+            .track_tile_ids();
+        let mut greedy_player = GreedyPlayer::new_with_seed(seed);
+        let mut tiles_by_id = HashMap::with_capacity(TILES_LEN);
+        let mut game = Game::from(first_state);
+
+        while let Some(player) = game.current_player() {
+            let action = match &mut game {
+                Game::First(first_state) => {
+                    let tile_ids = first_state.tile_ids().cloned();
+                    let bag = first_state.mut_bag().clone();
+                    let hands = first_state.mut_hands().clone();
+                    assert_tile_ids(&mut tiles_by_id, tile_ids, &bag, &hands, &Board::new());
+                    let first_view = first_state.first_view_for(player);
+                    assert_eq!(
+                        first_state
+                            .tile_ids()
+                            .and_then(|tile_ids| tile_ids.hand(player)),
+                        first_view.hand_ids
+                    );
+                    let hand = hands[player].clone();
+                    greedy_player.choose(PlayerView::First(first_view), &hand)
+                }
+                Game::Next(next_state) => {
+                    next_state
+                        .sort_hand(player, HandSort::MatchingGroups)
+                        .expect("sort_hand should return Ok");
+                    let tile_ids = next_state.tile_ids().cloned();
+                    let bag = next_state.mut_bag().clone();
+                    let hands = next_state.mut_hands().clone();
+                    let board = next_state.mut_board().clone();
+                    assert_tile_ids(&mut tiles_by_id, tile_ids, &bag, &hands, &board);
+                    let next_view = next_state.next_view_for(player);
+                    assert_eq!(
+                        next_state
+                            .tile_ids()
+                            .and_then(|tile_ids| tile_ids.hand(player)),
+                        next_view.hand_ids
+                    );
+                    assert_eq!(None, next_state.next_view().hand_ids);
+                    let hand = hands[player].clone();
+                    greedy_player.choose(PlayerView::Next(next_view), &hand)
+                }
+                Game::Last(_) => unreachable!("current_player should be None"),
+            };
+            game = match game.apply(&action) {
+                Ok((game, _)) => game,
+                Err((game, _)) => game,
+            };
+            assert_event_ids(&mut game);
+        }
+
+        let Game::Last(last_state) = game else {
+            unreachable!("game should have ended");
+        };
+        let tile_ids = last_state.tile_ids().expect("tile_ids should return Some");
+        let last_view = last_state.last_view();
+        for (coordinate, tile) in last_view.board {
+            assert_eq!(tiles_by_id[&tile_ids.board()[coordinate]], *tile);
+        }
+    }
+
+    /// Asserts that the [identities](TileId) in each [event](crate::Event) are the ones which
+    /// were moved along with its [tiles](Tile).
+    fn assert_event_ids(game: &mut Game) {
+        let events = game.drain_events();
+        let tile_ids = game.tile_ids().expect("tile_ids should return Some");
+        for event in events {
+            match event {
+                Event::TilesPlayed { tiles, ids, .. } => {
+                    let ids = ids.expect("ids should be Some");
+                    assert_eq!(tiles.len(), ids.len());
+                    for ((_, coordinate, _), id) in tiles.iter().zip(ids) {
+                        assert_eq!(tile_ids.board()[coordinate], id);
+                    }
+                }
+                Event::TilesDrawn {
+                    player, tiles, ids, ..
+                } => {
+                    let ids = ids.expect("ids should be Some");
+                    assert_eq!(tiles.len(), ids.len());
+                    let hand = tile_ids.hand(player).expect("hand should return Some");
+                    assert!(hand.ends_with(&ids));
+                }
+                Event::TilesExchanged {
+                    player, tiles, ids, ..
+                } => {
+                    let ids = ids.expect("ids should be Some");
+                    assert_eq!(tiles.len(), ids.len());
+                    assert!(ids.iter().all(|id| tile_ids.bag.contains(id)));
+                    let hand = tile_ids.hand(player).expect("hand should return Some");
+                    assert!(ids.iter().all(|id| !hand.contains(id)));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Asserts that the [identities](TileId) are unique, line up with the [tiles](Tile), and
+    /// always belong to the same [tile](Tile).
+    fn assert_tile_ids(
+        tiles_by_id: &mut HashMap<TileId, Tile>,
+        tile_ids: Option<TileIds>,
+        bag: &Bag,
+        hands: &Hands,
+        board: &Board,
+    ) {
+        let tile_ids = tile_ids.expect("tile_ids should return Some");
+        assert_eq!(bag.len(), tile_ids.bag.len());
+        assert_eq!(board.len(), tile_ids.board.len());
+        let mut pairs = bag.iter().zip(&tile_ids.bag).collect_vec();
+        for (hand, ids) in hands.iter().zip(&tile_ids.hands) {
+            assert_eq!(hand.len(), ids.len());
+            pairs.extend(hand.iter().zip(ids));
+        }
+        pairs.extend(
+            board
+                .iter()
+                .map(|(coordinate, tile)| (tile, &tile_ids.board[coordinate])),
+        );
+
+        assert!(pairs.iter().map(|(_, &id)| id).all_unique());
+        for (&tile, &id) in pairs {
+            assert_eq!(tile, *tiles_by_id.entry(id).or_insert(tile));
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use crate::{Coordinate, Tile, HAND_CAPACITY, PLAYER_CAPACITY};
 use bimap::BiBTreeMap;
+use rand_chacha::ChaCha8Rng;
 use smallvec::SmallVec;
-use std::collections::{BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 /// A bimap of indexes of [tiles](Tile) to be played to [coordinates](Coordinate) on
 /// the board.
@@ -18,6 +19,23 @@ pub type Plays = BiBTreeMap<usize, Coordinate>;
 ///
 /// * [NextState::next_exchange](crate::NextState::next_exchange)
 pub type Exchanges = BTreeSet<usize>;
+/// A map of [coordinates](Coordinate) on the board to [tiles](Tile) to be played, which
+/// addresses [tiles](Tile) by value instead of by their index in the hand.
+///
+/// # See Also
+///
+/// * [resolve_plays](crate::resolve_plays)
+/// * [FirstState::first_play_tiles](crate::FirstState::first_play_tiles)
+/// * [NextState::next_play_tiles](crate::NextState::next_play_tiles)
+pub type TilePlays = BTreeMap<Coordinate, Tile>;
+/// A vector of [tiles](Tile) to be exchanged, where each copy of a [tile](Tile) is exchanged
+/// once, which addresses [tiles](Tile) by value instead of by their index in the hand.
+///
+/// # See Also
+///
+/// * [resolve_exchanges](crate::resolve_exchanges)
+/// * [NextState::next_exchange_tiles](crate::NextState::next_exchange_tiles)
+pub type TileExchanges = Vec<Tile>;
 
 /// This is a bag of all the [tiles](Tile) that haven't been removed yet.
 ///
@@ -87,3 +105,13 @@ pub type Hands = SmallVec<[Hand; PLAYER_CAPACITY]>;
 /// * [FirstView](crate::FirstView)
 /// * [NextView](crate::NextView)
 pub type HandLens = SmallVec<[usize; PLAYER_CAPACITY]>;
+/// A seedable random number generator owned by the game state. It shuffles the bag when
+/// the game is created and shuffles exchanged [tiles](Tile) back into the bag, so a game
+/// created from the same seed is reproduced exactly.
+///
+/// # See Also
+///
+/// * [FirstState::new_with_seed](crate::FirstState::new_with_seed)
+/// * [FirstState::new_with_rng](crate::FirstState::new_with_rng)
+/// * [NextState::next_exchange](crate::NextState::next_exchange)
+pub type GameRng = ChaCha8Rng;
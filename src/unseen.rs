@@ -0,0 +1,234 @@
+use crate::{tiles, Bag, HandLens, NextView, Tile, TILES_LEN};
+use std::iter;
+
+/// Every [tile](Tile) a single player cannot see, which is every [tile](Tile) the game started
+/// with except those on the board and in the player's own hand. Each unseen [tile](Tile) is
+/// either in the bag or in some opponent's hand, and from the player's perspective it is equally
+/// likely to be in any of those places. Created from [NextView::unseen_tiles].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct UnseenTiles {
+    /// The number of unseen copies of each [tile](Tile) in the same order as [tiles].
+    counts: [usize; TILES_LEN],
+    /// The number of [tiles](Tile) in the bag.
+    bag_len: usize,
+    /// The number of [tiles](Tile) in each hand, where the player's own hand is `0`.
+    hand_lens: HandLens,
+}
+
+impl NextView<'_> {
+    /// Finds every [tile](Tile) `player` cannot see by removing the board and `hand` from
+    /// every copy of every [tile](Tile) the game started with. Copies seen more often than the
+    /// game started with are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `player`: The index of the player whose perspective is taken. When out of bounds,
+    /// every hand belongs to an opponent.
+    /// * `hand`: The [tiles](Tile) held by `player`.
+    ///
+    /// # Returns
+    ///
+    /// The [unseen tiles](UnseenTiles) of `player`.
+    pub fn unseen_tiles(&self, player: usize, hand: &[Tile]) -> UnseenTiles {
+        let mut counts = [self.unique_tile_copied_count; TILES_LEN];
+        for seen in self.board.values().chain(hand) {
+            let count = &mut counts[UnseenTiles::index(seen)];
+            *count = count.saturating_sub(1);
+        }
+
+        let mut hand_lens = self.hand_lens.clone();
+        if let Some(hand_len) = hand_lens.get_mut(player) {
+            *hand_len = 0;
+        }
+        UnseenTiles {
+            counts,
+            bag_len: self.bag_len,
+            hand_lens,
+        }
+    }
+}
+
+impl UnseenTiles {
+    /// The number of unseen copies of `tile`.
+    pub fn count(&self, tile: &Tile) -> usize {
+        self.counts[UnseenTiles::index(tile)]
+    }
+
+    /// The number of unseen [tiles](Tile) including copies.
+    pub fn len(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    /// Whether every [tile](Tile) has been seen.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Every [tile](Tile) with some unseen copy and the number of unseen copies of it in
+    /// the same order as [tiles].
+    pub fn iter(&self) -> impl Iterator<Item = (Tile, usize)> + '_ {
+        tiles()
+            .into_iter()
+            .zip(self.counts)
+            .filter(|&(_, count)| count > 0)
+    }
+
+    /// A bag with every unseen copy of every [tile](Tile) in the same order as [tiles].
+    pub fn to_bag(&self) -> Bag {
+        self.iter()
+            .flat_map(|(tile, count)| iter::repeat(tile).take(count))
+            .collect()
+    }
+
+    /// The number of [tiles](Tile) in the bag.
+    pub fn bag_len(&self) -> usize {
+        self.bag_len
+    }
+
+    /// The number of places an unseen [tile](Tile) could be, which is the number of
+    /// [tiles](Tile) in the bag and in every opponent's hand.
+    pub fn hidden_len(&self) -> usize {
+        self.bag_len + self.hand_lens.iter().sum::<usize>()
+    }
+
+    /// The expected number of copies of `tile` in the bag.
+    pub fn expected_in_bag(&self, tile: &Tile) -> f64 {
+        self.expected_in(self.bag_len, tile)
+    }
+
+    /// The expected number of copies of `tile` in the hand of `player`, which is `0` for the
+    /// player whose perspective is taken and for any player out of bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `player`: The index of the opponent.
+    /// * `tile`: The unseen [tile](Tile).
+    pub fn expected_in_hand(&self, player: usize, tile: &Tile) -> f64 {
+        let hand_len = self.hand_lens.get(player).copied().unwrap_or(0);
+        self.expected_in(hand_len, tile)
+    }
+
+    /// The expected number of copies of `tile` across every opponent's hand.
+    pub fn expected_in_opponents(&self, tile: &Tile) -> f64 {
+        self.expected_in(self.hand_lens.iter().sum(), tile)
+    }
+
+    /// The expected number of copies of `tile` among `len` of the hidden places.
+    fn expected_in(&self, len: usize, tile: &Tile) -> f64 {
+        match self.hidden_len() {
+            0 => 0.0,
+            hidden_len => (self.count(tile) * len) as f64 / hidden_len as f64,
+        }
+    }
+
+    /// The index of `tile` in [tiles].
+    fn index(tile: &Tile) -> usize {
+        tiles()
+            .iter()
+            .position(|other| other == tile)
+            .unwrap_or_else(|| unreachable!("every tile should be in tiles."))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Action, Color, FirstState, Game, GreedyPlayer, NextState, PlayerView, Shape,
+        DEFAULT_UNIQUE_TILE_COPIED_COUNT,
+    };
+
+    #[test]
+    fn unseen_tiles_counts() {
+        let mut next_state = NextState::empty_next_state();
+        *next_state.mut_unique_tile_copied_count() = 2;
+        next_state.mut_points().extend([0, 0, 0]);
+        next_state.mut_hands().extend([
+            [(Color::Red, Shape::Circle), (Color::Blue, Shape::X)]
+                .into_iter()
+                .collect(),
+            [(Color::Red, Shape::Circle)].into_iter().collect(),
+            [(Color::Green, Shape::Clover)].into_iter().collect(),
+        ]);
+        next_state.mut_bag().extend([(Color::Red, Shape::Square)]);
+        next_state.mut_board().extend([
+            ((0, 0), (Color::Red, Shape::Circle)),
+            ((1, 0), (Color::Blue, Shape::X)),
+            ((2, 0), (Color::Blue, Shape::X)),
+        ]);
+        let hand = next_state.mut_hands()[0].clone();
+
+        let unseen_tiles = next_state.next_view().unseen_tiles(0, &hand);
+
+        assert_eq!(0, unseen_tiles.count(&(Color::Red, Shape::Circle)));
+        assert_eq!(0, unseen_tiles.count(&(Color::Blue, Shape::X)));
+        assert_eq!(2, unseen_tiles.count(&(Color::Red, Shape::Square)));
+        assert_eq!(2 * TILES_LEN - 4, unseen_tiles.len());
+        assert_eq!(unseen_tiles.len(), unseen_tiles.to_bag().len());
+        assert_eq!(
+            Some(((Color::Red, Shape::Clover), 2)),
+            unseen_tiles.iter().next()
+        );
+        assert_eq!(3, unseen_tiles.hidden_len());
+    }
+
+    #[test]
+    fn unseen_tiles_expected() {
+        let mut next_state = NextState::empty_next_state();
+        next_state.mut_points().extend([0, 0, 0]);
+        next_state.mut_hands().extend([
+            [(Color::Red, Shape::Circle)].into_iter().collect(),
+            [(Color::Red, Shape::Square), (Color::Blue, Shape::X)]
+                .into_iter()
+                .collect(),
+            [(Color::Green, Shape::Clover)].into_iter().collect(),
+        ]);
+        next_state.mut_bag().extend([(Color::Red, Shape::Square)]);
+        let hand = next_state.mut_hands()[0].clone();
+        let tile = (Color::Red, Shape::Square);
+
+        let unseen_tiles = next_state.next_view().unseen_tiles(0, &hand);
+
+        let count = DEFAULT_UNIQUE_TILE_COPIED_COUNT as f64;
+        assert_eq!(count / 4.0, unseen_tiles.expected_in_bag(&tile));
+        assert_eq!(0.0, unseen_tiles.expected_in_hand(0, &tile));
+        assert_eq!(count / 2.0, unseen_tiles.expected_in_hand(1, &tile));
+        assert_eq!(count / 4.0, unseen_tiles.expected_in_hand(2, &tile));
+        assert_eq!(0.0, unseen_tiles.expected_in_hand(3, &tile));
+        assert_eq!(count * 3.0 / 4.0, unseen_tiles.expected_in_opponents(&tile));
+    }
+
+    #[test]
+    fn unseen_tiles_game() {
+        let first_state = FirstState::new_random_first_player_with_seed(3, Some(2), None, None, 5)
+            .expect("new_random_first_player_with_seed should return Ok");
+        let player = first_state.current_player();
+        let hand = first_state
+            .get_hand(player)
+            .expect("get_hand should return Some");
+        let action = GreedyPlayer::new().choose(PlayerView::First(first_state.first_view()), hand);
+        let Action::Play(plays) = &action else {
+            panic!("choose should return Action::Play");
+        };
+        let played = plays.len();
+        let Ok((Game::Next(next_state), _)) = Game::from(first_state).apply(&action) else {
+            panic!("apply should return Game::Next");
+        };
+        let hand = next_state
+            .get_hand(player)
+            .expect("get_hand should return Some")
+            .clone();
+
+        let unseen_tiles = next_state.next_view().unseen_tiles(player, &hand);
+
+        assert_eq!(unseen_tiles.hidden_len(), unseen_tiles.len());
+        assert_eq!(2 * TILES_LEN - played - hand.len(), unseen_tiles.len());
+        let expected: f64 = unseen_tiles
+            .iter()
+            .map(|(tile, _)| {
+                unseen_tiles.expected_in_bag(&tile) + unseen_tiles.expected_in_opponents(&tile)
+            })
+            .sum();
+        assert!((unseen_tiles.len() as f64 - expected).abs() < 1e-9);
+    }
+}